@@ -0,0 +1,256 @@
+use bevy::prelude::*;
+use bevy::asset::io::file::FileAssetReader;
+use bevy::audio::Volume;
+use crate::pause::GameState;
+use crate::weapon::{BulletHitEvent, RocketExplosionEvent, WeaponFiredEvent};
+use crate::weapon_system::WeaponType;
+use crate::dino::{DinoKilledEvent, DinoSpecies};
+use crate::vehicle::{PlayerVehicle, VehicleVelocity};
+use crate::proximity::ProximityWarning;
+
+/// Minimum seconds between two gunfire sounds, so the machine gun doesn't turn into noise
+const GUNFIRE_MIN_INTERVAL: f32 = 0.12;
+/// Minimum seconds between two impact sounds (shotgun pellets land together)
+const IMPACT_MIN_INTERVAL: f32 = 0.05;
+/// Sideways speed at which the tires start to screech
+const SCREECH_LATERAL_SPEED: f32 = 4.0;
+/// Roughly the screech clip length, so a held drift sounds continuous
+const SCREECH_INTERVAL: f32 = 0.5;
+/// Seconds between heartbeats with a threat at the edge of the danger radius, and point blank
+const HEARTBEAT_INTERVAL_FAR: f32 = 1.0;
+const HEARTBEAT_INTERVAL_NEAR: f32 = 0.35;
+
+/// Global audio settings shared by sound effects and music
+#[derive(Resource)]
+pub struct AudioSettings {
+    pub master_volume: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            master_volume: 0.7,
+            muted: false,
+        }
+    }
+}
+
+impl AudioSettings {
+    /// Effective volume after applying mute
+    pub fn volume(&self) -> f32 {
+        if self.muted { 0.0 } else { self.master_volume }
+    }
+
+    pub fn adjust_volume(&mut self, delta: f32) {
+        self.master_volume = (self.master_volume + delta).clamp(0.0, 1.0);
+    }
+}
+
+/// Handles to all sound effects, loaded once at startup; None where the file isn't shipped
+#[derive(Resource)]
+pub struct SoundAssets {
+    pub machine_gun: Option<Handle<AudioSource>>,
+    pub shotgun: Option<Handle<AudioSource>>,
+    pub rocket_launch: Option<Handle<AudioSource>>,
+    pub impact: Option<Handle<AudioSource>>,
+    pub roar: Option<Handle<AudioSource>>,
+    pub boss_roar: Option<Handle<AudioSource>>,
+    pub explosion: Option<Handle<AudioSource>>,
+    pub combo: Option<Handle<AudioSource>>,
+    pub tire_screech: Option<Handle<AudioSource>>,
+    pub heartbeat: Option<Handle<AudioSource>>,
+}
+
+#[derive(Resource, Default)]
+struct SoundRateLimit {
+    last_gunfire: f32,
+    last_impact: f32,
+    last_screech: f32,
+    last_heartbeat: f32,
+}
+
+pub struct GameAudioPlugin;
+
+impl Plugin for GameAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AudioSettings>()
+            .init_resource::<SoundRateLimit>()
+            .add_systems(Startup, load_sound_assets)
+            .add_systems(Update, (
+                play_gunfire_sounds,
+                play_impact_sounds,
+                play_explosion_sounds,
+                play_kill_sounds,
+                play_tire_screech,
+                play_proximity_heartbeat,
+            ).run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn load_sound_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(SoundAssets {
+        machine_gun: load_if_present(&asset_server, "sounds/machine_gun.ogg"),
+        shotgun: load_if_present(&asset_server, "sounds/shotgun.ogg"),
+        rocket_launch: load_if_present(&asset_server, "sounds/rocket_launch.ogg"),
+        impact: load_if_present(&asset_server, "sounds/impact.ogg"),
+        roar: load_if_present(&asset_server, "sounds/roar.ogg"),
+        boss_roar: load_if_present(&asset_server, "sounds/boss_roar.ogg"),
+        explosion: load_if_present(&asset_server, "sounds/explosion.ogg"),
+        combo: load_if_present(&asset_server, "sounds/combo.ogg"),
+        tire_screech: load_if_present(&asset_server, "sounds/tire_screech.ogg"),
+        heartbeat: load_if_present(&asset_server, "sounds/heartbeat.ogg"),
+    });
+}
+
+/// Load a clip only if it exists under assets/, so missing files stay silent instead of
+/// logging a load error on every run
+pub fn load_if_present(asset_server: &AssetServer, path: &str) -> Option<Handle<AudioSource>> {
+    let file = FileAssetReader::get_base_path().join("assets").join(path);
+    file.exists().then(|| asset_server.load(path.to_owned()))
+}
+
+/// Spawn a one-shot sound that despawns itself when finished
+fn play_sound(commands: &mut Commands, sound: &Option<Handle<AudioSource>>, volume: f32, speed: f32) {
+    let Some(sound) = sound else {
+        return;
+    };
+    if volume <= 0.0 {
+        return;
+    }
+
+    commands.spawn((
+        AudioPlayer::new(sound.clone()),
+        PlaybackSettings::DESPAWN
+            .with_volume(Volume::new(volume))
+            .with_speed(speed),
+    ));
+}
+
+fn play_gunfire_sounds(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut events: EventReader<WeaponFiredEvent>,
+    sounds: Res<SoundAssets>,
+    settings: Res<AudioSettings>,
+    mut rate_limit: ResMut<SoundRateLimit>,
+) {
+    let now = time.elapsed_secs();
+
+    for event in events.read() {
+        let sound = match event.weapon {
+            WeaponType::MachineGun => {
+                if now - rate_limit.last_gunfire < GUNFIRE_MIN_INTERVAL {
+                    continue;
+                }
+                &sounds.machine_gun
+            }
+            WeaponType::Shotgun => &sounds.shotgun,
+            WeaponType::RocketLauncher => &sounds.rocket_launch,
+        };
+
+        rate_limit.last_gunfire = now;
+        play_sound(&mut commands, sound, settings.volume() * 0.5, 1.0);
+    }
+}
+
+fn play_impact_sounds(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut events: EventReader<BulletHitEvent>,
+    sounds: Res<SoundAssets>,
+    settings: Res<AudioSettings>,
+    mut rate_limit: ResMut<SoundRateLimit>,
+) {
+    let now = time.elapsed_secs();
+
+    // Multiple hits in one frame only need one impact sound
+    if events.read().count() == 0 || now - rate_limit.last_impact < IMPACT_MIN_INTERVAL {
+        return;
+    }
+
+    rate_limit.last_impact = now;
+    play_sound(&mut commands, &sounds.impact, settings.volume() * 0.6, 1.0);
+}
+
+fn play_explosion_sounds(
+    mut commands: Commands,
+    mut events: EventReader<RocketExplosionEvent>,
+    sounds: Res<SoundAssets>,
+    settings: Res<AudioSettings>,
+) {
+    for _event in events.read() {
+        play_sound(&mut commands, &sounds.explosion, settings.volume(), 1.0);
+    }
+}
+
+fn play_kill_sounds(
+    mut commands: Commands,
+    mut events: EventReader<DinoKilledEvent>,
+    sounds: Res<SoundAssets>,
+    settings: Res<AudioSettings>,
+) {
+    for event in events.read() {
+        // Death roar, smaller dinos roar higher
+        let (roar, speed) = match event.species {
+            DinoSpecies::TRex => (&sounds.boss_roar, 1.0),
+            DinoSpecies::Velociraptor => (&sounds.roar, 1.4),
+            DinoSpecies::Brachiosaurus => (&sounds.roar, 0.7),
+            _ => (&sounds.roar, 1.0),
+        };
+        play_sound(&mut commands, roar, settings.volume() * 0.8, speed);
+
+        // Combo chime rises in pitch as the streak grows
+        if event.combo >= 2 {
+            let pitch = (1.0 + (event.combo - 2) as f32 * 0.05).min(2.0);
+            play_sound(&mut commands, &sounds.combo, settings.volume() * 0.5, pitch);
+        }
+    }
+}
+
+fn play_tire_screech(
+    mut commands: Commands,
+    time: Res<Time>,
+    sounds: Res<SoundAssets>,
+    settings: Res<AudioSettings>,
+    mut rate_limit: ResMut<SoundRateLimit>,
+    vehicle_q: Query<&VehicleVelocity, With<PlayerVehicle>>,
+) {
+    let Ok(velocity) = vehicle_q.get_single() else {
+        return;
+    };
+
+    let now = time.elapsed_secs();
+    let slide = velocity.lateral.abs();
+    if slide < SCREECH_LATERAL_SPEED || now - rate_limit.last_screech < SCREECH_INTERVAL {
+        return;
+    }
+
+    // Harder slides screech louder
+    rate_limit.last_screech = now;
+    let intensity = (slide / 12.0).min(1.0);
+    play_sound(&mut commands, &sounds.tire_screech, settings.volume() * 0.4 * intensity, 1.0);
+}
+
+fn play_proximity_heartbeat(
+    mut commands: Commands,
+    time: Res<Time>,
+    sounds: Res<SoundAssets>,
+    settings: Res<AudioSettings>,
+    warning: Res<ProximityWarning>,
+    mut rate_limit: ResMut<SoundRateLimit>,
+) {
+    let Some(closeness) = warning.closeness else {
+        return;
+    };
+
+    // Beats come faster and louder as the threat closes in
+    let now = time.elapsed_secs();
+    let interval = HEARTBEAT_INTERVAL_FAR + (HEARTBEAT_INTERVAL_NEAR - HEARTBEAT_INTERVAL_FAR) * closeness;
+    if now - rate_limit.last_heartbeat < interval {
+        return;
+    }
+
+    rate_limit.last_heartbeat = now;
+    play_sound(&mut commands, &sounds.heartbeat, settings.volume() * (0.3 + 0.5 * closeness), 1.0);
+}
@@ -0,0 +1,226 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::dino::DinoSpecies;
+use crate::pause::GameState;
+
+/// Species the player has hit and how many of each they have killed
+#[derive(Resource, Default)]
+pub struct Bestiary {
+    pub seen: Vec<DinoSpecies>,
+    pub kills: HashMap<DinoSpecies, u32>,
+}
+
+impl Bestiary {
+    pub fn record_sighting(&mut self, species: DinoSpecies) {
+        if !self.seen.contains(&species) {
+            self.seen.push(species);
+        }
+    }
+
+    pub fn record_kill(&mut self, species: DinoSpecies) {
+        self.record_sighting(species);
+        *self.kills.entry(species).or_insert(0) += 1;
+    }
+
+    pub fn kill_count(&self, species: DinoSpecies) -> u32 {
+        self.kills.get(&species).copied().unwrap_or(0)
+    }
+
+    /// Species with at least one kill, whose codex entry is filled in
+    pub fn discovered(&self) -> usize {
+        DinoSpecies::ALL.iter().filter(|s| self.kill_count(**s) > 0).count()
+    }
+}
+
+/// Main menu button that opens the codex
+#[derive(Component)]
+pub struct BestiaryButton;
+
+/// Codex overlay root, drawn over the main menu
+#[derive(Component)]
+pub struct BestiaryPanel;
+
+#[derive(Component)]
+pub struct BestiaryCloseButton;
+
+pub struct BestiaryPlugin;
+
+impl Plugin for BestiaryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Bestiary>()
+            .add_systems(Update, handle_bestiary_buttons.run_if(in_state(GameState::Paused)))
+            .add_systems(OnExit(GameState::Paused), cleanup_bestiary);
+    }
+}
+
+fn handle_bestiary_buttons(
+    mut commands: Commands,
+    bestiary: Res<Bestiary>,
+    interaction_q: Query<
+        (&Interaction, Has<BestiaryButton>, Has<BestiaryCloseButton>),
+        Changed<Interaction>,
+    >,
+    panel_q: Query<Entity, With<BestiaryPanel>>,
+) {
+    for (interaction, is_open, is_close) in interaction_q.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if is_open && panel_q.is_empty() {
+            spawn_bestiary_panel(&mut commands, &bestiary);
+        } else if is_close {
+            for entity in panel_q.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
+
+fn spawn_bestiary_panel(commands: &mut Commands, bestiary: &Bestiary) {
+    commands.spawn((
+        BestiaryPanel,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(12.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.07, 0.05, 0.97)),
+        GlobalZIndex(10),
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("BESTIARY"),
+            TextFont {
+                font_size: 48.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.9, 0.5)),
+        ));
+
+        parent.spawn((
+            Text::new(format!("{}/{} species documented", bestiary.discovered(), DinoSpecies::ALL.len())),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            Node {
+                margin: UiRect::bottom(Val::Px(10.0)),
+                ..default()
+            },
+        ));
+
+        for species in DinoSpecies::ALL {
+            spawn_entry(parent, bestiary, species);
+        }
+
+        parent.spawn((
+            BestiaryCloseButton,
+            Button,
+            Node {
+                width: Val::Px(180.0),
+                height: Val::Px(45.0),
+                margin: UiRect::top(Val::Px(20.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.4, 0.4, 0.4)),
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new("Close"),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+    });
+}
+
+/// One codex row: a swatch in the species color, or a dark silhouette until the first kill
+fn spawn_entry(parent: &mut ChildBuilder, bestiary: &Bestiary, species: DinoSpecies) {
+    let kills = bestiary.kill_count(species);
+    let seen = bestiary.seen.contains(&species);
+
+    let (swatch, name, stats) = if kills > 0 {
+        let (score, coins) = species.kill_reward();
+        (
+            species.map_color(),
+            species.name().to_uppercase(),
+            format!(
+                "Health {:.0}  |  Speed {:.0}  |  Reward {} pts, {} coins  |  Killed {}",
+                species.base_health(),
+                species.base_speed(),
+                score,
+                coins,
+                kills
+            ),
+        )
+    } else if seen {
+        // Wounded but never brought down: named, but no stats yet
+        (Color::srgb(0.12, 0.12, 0.12), species.name().to_uppercase(), "Kill one to document it".to_string())
+    } else {
+        (Color::srgb(0.12, 0.12, 0.12), "???".to_string(), "Undiscovered".to_string())
+    };
+
+    parent.spawn((
+        Node {
+            width: Val::Px(720.0),
+            padding: UiRect::all(Val::Px(10.0)),
+            column_gap: Val::Px(16.0),
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.05)),
+        BorderRadius::all(Val::Px(6.0)),
+    )).with_children(|row| {
+        row.spawn((
+            Node {
+                width: Val::Px(48.0),
+                height: Val::Px(48.0),
+                ..default()
+            },
+            BackgroundColor(swatch),
+            BorderRadius::all(Val::Px(24.0)),
+        ));
+
+        row.spawn(Node {
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        }).with_children(|column| {
+            column.spawn((
+                Text::new(name),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(if kills > 0 { Color::WHITE } else { Color::srgb(0.5, 0.5, 0.5) }),
+            ));
+            column.spawn((
+                Text::new(stats),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            ));
+        });
+    });
+}
+
+fn cleanup_bestiary(
+    mut commands: Commands,
+    panel_q: Query<Entity, With<BestiaryPanel>>,
+) {
+    for entity in panel_q.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
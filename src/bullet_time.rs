@@ -0,0 +1,192 @@
+use bevy::prelude::*;
+use crate::dino::DinoKilledEvent;
+use crate::input::PlayerInput;
+use crate::kill_cam::KillCam;
+use crate::pause::{GameState, RestartGameEvent};
+
+/// Game speed while bullet time is held
+const BULLET_TIME_SCALE: f32 = 0.35;
+/// Real seconds a full meter lasts
+const METER_DRAIN_SECONDS: f32 = 4.0;
+/// Meter refilled by each kill
+const KILL_RECHARGE: f32 = 0.2;
+/// Meter needed to switch bullet time on
+const MIN_ACTIVATION: f32 = 0.25;
+/// Real seconds after bullet time ends before it can be used again
+const COOLDOWN_SECONDS: f32 = 2.0;
+
+/// Hold-to-slow ability. It scales virtual time, so everything on `Time` slows with the world,
+/// while the turret aims on real time and the fire interval is compensated
+#[derive(Resource)]
+pub struct BulletTime {
+    /// 0 = empty, 1 = full
+    pub meter: f32,
+    pub active: bool,
+    cooldown: Timer,
+}
+
+impl Default for BulletTime {
+    // Full meter and no cooldown at the start of a hunt
+    fn default() -> Self {
+        let mut cooldown = Timer::from_seconds(COOLDOWN_SECONDS, TimerMode::Once);
+        cooldown.tick(cooldown.duration());
+        Self {
+            meter: 1.0,
+            active: false,
+            cooldown,
+        }
+    }
+}
+
+impl BulletTime {
+    pub fn can_activate(&self) -> bool {
+        !self.active && self.cooldown.finished() && self.meter >= MIN_ACTIVATION
+    }
+}
+
+#[derive(Component)]
+pub struct BulletTimeMeterFill;
+
+/// Faint blue wash over the screen while bullet time is on
+#[derive(Component)]
+pub struct BulletTimeTint;
+
+pub struct BulletTimePlugin;
+
+impl Plugin for BulletTimePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BulletTime>()
+            .add_systems(Startup, setup_bullet_time_hud)
+            .add_systems(Update, (
+                update_bullet_time,
+                recharge_on_kills,
+                update_bullet_time_hud,
+            ).chain().run_if(in_state(GameState::Playing)))
+            .add_systems(Update, reset_bullet_time_on_restart);
+    }
+}
+
+fn setup_bullet_time_hud(mut commands: Commands) {
+    commands.spawn((
+        BulletTimeTint,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+    ));
+
+    // Meter (bottom left, above the dodge bar)
+    commands.spawn(Node {
+        position_type: PositionType::Absolute,
+        bottom: Val::Px(86.0),
+        left: Val::Px(20.0),
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(8.0),
+        ..default()
+    }).with_children(|parent| {
+        parent.spawn((
+            Text::new("SLOW-MO"),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+        parent.spawn((
+            Node {
+                width: Val::Px(150.0),
+                height: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        )).with_children(|bar| {
+            bar.spawn((
+                BulletTimeMeterFill,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.5, 0.5, 1.0)),
+            ));
+        });
+    });
+}
+
+fn update_bullet_time(
+    real_time: Res<Time<Real>>,
+    input: Res<PlayerInput>,
+    kill_cam: Res<KillCam>,
+    mut bullet_time: ResMut<BulletTime>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    // Drains on real time, so the slowdown doesn't stretch its own meter
+    bullet_time.cooldown.tick(real_time.delta());
+
+    if bullet_time.active {
+        bullet_time.meter -= real_time.delta_secs() / METER_DRAIN_SECONDS;
+
+        if !input.bullet_time || bullet_time.meter <= 0.0 {
+            bullet_time.active = false;
+            bullet_time.meter = bullet_time.meter.max(0.0);
+            bullet_time.cooldown.reset();
+            // The kill-cam owns game speed while it plays
+            if !kill_cam.is_active() {
+                virtual_time.set_relative_speed(1.0);
+            }
+        }
+    } else if input.bullet_time && bullet_time.can_activate() {
+        bullet_time.active = true;
+        virtual_time.set_relative_speed(BULLET_TIME_SCALE);
+    }
+}
+
+fn recharge_on_kills(
+    mut events: EventReader<DinoKilledEvent>,
+    mut bullet_time: ResMut<BulletTime>,
+) {
+    for _event in events.read() {
+        bullet_time.meter = (bullet_time.meter + KILL_RECHARGE).min(1.0);
+    }
+}
+
+fn update_bullet_time_hud(
+    bullet_time: Res<BulletTime>,
+    mut fill_q: Query<(&mut Node, &mut BackgroundColor), (With<BulletTimeMeterFill>, Without<BulletTimeTint>)>,
+    mut tint_q: Query<&mut BackgroundColor, (With<BulletTimeTint>, Without<BulletTimeMeterFill>)>,
+) {
+    for (mut node, mut color) in fill_q.iter_mut() {
+        node.width = Val::Percent(bullet_time.meter * 100.0);
+        color.0 = if bullet_time.active || bullet_time.can_activate() {
+            Color::srgb(0.5, 0.5, 1.0)
+        } else {
+            Color::srgb(0.3, 0.3, 0.45)
+        };
+    }
+
+    for mut tint in tint_q.iter_mut() {
+        tint.0 = if bullet_time.active {
+            Color::srgba(0.2, 0.3, 0.8, 0.12)
+        } else {
+            Color::NONE
+        };
+    }
+}
+
+fn reset_bullet_time_on_restart(
+    mut events: EventReader<RestartGameEvent>,
+    mut bullet_time: ResMut<BulletTime>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+
+    if bullet_time.active {
+        virtual_time.set_relative_speed(1.0);
+    }
+    *bullet_time = BulletTime::default();
+}
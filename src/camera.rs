@@ -1,25 +1,54 @@
 use bevy::prelude::*;
+use bevy::input::mouse::{MouseMotion, MouseWheel};
+use crate::effects::ScreenShake;
+use crate::round_start::RoundStart;
+use crate::kill_cam::KillCam;
 use crate::vehicle::PlayerVehicle;
 use crate::input::PlayerInput;
+use crate::pause::GameState;
+use crate::smoothing;
 
 pub struct CameraPlugin;
 
-#[derive(Resource, Default)]
-pub struct CameraSettings {
+/// Follow camera placement and feel; the player can tweak fov and snap in the settings menu
+#[derive(Resource)]
+pub struct CameraConfig {
     pub height: f32,
     pub distance: f32,
     pub angle: f32,
+    pub fov: f32,
+    /// How quickly the camera closes the gap to its follow spot, per second
+    pub stiffness: f32,
+    /// Lock the camera rigidly to the vehicle instead of easing after it
+    pub snap: bool,
 }
 
-impl CameraSettings {
+// Default must match new(), a zero fov or offset leaves nothing to see
+impl Default for CameraConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Vertical field of view range offered in the settings menu, in degrees
+const FOV_RANGE: std::ops::RangeInclusive<f32> = 50.0..=100.0;
+
+impl CameraConfig {
     pub fn new() -> Self {
         Self {
             height: 60.0,   // High bird's eye view
             distance: 30.0, // Distance behind vehicle
             angle: 60.0,    // Look-down angle in degrees
+            fov: 60.0,      // Vertical field of view in degrees
+            stiffness: 6.0, // About the old 0.1-per-frame lerp at 60 FPS
+            snap: false,
         }
     }
 
+    pub fn adjust_fov(&mut self, delta: f32) {
+        self.fov = (self.fov + delta).clamp(*FOV_RANGE.start(), *FOV_RANGE.end());
+    }
+
     pub fn adjust_height(&mut self, delta: f32) {
         self.height = (self.height + delta).clamp(10.0, 150.0);
     }
@@ -33,30 +62,117 @@ impl CameraSettings {
     }
 }
 
+/// Free-cam tuning and the current look angles while flying
+#[derive(Resource)]
+pub struct FreeCam {
+    pub speed: f32,
+    pub fast_multiplier: f32,
+    pub sensitivity: f32,
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl Default for FreeCam {
+    fn default() -> Self {
+        Self {
+            speed: 30.0,
+            fast_multiplier: 3.0,
+            sensitivity: 0.003,
+            yaw: 0.0,
+            pitch: 0.0,
+        }
+    }
+}
+
+/// Held to orbit the camera while paused; the menu hides meanwhile so the scene shows through
+pub const PAUSE_ORBIT_BUTTON: MouseButton = MouseButton::Right;
+
+/// Look-around orbit around the vehicle while paused, and the follow view to snap back to on resume
+#[derive(Resource)]
+pub struct PauseOrbit {
+    pub sensitivity: f32,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+    pub resume_view: Transform,
+}
+
+impl Default for PauseOrbit {
+    fn default() -> Self {
+        Self {
+            sensitivity: 0.005,
+            yaw: 0.0,
+            pitch: 0.0,
+            distance: 0.0,
+            resume_view: Transform::default(),
+        }
+    }
+}
+
+/// Orbit pitch stays between just above the ground and nearly straight down
+const PAUSE_ORBIT_PITCH: std::ops::RangeInclusive<f32> = 0.1..=1.5;
+const PAUSE_ORBIT_DISTANCE: std::ops::RangeInclusive<f32> = 8.0..=150.0;
+/// Zoom per scroll wheel notch
+const PAUSE_ORBIT_ZOOM_STEP: f32 = 0.9;
+
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<CameraSettings>()
+        app.init_resource::<CameraConfig>()
+            .init_resource::<FreeCam>()
+            .init_resource::<PauseOrbit>()
             .add_systems(Startup, setup_camera)
-            .add_systems(Update, (update_camera_settings, camera_follow));
+            .add_systems(OnEnter(GameState::FreeCam), enter_free_cam)
+            .add_systems(OnEnter(GameState::Paused), enter_pause_orbit)
+            .add_systems(OnExit(GameState::Paused), exit_pause_orbit)
+            .add_systems(Update, (
+                update_camera_config,
+                camera_follow,
+            ).run_if(in_state(GameState::Playing)))
+            // The settings menu changes the fov while paused
+            .add_systems(Update, apply_camera_fov)
+            .add_systems(Update, (
+                toggle_free_cam.run_if(in_state(GameState::Playing).or(in_state(GameState::FreeCam))),
+                free_cam_movement.run_if(in_state(GameState::FreeCam)),
+                pause_orbit.run_if(in_state(GameState::Paused)),
+            ));
     }
 }
 
 #[derive(Component)]
 pub struct MainCamera;
 
-fn setup_camera(mut commands: Commands) {
+fn setup_camera(mut commands: Commands, config: Res<CameraConfig>) {
     // Initial spawn position, will be updated by camera_follow system
     commands.spawn((
         Camera3d::default(),
         MainCamera,
         Transform::from_xyz(0.0, 60.0, 30.0).looking_at(Vec3::ZERO, Vec3::Y),
-        Projection::Perspective(default()),
+        Projection::Perspective(PerspectiveProjection {
+            fov: config.fov.to_radians(),
+            ..default()
+        }),
     ));
 }
 
-fn update_camera_settings(
+/// Push the fov setting onto the live camera
+fn apply_camera_fov(
+    config: Res<CameraConfig>,
+    mut projection_q: Query<&mut Projection, With<MainCamera>>,
+) {
+    if !config.is_changed() {
+        return;
+    }
+
+    for mut projection in projection_q.iter_mut() {
+        if let Projection::Perspective(perspective) = &mut *projection {
+            perspective.fov = config.fov.to_radians();
+        }
+    }
+}
+
+fn update_camera_config(
     input: Res<PlayerInput>,
-    mut settings: ResMut<CameraSettings>,
+    mut config: ResMut<CameraConfig>,
     time: Res<Time>,
 ) {
     let dt = time.delta_secs();
@@ -64,21 +180,29 @@ fn update_camera_settings(
 
     // Page Up: Raise camera and increase distance (move away from ground)
     if input.camera_up {
-        settings.adjust_height(adjust_speed);
-        settings.adjust_distance(adjust_speed * 0.5);
+        config.adjust_height(adjust_speed);
+        config.adjust_distance(adjust_speed * 0.5);
     }
     // Page Down: Lower camera and decrease distance (move closer to ground)
     if input.camera_down {
-        settings.adjust_height(-adjust_speed);
-        settings.adjust_distance(-adjust_speed * 0.5);
+        config.adjust_height(-adjust_speed);
+        config.adjust_distance(-adjust_speed * 0.5);
     }
 }
 
 fn camera_follow(
+    time: Res<Time<Real>>,
     mut camera_q: Query<&mut Transform, (With<MainCamera>, Without<PlayerVehicle>)>,
     vehicle_q: Query<&Transform, (With<PlayerVehicle>, Without<MainCamera>)>,
-    settings: Res<CameraSettings>,
+    config: Res<CameraConfig>,
+    round_start: Res<RoundStart>,
+    kill_cam: Res<KillCam>,
 ) {
+    // The boss kill-cam has the camera; following resumes from wherever it leaves off
+    if kill_cam.is_active() {
+        return;
+    }
+
     let Ok(mut camera_transform) = camera_q.get_single_mut() else {
         return;
     };
@@ -89,19 +213,180 @@ fn camera_follow(
 
     let vehicle_pos = vehicle_transform.translation;
 
-    // Calculate camera position based on vehicle position and settings
+    // Calculate camera position based on vehicle position and config
     // Camera is positioned at (height) units above and (distance) units behind
-    let angle_rad = settings.angle.to_radians();
-    let vertical_offset = settings.height;
-    let horizontal_offset = settings.distance * angle_rad.cos();
+    let angle_rad = config.angle.to_radians();
+    let vertical_offset = config.height;
+    let horizontal_offset = config.distance * angle_rad.cos();
 
     let offset = Vec3::new(0.0, vertical_offset, horizontal_offset);
+
+    // Establishing shot during the round countdown: swing in from the side and further out
+    let pan = round_start.pan();
+    let offset = Quat::from_rotation_y(pan * std::f32::consts::FRAC_PI_2) * offset * (1.0 + pan * 0.8);
     let target_pos = vehicle_pos + offset;
 
-    // Smooth follow
-    camera_transform.translation = camera_transform.translation.lerp(target_pos, 0.1);
+    // Exponential smoothing, so the follow feels the same at any frame rate; real time keeps
+    // the camera up with the vehicle through bullet time
+    camera_transform.translation = if config.snap {
+        target_pos
+    } else {
+        camera_transform.translation.lerp(target_pos, smoothing(config.stiffness, time.delta_secs()))
+    };
 
     // Look at vehicle from above
     let look_at = vehicle_pos + Vec3::new(0.0, 0.0, 0.0);
     camera_transform.look_at(look_at, Vec3::Y);
 }
+
+/// F8 detaches the camera for screenshots; pressing it again hands it back to camera_follow
+fn toggle_free_cam(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if !keyboard.just_pressed(KeyCode::F8) {
+        return;
+    }
+
+    match state.get() {
+        GameState::Playing => next_state.set(GameState::FreeCam),
+        GameState::FreeCam => next_state.set(GameState::Playing),
+        GameState::Paused => {}
+    }
+}
+
+fn enter_free_cam(
+    mut free_cam: ResMut<FreeCam>,
+    mut shake: ResMut<ScreenShake>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut camera_q: Query<&mut Transform, With<MainCamera>>,
+) {
+    let Ok(mut transform) = camera_q.get_single_mut() else {
+        return;
+    };
+
+    // Drop any shake in progress so it can't nudge the detached camera
+    transform.translation -= shake.offset;
+    shake.offset = Vec3::ZERO;
+    let remaining = shake.duration.remaining();
+    shake.duration.tick(remaining);
+
+    let (yaw, pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
+    free_cam.yaw = yaw;
+    free_cam.pitch = pitch;
+
+    // Motion from the toggle frame shouldn't jerk the view
+    mouse_motion.clear();
+}
+
+fn free_cam_movement(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut free_cam: ResMut<FreeCam>,
+    mut camera_q: Query<&mut Transform, With<MainCamera>>,
+    // Virtual time is paused while flying
+    time: Res<Time<Real>>,
+) {
+    let Ok(mut transform) = camera_q.get_single_mut() else {
+        return;
+    };
+
+    let look: Vec2 = mouse_motion.read().map(|motion| motion.delta).sum();
+    free_cam.yaw -= look.x * free_cam.sensitivity;
+    free_cam.pitch = (free_cam.pitch - look.y * free_cam.sensitivity).clamp(-1.5, 1.5);
+    transform.rotation = Quat::from_euler(EulerRot::YXZ, free_cam.yaw, free_cam.pitch, 0.0);
+
+    let mut direction = Vec3::ZERO;
+    if keyboard.pressed(KeyCode::KeyW) {
+        direction += *transform.forward();
+    }
+    if keyboard.pressed(KeyCode::KeyS) {
+        direction -= *transform.forward();
+    }
+    if keyboard.pressed(KeyCode::KeyD) {
+        direction += *transform.right();
+    }
+    if keyboard.pressed(KeyCode::KeyA) {
+        direction -= *transform.right();
+    }
+    if keyboard.pressed(KeyCode::Space) {
+        direction += Vec3::Y;
+    }
+    if keyboard.pressed(KeyCode::ControlLeft) {
+        direction -= Vec3::Y;
+    }
+
+    let mut speed = free_cam.speed;
+    if keyboard.pressed(KeyCode::ShiftLeft) {
+        speed *= free_cam.fast_multiplier;
+    }
+
+    transform.translation += direction.normalize_or_zero() * speed * time.delta_secs();
+}
+
+/// Remember the follow view and start the orbit from it
+fn enter_pause_orbit(
+    mut orbit: ResMut<PauseOrbit>,
+    camera_q: Query<&Transform, With<MainCamera>>,
+    vehicle_q: Query<&Transform, (With<PlayerVehicle>, Without<MainCamera>)>,
+) {
+    let (Ok(camera), Ok(vehicle)) = (camera_q.get_single(), vehicle_q.get_single()) else {
+        return;
+    };
+
+    let offset = camera.translation - vehicle.translation;
+    orbit.resume_view = *camera;
+    orbit.distance = offset.length().clamp(*PAUSE_ORBIT_DISTANCE.start(), *PAUSE_ORBIT_DISTANCE.end());
+    orbit.yaw = offset.x.atan2(offset.z);
+    orbit.pitch = (offset.y / offset.length().max(f32::EPSILON))
+        .asin()
+        .clamp(*PAUSE_ORBIT_PITCH.start(), *PAUSE_ORBIT_PITCH.end());
+}
+
+/// Drag to swing around the vehicle, scroll to zoom; left clicks are left to the menu
+fn pause_orbit(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut orbit: ResMut<PauseOrbit>,
+    mut camera_q: Query<&mut Transform, (With<MainCamera>, Without<PlayerVehicle>)>,
+    vehicle_q: Query<&Transform, (With<PlayerVehicle>, Without<MainCamera>)>,
+) {
+    let look: Vec2 = mouse_motion.read().map(|motion| motion.delta).sum();
+    let scroll: f32 = mouse_wheel.read().map(|wheel| wheel.y).sum();
+    if !mouse.pressed(PAUSE_ORBIT_BUTTON) {
+        return;
+    }
+
+    let (Ok(mut camera), Ok(vehicle)) = (camera_q.get_single_mut(), vehicle_q.get_single()) else {
+        return;
+    };
+
+    orbit.yaw -= look.x * orbit.sensitivity;
+    orbit.pitch = (orbit.pitch + look.y * orbit.sensitivity).clamp(*PAUSE_ORBIT_PITCH.start(), *PAUSE_ORBIT_PITCH.end());
+    orbit.distance = (orbit.distance * PAUSE_ORBIT_ZOOM_STEP.powf(scroll))
+        .clamp(*PAUSE_ORBIT_DISTANCE.start(), *PAUSE_ORBIT_DISTANCE.end());
+
+    let direction = Vec3::new(
+        orbit.yaw.sin() * orbit.pitch.cos(),
+        orbit.pitch.sin(),
+        orbit.yaw.cos() * orbit.pitch.cos(),
+    );
+    camera.translation = vehicle.translation + direction * orbit.distance;
+    camera.look_at(vehicle.translation, Vec3::Y);
+}
+
+/// Snap back to the follow view, so gameplay resumes exactly as it was left
+fn exit_pause_orbit(
+    orbit: Res<PauseOrbit>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut camera_q: Query<&mut Transform, With<MainCamera>>,
+) {
+    if let Ok(mut camera) = camera_q.get_single_mut() {
+        *camera = orbit.resume_view;
+    }
+
+    // Orbit drags shouldn't carry over into the turret aim
+    mouse_motion.clear();
+}
@@ -0,0 +1,174 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::rng::GameRng;
+use crate::pause::GameState;
+use crate::dino::CoinSystem;
+use crate::vehicle::PlayerVehicle;
+use crate::terrain::TerrainSeed;
+use crate::shop::VehicleUpgrades;
+
+/// Coins despawn if not picked up within this many seconds
+const COIN_LIFETIME: f32 = 20.0;
+/// Coins start flying toward the vehicle inside this distance, widened by the Coin Magnet upgrade
+pub const COIN_MAGNET_RADIUS: f32 = 6.0;
+const COIN_MAGNET_SPEED: f32 = 18.0;
+const COIN_PICKUP_RADIUS: f32 = 1.8;
+const COIN_GRAVITY: f32 = 20.0;
+/// Height of a resting coin's center above the ground
+const COIN_REST_HEIGHT: f32 = 0.35;
+/// Coin pieces a regular kill splits its reward into, at most
+const MAX_DROP_PIECES: u32 = 6;
+/// Coin pieces in a boss shower
+const BOSS_SHOWER_PIECES: u32 = 20;
+
+/// Sent when a dino dies; spawns coin pickups worth `amount` in total
+#[derive(Event)]
+pub struct CoinDropEvent {
+    pub position: Vec3,
+    pub amount: u32,
+    /// Boss kills burst a big shower of coins instead of a handful
+    pub shower: bool,
+}
+
+/// A coin pickup, tumbling until it lands and pulled in when the vehicle is near
+#[derive(Component)]
+pub struct Coin {
+    pub value: u32,
+    pub velocity: Vec3,
+    pub lifetime: Timer,
+}
+
+/// Mesh and material shared by every coin
+#[derive(Resource)]
+pub struct CoinAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+}
+
+pub struct CoinPlugin;
+
+impl Plugin for CoinPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CoinDropEvent>()
+            .add_systems(Startup, setup_coin_assets)
+            .add_systems(Update, (
+                spawn_coin_drops,
+                update_coins,
+            ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn setup_coin_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(CoinAssets {
+        mesh: meshes.add(Cylinder::new(0.3, 0.08)),
+        material: materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.8, 0.2),
+            emissive: LinearRgba::rgb(0.6, 0.45, 0.05),
+            metallic: 0.8,
+            perceptual_roughness: 0.3,
+            ..default()
+        }),
+    });
+}
+
+fn spawn_coin_drops(
+    mut commands: Commands,
+    mut events: EventReader<CoinDropEvent>,
+    assets: Res<CoinAssets>,
+    mut rng: ResMut<GameRng>,
+) {
+    for event in events.read() {
+        if event.amount == 0 {
+            continue;
+        }
+
+        let pieces = if event.shower {
+            BOSS_SHOWER_PIECES
+        } else {
+            (event.amount / 5).clamp(1, MAX_DROP_PIECES)
+        }
+        .min(event.amount);
+        let (speed, lift) = if event.shower { (4.0..9.0, 8.0..14.0) } else { (2.0..5.0, 5.0..8.0) };
+
+        for i in 0..pieces {
+            // Split evenly, with the remainder on the first coin
+            let value = event.amount / pieces + if i == 0 { event.amount % pieces } else { 0 };
+
+            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+            let outward = Vec3::new(angle.cos(), 0.0, angle.sin()) * rng.gen_range(speed.clone());
+
+            commands.spawn((
+                Coin {
+                    value,
+                    velocity: outward + Vec3::Y * rng.gen_range(lift.clone()),
+                    lifetime: Timer::from_seconds(COIN_LIFETIME, TimerMode::Once),
+                },
+                Mesh3d(assets.mesh.clone()),
+                MeshMaterial3d(assets.material.clone()),
+                // Stand the disc on its edge so it reads as a coin from above
+                Transform::from_translation(event.position + Vec3::Y)
+                    .with_rotation(Quat::from_rotation_y(angle) * Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+            ));
+        }
+    }
+}
+
+fn update_coins(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut coins: ResMut<CoinSystem>,
+    terrain: Res<TerrainSeed>,
+    vehicle_upgrades: Res<VehicleUpgrades>,
+    vehicle_q: Query<&Transform, (With<PlayerVehicle>, Without<Coin>)>,
+    mut coin_q: Query<(Entity, &mut Coin, &mut Transform)>,
+) {
+    let dt = time.delta_secs();
+    let vehicle_pos = vehicle_q.get_single().ok().map(|t| t.translation);
+    let magnet_radius = vehicle_upgrades.coin_magnet_radius();
+
+    for (entity, mut coin, mut transform) in coin_q.iter_mut() {
+        coin.lifetime.tick(time.delta());
+        if coin.lifetime.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        transform.rotate_y(3.0 * dt);
+
+        let to_vehicle = vehicle_pos.map(|pos| pos - transform.translation);
+        let distance = to_vehicle.map_or(f32::MAX, |v| v.length());
+
+        if distance < COIN_PICKUP_RADIUS {
+            coins.total_coins += coin.value;
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        if let Some(to_vehicle) = to_vehicle.filter(|_| distance < magnet_radius) {
+            // Vacuumed in, faster the closer it gets
+            let pull = 1.0 + (1.0 - distance / magnet_radius) * 2.0;
+            coin.velocity = to_vehicle / distance * COIN_MAGNET_SPEED * pull;
+            transform.translation += coin.velocity * dt;
+            continue;
+        }
+
+        coin.velocity.y -= COIN_GRAVITY * dt;
+        transform.translation += coin.velocity * dt;
+
+        // Bounce a little on landing, then settle
+        let rest = terrain.ground_height(transform.translation.x, transform.translation.z) + COIN_REST_HEIGHT;
+        if transform.translation.y < rest {
+            transform.translation.y = rest;
+            coin.velocity.x *= 0.5;
+            coin.velocity.z *= 0.5;
+            coin.velocity.y = if coin.velocity.y < -2.0 { -coin.velocity.y * 0.35 } else { 0.0 };
+            if coin.velocity.y == 0.0 {
+                coin.velocity = Vec3::ZERO;
+            }
+        }
+    }
+}
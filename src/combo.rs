@@ -1,14 +1,77 @@
 use bevy::prelude::*;
+use crate::dino::DinoCombatConfig;
 use crate::pause::GameState;
 
+/// Decay window at normal difficulty; harder games leave less time between kills
+const BASE_DECAY_WINDOW: f32 = 2.0;
+
+/// Named combo ranks, reached at fixed kill-streak thresholds
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ComboTier {
+    None,
+    Nice,
+    Rampage,
+    Unstoppable,
+}
+
+impl ComboTier {
+    pub fn from_combo(combo: u32) -> Self {
+        if combo >= 20 {
+            ComboTier::Unstoppable
+        } else if combo >= 10 {
+            ComboTier::Rampage
+        } else if combo >= 5 {
+            ComboTier::Nice
+        } else {
+            ComboTier::None
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            ComboTier::None => "",
+            ComboTier::Nice => "NICE",
+            ComboTier::Rampage => "RAMPAGE",
+            ComboTier::Unstoppable => "UNSTOPPABLE",
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            ComboTier::None => Color::WHITE,
+            ComboTier::Nice => Color::srgb(1.0, 0.84, 0.0),
+            ComboTier::Rampage => Color::srgb(1.0, 0.5, 0.1),
+            ComboTier::Unstoppable => Color::srgb(1.0, 0.2, 0.3),
+        }
+    }
+}
+
+/// Event fired when a kill pushes the combo into a higher tier
+#[derive(Event)]
+pub struct ComboTierReachedEvent {
+    pub tier: ComboTier,
+    pub combo: u32,
+}
+
 /// Combo system tracking kill streaks
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct ComboSystem {
     pub current_combo: u32,
     pub max_combo: u32,
     pub combo_timer: Timer,
     pub last_kill_time: f32,
     pub combo_multiplier: f32,
+    pub multiplier_per_kill: f32, // Multiplier gained per kill in the streak
+    pub max_multiplier: f32,
+    pub decay_window: f32, // Seconds without a kill before the combo resets
+    pub window_per_kill: f32, // Extra seconds of window per kill in the streak
+    pub max_decay_window: f32,
+}
+
+impl Default for ComboSystem {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ComboSystem {
@@ -16,14 +79,28 @@ impl ComboSystem {
         Self {
             current_combo: 0,
             max_combo: 0,
-            combo_timer: Timer::from_seconds(2.0, TimerMode::Once),
+            combo_timer: Timer::from_seconds(BASE_DECAY_WINDOW, TimerMode::Once),
             last_kill_time: 0.0,
             combo_multiplier: 1.0,
+            multiplier_per_kill: 0.1,
+            max_multiplier: 5.0,
+            decay_window: BASE_DECAY_WINDOW,
+            window_per_kill: 0.1,
+            max_decay_window: 4.0,
         }
     }
 
-    pub fn add_kill(&mut self) {
+    /// Tune how long a streak survives without a kill; follows the difficulty by default
+    pub fn set_decay_window(&mut self, seconds: f32) {
+        self.decay_window = seconds;
+    }
+
+    /// Register a kill, returning the new tier if this kill crossed into one
+    pub fn add_kill(&mut self) -> Option<ComboTier> {
+        let previous_tier = self.current_tier();
+
         self.current_combo += 1;
+        self.combo_timer.set_duration(std::time::Duration::from_secs_f32(self.combo_window()));
         self.combo_timer.reset();
 
         // Update max combo
@@ -32,9 +109,25 @@ impl ComboSystem {
         }
 
         // Calculate multiplier based on combo count
-        self.combo_multiplier = 1.0 + (self.current_combo as f32 * 0.1);
-        // Cap at 5x multiplier
-        self.combo_multiplier = self.combo_multiplier.min(5.0);
+        self.combo_multiplier = 1.0 + (self.current_combo as f32 * self.multiplier_per_kill);
+        self.combo_multiplier = self.combo_multiplier.min(self.max_multiplier);
+
+        let tier = self.current_tier();
+        (tier > previous_tier).then_some(tier)
+    }
+
+    /// Seconds the current streak survives without a kill; longer streaks get more slack
+    pub fn combo_window(&self) -> f32 {
+        let bonus = self.current_combo.saturating_sub(1) as f32 * self.window_per_kill;
+        (self.decay_window + bonus).min(self.max_decay_window)
+    }
+
+    /// Clear the streak on restart, keeping tuning such as the decay window
+    pub fn reset(&mut self) {
+        self.current_combo = 0;
+        self.max_combo = 0;
+        self.combo_multiplier = 1.0;
+        self.combo_timer.reset();
     }
 
     pub fn update(&mut self, delta: std::time::Duration) {
@@ -47,25 +140,46 @@ impl ComboSystem {
         }
     }
 
+    pub fn current_tier(&self) -> ComboTier {
+        ComboTier::from_combo(self.current_combo)
+    }
+
     pub fn get_score_multiplier(&self) -> f32 {
         self.combo_multiplier
     }
 
     pub fn get_combo_display(&self) -> String {
-        if self.current_combo >= 2 {
-            format!("{}x", self.current_combo)
-        } else {
-            String::new()
+        if self.current_combo < 2 {
+            return String::new();
+        }
+
+        match self.current_tier() {
+            ComboTier::None => format!("{}x", self.current_combo),
+            tier => format!("{}x {}", self.current_combo, tier.name()),
         }
     }
 }
 
+/// Large center-screen text shown briefly when a new tier is reached
+#[derive(Component)]
+pub struct ComboBanner {
+    pub timer: Timer,
+    pub tier: ComboTier,
+}
+
 pub struct ComboPlugin;
 
 impl Plugin for ComboPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ComboSystem>()
-            .add_systems(Update, update_combo.run_if(in_state(GameState::Playing)));
+            .add_event::<ComboTierReachedEvent>()
+            .add_systems(Startup, setup_combo_banner)
+            .add_systems(Update, apply_combo_difficulty.run_if(resource_changed::<DinoCombatConfig>))
+            .add_systems(Update, (
+                update_combo,
+                show_combo_banner,
+                update_combo_banner,
+            ).chain().run_if(in_state(GameState::Playing)));
     }
 }
 
@@ -75,3 +189,56 @@ fn update_combo(
 ) {
     combo.update(time.delta());
 }
+
+fn apply_combo_difficulty(combat: Res<DinoCombatConfig>, mut combo: ResMut<ComboSystem>) {
+    combo.set_decay_window(BASE_DECAY_WINDOW / combat.difficulty.max(0.1));
+}
+
+fn setup_combo_banner(mut commands: Commands) {
+    commands.spawn(Node {
+        position_type: PositionType::Absolute,
+        width: Val::Percent(100.0),
+        top: Val::Percent(25.0),
+        justify_content: JustifyContent::Center,
+        ..default()
+    }).with_children(|parent| {
+        let mut timer = Timer::from_seconds(1.5, TimerMode::Once);
+        timer.tick(timer.duration());
+
+        parent.spawn((
+            ComboBanner { timer, tier: ComboTier::None },
+            Text::new(""),
+            TextFont {
+                font_size: 56.0,
+                ..default()
+            },
+            TextColor(Color::NONE),
+        ));
+    });
+}
+
+fn show_combo_banner(
+    mut events: EventReader<ComboTierReachedEvent>,
+    mut banner_q: Query<(&mut ComboBanner, &mut Text)>,
+) {
+    for event in events.read() {
+        for (mut banner, mut text) in banner_q.iter_mut() {
+            text.0 = format!("{}! {}x COMBO", event.tier.name(), event.combo);
+            banner.tier = event.tier;
+            banner.timer.reset();
+        }
+    }
+}
+
+fn update_combo_banner(
+    time: Res<Time>,
+    mut banner_q: Query<(&mut ComboBanner, &mut TextColor)>,
+) {
+    for (mut banner, mut color) in banner_q.iter_mut() {
+        banner.timer.tick(time.delta());
+
+        // Fade out over the banner's lifetime
+        let alpha = 1.0 - banner.timer.fraction();
+        color.0 = banner.tier.color().with_alpha(alpha);
+    }
+}
@@ -0,0 +1,261 @@
+use bevy::prelude::*;
+use bevy::input::{ButtonState, InputSystem};
+use bevy::input::keyboard::{Key, KeyboardInput};
+use rand::Rng;
+use crate::dino::{
+    spawn_dinosaur, BodyPart, CoinSystem, DinoCombatConfig, DinoDeath, DinoHealth, DinoSpecies, Dinosaur,
+    RespawnDinosEvent,
+};
+use crate::pause::GameState;
+use crate::rng::GameRng;
+use crate::terrain::{TerrainSeed, GROUND_LEVEL};
+use crate::vehicle::PlayerVehicle;
+use crate::weapon::BulletHitEvent;
+
+/// Output lines kept on screen
+const LOG_LINES: usize = 10;
+/// Console spawns land this far from the vehicle
+const SPAWN_DISTANCE: std::ops::Range<f32> = 25.0..40.0;
+/// Most dinos a single spawn command will create
+const MAX_SPAWN_COUNT: u32 = 50;
+
+const HELP: &str = "spawn <species> [count] | give coins <n> | set difficulty <easy|normal|hard|x> | kill all | respawn | clear";
+
+/// Tilde-toggled command line for testing; gameplay keys are ignored while it is open
+#[derive(Resource, Default)]
+pub struct DebugConsole {
+    pub open: bool,
+    pub input: String,
+    pub log: Vec<String>,
+}
+
+impl DebugConsole {
+    fn print(&mut self, line: impl Into<String>) {
+        self.log.push(line.into());
+        if self.log.len() > LOG_LINES {
+            self.log.remove(0);
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct ConsolePanel;
+
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DebugConsole>()
+            .add_systems(Startup, setup_console)
+            // Right after input is gathered, so no gameplay system sees keys meant for the console
+            .add_systems(PreUpdate, (toggle_console, swallow_game_input)
+                .chain()
+                .after(InputSystem)
+                .run_if(in_state(GameState::Playing)))
+            .add_systems(Update, (
+                handle_console_typing,
+                update_console_panel,
+            ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn setup_console(mut commands: Commands) {
+    commands.spawn((
+        ConsolePanel,
+        Text::new(""),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.85, 0.95, 0.85)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(0.0),
+            left: Val::Px(0.0),
+            width: Val::Percent(100.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            display: Display::None,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        GlobalZIndex(20),
+    ));
+}
+
+fn toggle_console(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut console: ResMut<DebugConsole>,
+) {
+    if keyboard.just_pressed(KeyCode::Backquote) {
+        console.open = !console.open;
+        if console.open && console.log.is_empty() {
+            console.print(HELP);
+        }
+    }
+}
+
+/// Typing goes through `KeyboardInput` events, so the button state can be dropped wholesale;
+/// this covers keys and clicks read directly by systems outside `PlayerInput`
+fn swallow_game_input(
+    console: Res<DebugConsole>,
+    mut keyboard: ResMut<ButtonInput<KeyCode>>,
+    mut mouse: ResMut<ButtonInput<MouseButton>>,
+) {
+    if console.open {
+        keyboard.reset_all();
+        mouse.reset_all();
+    }
+}
+
+fn handle_console_typing(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut key_events: EventReader<KeyboardInput>,
+    mut console: ResMut<DebugConsole>,
+    mut coins: ResMut<CoinSystem>,
+    mut combat: ResMut<DinoCombatConfig>,
+    terrain: Res<TerrainSeed>,
+    vehicle_q: Query<&Transform, With<PlayerVehicle>>,
+    dino_q: Query<(Entity, &DinoHealth, &Transform), (With<Dinosaur>, Without<DinoDeath>)>,
+    mut hit_events: EventWriter<BulletHitEvent>,
+    mut respawn_events: EventWriter<RespawnDinosEvent>,
+    mut rng: ResMut<GameRng>,
+) {
+    if !console.open {
+        key_events.clear();
+        return;
+    }
+
+    for event in key_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        match &event.logical_key {
+            // The toggle key itself isn't part of the command
+            Key::Character(text) if event.key_code != KeyCode::Backquote => {
+                console.input.push_str(text);
+            }
+            Key::Space => console.input.push(' '),
+            Key::Backspace => {
+                console.input.pop();
+            }
+            Key::Enter => {
+                let line = std::mem::take(&mut console.input);
+                let line = line.trim().to_lowercase();
+                if line.is_empty() {
+                    continue;
+                }
+                console.print(format!("> {}", line));
+
+                let args: Vec<&str> = line.split_whitespace().collect();
+                let reply = match args.as_slice() {
+                    ["spawn", name, rest @ ..] => {
+                        let Some(species) = parse_species(name) else {
+                            console.print(format!("Unknown species '{}'", name));
+                            continue;
+                        };
+                        let count = match rest.first().map(|n| n.parse::<u32>()) {
+                            None => 1,
+                            Some(Ok(count)) => count.min(MAX_SPAWN_COUNT),
+                            Some(Err(_)) => {
+                                console.print("Count must be a number");
+                                continue;
+                            }
+                        };
+                        let center = vehicle_q.get_single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
+                        for _ in 0..count {
+                            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                            let distance = rng.gen_range(SPAWN_DISTANCE);
+                            let (x, z) = (center.x + angle.cos() * distance, center.z + angle.sin() * distance);
+                            let position = Vec3::new(x, terrain.ground_height(x, z) - GROUND_LEVEL, z);
+                            spawn_dinosaur(&mut commands, &mut meshes, &mut materials, species, position, false);
+                        }
+                        format!("Spawned {} {}", count, species.name())
+                    }
+                    ["give", "coins", amount] => match amount.parse::<u32>() {
+                        Ok(amount) => {
+                            coins.total_coins = coins.total_coins.saturating_add(amount);
+                            format!("Coins: {}", coins.total_coins)
+                        }
+                        Err(_) => "Amount must be a number".to_string(),
+                    },
+                    ["set", "difficulty", level] => {
+                        let difficulty = match *level {
+                            "easy" => Some(0.7),
+                            "normal" => Some(1.0),
+                            "hard" => Some(1.5),
+                            other => other.parse::<f32>().ok().filter(|d| *d > 0.0),
+                        };
+                        match difficulty {
+                            Some(difficulty) => {
+                                combat.difficulty = difficulty;
+                                format!("Difficulty set to {:.2}", difficulty)
+                            }
+                            None => "Difficulty is easy, normal, hard or a positive number".to_string(),
+                        }
+                    }
+                    // Lethal hits, so kills pay out and show up like any other
+                    ["kill", "all"] => {
+                        let mut killed = 0;
+                        for (entity, health, transform) in dino_q.iter().filter(|(_, health, _)| health.current > 0.0) {
+                            hit_events.send(BulletHitEvent {
+                                target: entity,
+                                damage: health.current,
+                                position: transform.translation,
+                                hit_part: BodyPart::Body,
+                                status: None,
+                            });
+                            killed += 1;
+                        }
+                        format!("Killed {} dinos", killed)
+                    }
+                    ["respawn"] => {
+                        respawn_events.send(RespawnDinosEvent { from_seed: false });
+                        "Respawning a herd".to_string()
+                    }
+                    ["clear"] => {
+                        console.log.clear();
+                        continue;
+                    }
+                    ["help"] => HELP.to_string(),
+                    _ => format!("Unknown command. {}", HELP),
+                };
+                console.print(reply);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Match a species by any part of its name, so "trex", "raptor" and "brachio" all work
+fn parse_species(name: &str) -> Option<DinoSpecies> {
+    let name = name.replace('-', "");
+    if name.len() < 3 {
+        return None;
+    }
+    DinoSpecies::ALL
+        .into_iter()
+        .find(|species| species.name().to_lowercase().replace('-', "").contains(&name))
+}
+
+fn update_console_panel(
+    console: Res<DebugConsole>,
+    mut panel_q: Query<(&mut Node, &mut Text), With<ConsolePanel>>,
+) {
+    if !console.is_changed() {
+        return;
+    }
+
+    for (mut node, mut text) in panel_q.iter_mut() {
+        node.display = if console.open { Display::Flex } else { Display::None };
+        if console.open {
+            let mut lines = console.log.join("\n");
+            if !lines.is_empty() {
+                lines.push('\n');
+            }
+            text.0 = format!("{}> {}_", lines, console.input);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::rng::GameRng;
+use crate::pause::GameState;
+use crate::dino::{CoinSystem, DinoHealth, DinoKilledEvent, DinoSpecies};
+use crate::kill_feed::KillFeedEvent;
+
+/// A reusable objective shape that contracts are drawn from
+#[derive(Clone, Copy)]
+pub struct ContractTemplate {
+    pub species: DinoSpecies,
+    pub count: u32,
+    pub reward: u32,
+}
+
+const CONTRACT_TEMPLATES: [ContractTemplate; 6] = [
+    ContractTemplate { species: DinoSpecies::Stegosaurus, count: 3, reward: 150 },
+    ContractTemplate { species: DinoSpecies::Velociraptor, count: 5, reward: 200 },
+    ContractTemplate { species: DinoSpecies::Triceratops, count: 3, reward: 150 },
+    ContractTemplate { species: DinoSpecies::Brachiosaurus, count: 2, reward: 250 },
+    ContractTemplate { species: DinoSpecies::Velociraptor, count: 10, reward: 450 },
+    ContractTemplate { species: DinoSpecies::TRex, count: 1, reward: 500 },
+];
+
+/// The active hunting objective
+#[derive(Resource)]
+pub struct HuntContract {
+    pub template: ContractTemplate,
+    pub progress: u32,
+    pub completed: u32,
+}
+
+impl Default for HuntContract {
+    fn default() -> Self {
+        Self {
+            template: CONTRACT_TEMPLATES[0],
+            progress: 0,
+            completed: 0,
+        }
+    }
+}
+
+impl HuntContract {
+    pub fn description(&self) -> String {
+        if self.template.species == DinoSpecies::TRex {
+            "Defeat the T-Rex".to_string()
+        } else {
+            format!("Kill {} {}", self.template.count, self.template.species.name())
+        }
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.progress >= self.template.count
+    }
+
+    /// Replace the contract with a different random template.
+    /// Boss contracts are only offered while a T-Rex is alive to hunt.
+    pub fn issue_next(&mut self, boss_alive: bool, rng: &mut impl Rng) {
+        let current = self.template;
+
+        let candidates: Vec<ContractTemplate> = CONTRACT_TEMPLATES.iter()
+            .copied()
+            .filter(|t| boss_alive || t.species != DinoSpecies::TRex)
+            .filter(|t| t.species != current.species || t.count != current.count)
+            .collect();
+
+        if let Some(template) = candidates.get(rng.gen_range(0..candidates.len().max(1))) {
+            self.template = *template;
+        }
+        self.progress = 0;
+    }
+}
+
+pub struct ContractPlugin;
+
+impl Plugin for ContractPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HuntContract>()
+            .add_systems(Update, track_contract_kills.run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn track_contract_kills(
+    mut events: EventReader<DinoKilledEvent>,
+    mut contract: ResMut<HuntContract>,
+    mut coins: ResMut<CoinSystem>,
+    mut kill_feed: EventWriter<KillFeedEvent>,
+    dino_q: Query<(&DinoSpecies, &DinoHealth)>,
+    mut rng: ResMut<GameRng>,
+) {
+    for event in events.read() {
+        // Babies don't count, or a contract could be filled by picking off a herd's young
+        if event.species != contract.template.species || event.baby {
+            continue;
+        }
+
+        contract.progress += 1;
+
+        if contract.is_complete() {
+            coins.total_coins += contract.template.reward;
+            contract.completed += 1;
+
+            kill_feed.send(KillFeedEvent::new(
+                format!("CONTRACT COMPLETE! +{} coins", contract.template.reward),
+                Color::srgb(0.4, 1.0, 0.5),
+            ));
+
+            let boss_alive = dino_q.iter()
+                .any(|(species, health)| *species == DinoSpecies::TRex && health.current > 0.0);
+            contract.issue_next(boss_alive, &mut *rng);
+        }
+    }
+}
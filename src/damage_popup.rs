@@ -1,5 +1,9 @@
 use bevy::prelude::*;
 use crate::pause::GameState;
+use crate::smoothing;
+
+/// How quickly damage numbers lose their upward drift, per second
+const POPUP_DRAG: f32 = 3.0;
 
 /// Floating damage number that appears when hitting enemies
 #[derive(Component)]
@@ -19,6 +23,17 @@ pub enum DamageType {
 #[derive(Component)]
 pub struct DamageText;
 
+/// Score gained on a kill, as UI text pinned above where the dino died
+#[derive(Component)]
+pub struct ScorePopup {
+    pub lifetime: Timer,
+    pub world_position: Vec3,
+}
+
+const SCORE_POPUP_LIFETIME: f32 = 1.2;
+/// How far the score text rises in world units over its lifetime
+const SCORE_POPUP_RISE: f32 = 3.0;
+
 pub struct DamagePopupPlugin;
 
 impl Plugin for DamagePopupPlugin {
@@ -26,6 +41,8 @@ impl Plugin for DamagePopupPlugin {
         app.add_systems(Update, (
             spawn_damage_popups,
             update_damage_popups,
+            spawn_score_popups,
+            update_score_popups,
         ).chain().run_if(in_state(GameState::Playing)));
     }
 }
@@ -33,7 +50,7 @@ impl Plugin for DamagePopupPlugin {
 /// Spawn damage numbers - call this when a hit occurs
 pub fn spawn_damage_popups(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
+    weapon_assets: Res<crate::weapon::WeaponAssets>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut damage_events: EventReader<crate::weapon::BulletHitEvent>,
 ) {
@@ -69,14 +86,16 @@ pub fn spawn_damage_popups(
                 lifetime: Timer::from_seconds(1.5, TimerMode::Once),
                 velocity: Vec3::new(0.0, 4.0, 0.0), // Float upward
             },
-            Mesh3d(meshes.add(Sphere { radius: size * 0.3 })),
+            // Shared unit sphere; the material stays per-popup since each one fades on its own
+            Mesh3d(weapon_assets.sphere.clone()),
             MeshMaterial3d(materials.add(StandardMaterial {
                 base_color: color,
                 emissive: LinearRgba::new(0.3, 0.3, 0.3, 1.0),
                 unlit: true,
                 ..default()
             })),
-            Transform::from_translation(event.position + Vec3::new(0.0, 1.0, 0.0)),
+            Transform::from_translation(event.position + Vec3::new(0.0, 1.0, 0.0))
+                .with_scale(Vec3::splat(size * 0.3)),
         ));
     }
 }
@@ -112,6 +131,80 @@ pub fn update_damage_popups(
         }
 
         // Slow down velocity
-        popup.velocity *= 0.95;
+        popup.velocity *= 1.0 - smoothing(POPUP_DRAG, dt);
+    }
+}
+
+/// Show "+score ×multiplier" where a dino was killed; the multiplier is left off when it's 1
+fn spawn_score_popups(
+    mut commands: Commands,
+    mut killed_events: EventReader<crate::dino::DinoKilledEvent>,
+) {
+    for event in killed_events.read() {
+        let label = if (event.multiplier - 1.0).abs() < 0.05 {
+            format!("+{}", event.score)
+        } else {
+            format!("+{} ×{:.1}", event.score, event.multiplier)
+        };
+
+        // Bigger multipliers get bigger, warmer text
+        let emphasis = (event.multiplier / 4.0).clamp(0.0, 1.0);
+
+        commands.spawn((
+            ScorePopup {
+                lifetime: Timer::from_seconds(SCORE_POPUP_LIFETIME, TimerMode::Once),
+                world_position: event.position + Vec3::Y * 2.0,
+            },
+            Text::new(label),
+            TextFont {
+                font_size: 22.0 + emphasis * 12.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.95 - emphasis * 0.4, 0.4 - emphasis * 0.3)),
+            Node {
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            // Hidden until the first update places it on screen
+            Visibility::Hidden,
+        ));
+    }
+}
+
+fn update_score_popups(
+    time: Res<Time>,
+    mut commands: Commands,
+    camera_q: Query<(&Camera, &GlobalTransform), With<crate::camera::MainCamera>>,
+    mut popup_q: Query<(Entity, &mut ScorePopup, &mut Node, &mut TextColor, &mut Visibility, &ComputedNode)>,
+) {
+    let Ok((camera, camera_transform)) = camera_q.get_single() else {
+        return;
+    };
+
+    for (entity, mut popup, mut node, mut color, mut visibility, computed) in popup_q.iter_mut() {
+        popup.lifetime.tick(time.delta());
+
+        if popup.lifetime.finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        let progress = popup.lifetime.fraction();
+        let world = popup.world_position + Vec3::Y * SCORE_POPUP_RISE * progress;
+
+        // Off-screen or behind the camera: keep it hidden
+        let Ok(screen) = camera.world_to_viewport(camera_transform, world) else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        // Center the text on the point
+        let size = computed.size() * computed.inverse_scale_factor();
+        node.left = Val::Px(screen.x - size.x / 2.0);
+        node.top = Val::Px(screen.y - size.y / 2.0);
+        *visibility = Visibility::Inherited;
+
+        // Hold, then fade over the last half
+        color.0.set_alpha((2.0 - progress * 2.0).min(1.0));
     }
 }
@@ -0,0 +1,110 @@
+use bevy::prelude::*;
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy_rapier3d::prelude::DebugRenderContext;
+use crate::dino::Dinosaur;
+use crate::rng::GameRng;
+use crate::weapon::Bullet;
+
+/// Corner text with frame timing, entity counts and the game seed, toggled with F3
+#[derive(Component)]
+pub struct DebugOverlay;
+
+pub struct DebugOverlayPlugin;
+
+impl Plugin for DebugOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(FrameTimeDiagnosticsPlugin)
+            .add_systems(Startup, setup_debug_overlay)
+            .add_systems(Update, (
+                toggle_debug_overlay,
+                update_debug_overlay,
+            ).chain());
+
+        if cfg!(debug_assertions) {
+            app.add_systems(Update, toggle_physics_debug_render);
+        }
+    }
+}
+
+fn setup_debug_overlay(mut commands: Commands) {
+    commands.spawn((
+        DebugOverlay,
+        Text::new(""),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.6, 1.0, 0.6)),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(60.0),
+            left: Val::Px(20.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            display: Display::None,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        GlobalZIndex(10),
+    ));
+}
+
+fn toggle_debug_overlay(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut overlay_q: Query<&mut Node, With<DebugOverlay>>,
+) {
+    if !keyboard.just_pressed(KeyCode::F3) {
+        return;
+    }
+
+    for mut node in overlay_q.iter_mut() {
+        node.display = match node.display {
+            Display::None => Display::Flex,
+            _ => Display::None,
+        };
+    }
+}
+
+/// F4 shows or hides the Rapier collider wireframes
+fn toggle_physics_debug_render(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut debug_render: ResMut<DebugRenderContext>,
+) {
+    if keyboard.just_pressed(KeyCode::F4) {
+        debug_render.enabled = !debug_render.enabled;
+    }
+}
+
+fn update_debug_overlay(
+    diagnostics: Res<DiagnosticsStore>,
+    mut overlay_q: Query<(&Node, &mut Text), With<DebugOverlay>>,
+    entity_q: Query<Entity>,
+    bullet_q: Query<(), With<Bullet>>,
+    dino_q: Query<(), With<Dinosaur>>,
+    rng: Res<GameRng>,
+) {
+    let Ok((node, mut text)) = overlay_q.get_single_mut() else {
+        return;
+    };
+
+    // Skip the counting while hidden
+    if node.display == Display::None {
+        return;
+    }
+
+    let fps = diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|d| d.smoothed())
+        .unwrap_or(0.0);
+    let frame_time = diagnostics.get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|d| d.smoothed())
+        .unwrap_or(0.0);
+
+    text.0 = format!(
+        "FPS: {:.0} ({:.1} ms)\nEntities: {}\nBullets: {}\nDinos: {}\nSeed: {}",
+        fps,
+        frame_time,
+        entity_q.iter().count(),
+        bullet_q.iter().count(),
+        dino_q.iter().count(),
+        rng.seed(),
+    );
+}
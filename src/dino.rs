@@ -1,10 +1,21 @@
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use crate::weapon::BulletHitEvent;
-use crate::GameScore;
+use crate::{smoothing, GameScore};
 use crate::pause::GameState;
-use crate::combo::ComboSystem;
+use crate::combo::{ComboSystem, ComboTierReachedEvent};
+use crate::kill_feed::KillFeedEvent;
+use crate::environment::Obstacle;
+use crate::stealth::PlayerNoise;
+use crate::coins::CoinDropEvent;
+use crate::bestiary::Bestiary;
+use crate::tow::Towed;
+use crate::terrain::{TerrainSeed, GROUND_LEVEL};
+use crate::rng::{GameRng, DINO_LAYOUT};
+use crate::status_effects::StatusEffects;
 
 #[derive(Resource)]
 pub struct CoinSystem {
@@ -22,7 +33,7 @@ pub struct DinoPlugin;
 #[derive(Component)]
 pub struct Dinosaur;
 
-#[derive(Component, Clone, Copy, PartialEq)]
+#[derive(Component, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DinoSpecies {
     Triceratops,
     Velociraptor,
@@ -31,6 +42,110 @@ pub enum DinoSpecies {
     TRex, // Boss
 }
 
+impl DinoSpecies {
+    pub const ALL: [DinoSpecies; 5] = [
+        DinoSpecies::Triceratops,
+        DinoSpecies::Velociraptor,
+        DinoSpecies::Brachiosaurus,
+        DinoSpecies::Stegosaurus,
+        DinoSpecies::TRex,
+    ];
+
+    pub fn name(&self) -> &str {
+        match self {
+            DinoSpecies::Triceratops => "Triceratops",
+            DinoSpecies::Velociraptor => "Velociraptor",
+            DinoSpecies::Brachiosaurus => "Brachiosaurus",
+            DinoSpecies::Stegosaurus => "Stegosaurus",
+            DinoSpecies::TRex => "T-Rex",
+        }
+    }
+
+    /// Big, tough species get a glowing head so players learn to aim for it
+    pub fn has_weak_point(&self) -> bool {
+        matches!(self, DinoSpecies::Brachiosaurus | DinoSpecies::TRex)
+    }
+
+    pub fn is_carnivore(&self) -> bool {
+        matches!(self, DinoSpecies::Velociraptor | DinoSpecies::TRex)
+    }
+
+    /// Chance of running for cover instead of fleeing in a straight line
+    pub fn cover_seeking_chance(&self) -> f64 {
+        match self {
+            DinoSpecies::Stegosaurus | DinoSpecies::Triceratops => 0.9,
+            DinoSpecies::Brachiosaurus => 0.4,
+            DinoSpecies::Velociraptor | DinoSpecies::TRex => 0.1,
+        }
+    }
+
+    /// Distinct marker color for map displays
+    pub fn map_color(&self) -> Color {
+        match self {
+            DinoSpecies::Triceratops => Color::srgb(0.9, 0.6, 0.2),
+            DinoSpecies::Velociraptor => Color::srgb(0.9, 0.2, 0.2),
+            DinoSpecies::Brachiosaurus => Color::srgb(0.4, 0.8, 0.9),
+            DinoSpecies::Stegosaurus => Color::srgb(0.6, 0.9, 0.3),
+            DinoSpecies::TRex => Color::srgb(0.8, 0.2, 0.9),
+        }
+    }
+
+    /// Health of a regular adult
+    pub fn base_health(&self) -> f32 {
+        match self {
+            DinoSpecies::Triceratops => 150.0,
+            DinoSpecies::Velociraptor => 60.0,
+            DinoSpecies::Brachiosaurus => 300.0,
+            DinoSpecies::Stegosaurus => 200.0,
+            DinoSpecies::TRex => 500.0,
+        }
+    }
+
+    /// Movement speed of a regular adult
+    pub fn base_speed(&self) -> f32 {
+        match self {
+            DinoSpecies::Triceratops => 8.0,
+            DinoSpecies::Velociraptor => 15.0,
+            DinoSpecies::Brachiosaurus => 4.0,
+            DinoSpecies::Stegosaurus => 6.0,
+            DinoSpecies::TRex => 10.0,
+        }
+    }
+
+    /// Base score and coins for a kill, before alpha, hit part and combo bonuses
+    pub fn kill_reward(&self) -> (u32, u32) {
+        match self {
+            DinoSpecies::Velociraptor => (150, 15),
+            DinoSpecies::Triceratops => (200, 20),
+            DinoSpecies::Stegosaurus => (175, 25),
+            DinoSpecies::Brachiosaurus => (400, 30),
+            DinoSpecies::TRex => (1000, 100), // Boss gives huge rewards
+        }
+    }
+
+    /// Rough ground footprint radius, used to keep dinos from overlapping
+    pub fn body_radius(&self) -> f32 {
+        match self {
+            DinoSpecies::Triceratops => 1.25,
+            DinoSpecies::Velociraptor => 0.6,
+            DinoSpecies::Brachiosaurus => 2.0,
+            DinoSpecies::Stegosaurus => 1.5,
+            DinoSpecies::TRex => 1.75,
+        }
+    }
+}
+
+/// How far a fleeing dino will look for an obstacle to hide behind
+const COVER_SEARCH_RADIUS: f32 = 40.0;
+/// Distance behind the obstacle (away from the vehicle) a dino hides at
+const COVER_OFFSET: f32 = 3.5;
+
+/// Position samples tried per dino before giving up on a spawn area that is nearly all exclusion zone
+const MAX_SPAWN_ATTEMPTS: u32 = 32;
+
+/// Fraction of an overlap resolved per frame, below 1.0 so dinos ease apart instead of popping
+const SEPARATION_STIFFNESS: f32 = 0.5;
+
 #[derive(Component)]
 pub struct DinoHealth {
     pub current: f32,
@@ -49,14 +164,87 @@ pub struct HitBox {
     pub part: BodyPart,
 }
 
+/// Marks a dino's head mesh, remembering where it sits when not animated
+#[derive(Component)]
+pub struct DinoHead {
+    pub rest: Vec3,
+}
+
+/// Herbivore idle sub-state: pauses and dips its head to graze
+#[derive(Component)]
+pub struct Grazing {
+    pub timer: Timer,
+}
+
+/// Distance-based level of detail for dino meshes
+#[derive(Resource)]
+pub struct DinoLodConfig {
+    pub detail_distance: f32, // Beyond this only the body mesh is drawn
+}
+
+impl Default for DinoLodConfig {
+    fn default() -> Self {
+        Self { detail_distance: 120.0 }
+    }
+}
+
+/// Whether a dino currently shows its head and legs
+#[derive(Component)]
+pub struct DinoLod {
+    pub detailed: bool,
+}
+
+/// A scaled-down juvenile that tags along with an adult
+#[derive(Component)]
+pub struct Baby;
+
+/// Keeps a baby trailing its parent; removed when the parent dies
+#[derive(Component)]
+pub struct FollowParent {
+    pub target: Entity,
+}
+
+/// Glowing head on tough species; `flash` spikes on a headshot and fades out
+#[derive(Component)]
+pub struct WeakPoint {
+    pub flash: f32,
+}
+
+/// Weak point glow at rest, pulsing between these two strengths
+const WEAK_POINT_GLOW_MIN: f32 = 0.25;
+const WEAK_POINT_GLOW_MAX: f32 = 0.6;
+/// Extra glow right after a headshot
+const WEAK_POINT_FLASH_GLOW: f32 = 4.0;
+const WEAK_POINT_FLASH_DECAY: f32 = 5.0;
+
+/// A rare buffed variant: bigger, pale gold, tougher, faster and worth more
+#[derive(Component)]
+pub struct Alpha;
+
+/// Chance that a regular adult spawns as an alpha; rarer than the T-Rex roll
+const ALPHA_CHANCE: f64 = 0.02;
+const ALPHA_SCALE: f32 = 1.4;
+const ALPHA_HEALTH_MULTIPLIER: f32 = 2.5;
+const ALPHA_SPEED_MULTIPLIER: f32 = 1.3;
+const ALPHA_SCORE_MULTIPLIER: u32 = 2;
+const ALPHA_COIN_MULTIPLIER: u32 = 3;
+
+/// Chance that a spawned herbivore brings babies along
+const BABY_CHANCE: f64 = 0.2;
+const BABY_SCALE: f32 = 0.45;
+const BABY_HEALTH: f32 = 30.0;
+/// Babies stop closing in once this near their parent
+const FOLLOW_DISTANCE: f32 = 4.0;
+
 #[derive(Component)]
 pub struct DinoAI {
     pub state: AIState,
     pub wander_target: Option<Vec3>,
     pub flee_direction: Vec3,
+    pub flee_target: Option<Vec3>, // Cover spot to hide at instead of fleeing in a straight line
     pub move_speed: f32,
+    /// Time until the next attack; its length comes from `DinoCombatConfig` each time it restarts
     pub attack_cooldown: Timer,
-    pub attack_range: f32,
 }
 
 impl Default for DinoAI {
@@ -65,14 +253,14 @@ impl Default for DinoAI {
             state: AIState::Roam,
             wander_target: None,
             flee_direction: Vec3::ZERO,
+            flee_target: None,
             move_speed: 10.0,
             attack_cooldown: Timer::from_seconds(2.0, TimerMode::Once),
-            attack_range: 15.0,
         }
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AIState {
     Idle,
     Roam,
@@ -81,6 +269,26 @@ pub enum AIState {
     Dead,
 }
 
+/// How quickly a moving dino turns to face where it's heading, per second
+const TURN_RATE: f32 = 6.0;
+
+/// Seconds a carnivore rears up before charging, giving the player time to react
+const ATTACK_WINDUP_TIME: f32 = 0.5;
+
+/// Attack sub-state: present while a dino is in AIState::Attack
+#[derive(Component)]
+pub struct Attacking {
+    pub windup: Timer,
+}
+
+impl Attacking {
+    pub fn new() -> Self {
+        Self {
+            windup: Timer::from_seconds(ATTACK_WINDUP_TIME, TimerMode::Once),
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct DinoDeath {
     timer: Timer,
@@ -91,6 +299,10 @@ pub struct DinoSpawnConfig {
     pub count: u32,
     pub spawn_radius: f32,
     pub min_distance_from_player: f32,
+    /// Relative chance of each species for a regular spawn; missing or zero-weight species never appear
+    pub species_weights: Vec<(DinoSpecies, f32)>,
+    /// Chance the first dino of a herd is a T-Rex boss
+    pub boss_chance: f64,
 }
 
 impl Default for DinoSpawnConfig {
@@ -99,91 +311,304 @@ impl Default for DinoSpawnConfig {
             count: 15,
             spawn_radius: 150.0,
             min_distance_from_player: 20.0,
+            species_weights: vec![
+                (DinoSpecies::Triceratops, 2.0), // Weight toward Triceratops
+                (DinoSpecies::Velociraptor, 1.0),
+                (DinoSpecies::Brachiosaurus, 1.0),
+                (DinoSpecies::Stegosaurus, 1.0),
+            ],
+            boss_chance: 0.3,
+        }
+    }
+}
+
+impl DinoSpawnConfig {
+    /// Roll a species from the weights; the first dino of a herd may roll the boss instead
+    pub fn pick_species(&self, rng: &mut impl Rng, first: bool) -> DinoSpecies {
+        if first && rng.gen_bool(self.boss_chance.clamp(0.0, 1.0)) {
+            return DinoSpecies::TRex;
+        }
+
+        let total: f32 = self.species_weights.iter().map(|(_, weight)| weight.max(0.0)).sum();
+        if total <= 0.0 {
+            return DinoSpecies::Triceratops;
+        }
+
+        let mut roll = rng.gen_range(0.0..total);
+        for (species, weight) in &self.species_weights {
+            let weight = weight.max(0.0);
+            if roll < weight {
+                return *species;
+            }
+            roll -= weight;
+        }
+        self.species_weights.last().map_or(DinoSpecies::Triceratops, |(species, _)| *species)
+    }
+}
+
+/// Dino AI tuning, read by the AI and movement systems every frame
+#[derive(Resource)]
+pub struct DinoAIConfig {
+    /// Distance at which herbivores notice the vehicle at normal noise
+    pub flee_detection_range: f32,
+    /// Fleeing dinos calm down and roam again past this distance
+    pub calm_distance: f32,
+    /// A hiding dino bolts once the vehicle gets this close
+    pub cover_break_distance: f32,
+    /// Per-second chance that a roaming herbivore stops to graze
+    pub graze_chance_per_second: f64,
+    /// How long a grazing stop lasts, in seconds
+    pub graze_seconds: std::ops::Range<f32>,
+    /// How far away a new wander target is picked
+    pub wander_distance: std::ops::Range<f32>,
+    /// A wander target counts as reached inside this distance
+    pub wander_arrive_distance: f32,
+    /// Speed multiplier for a charge once the windup is over
+    pub attack_speed_boost: f32,
+    /// Speed multiplier for babies catching up with their parent
+    pub baby_follow_speed_boost: f32,
+}
+
+impl Default for DinoAIConfig {
+    fn default() -> Self {
+        Self {
+            flee_detection_range: 30.0,
+            calm_distance: 60.0,
+            cover_break_distance: 15.0,
+            graze_chance_per_second: 0.05,
+            graze_seconds: 2.0..5.0,
+            wander_distance: 20.0..50.0,
+            wander_arrive_distance: 5.0,
+            attack_speed_boost: 1.5,
+            baby_follow_speed_boost: 1.2,
+        }
+    }
+}
+
+/// One species' attack tuning
+#[derive(Clone, Copy)]
+pub struct DinoAttackStats {
+    pub damage: f32,
+    /// Seconds between attacks
+    pub cooldown: f32,
+    /// Distance at which the dino commits to an attack; 0 = never attacks
+    pub aggro_range: f32,
+    /// Once wounded, the dino charges a vehicle that comes this close; 0 = never
+    pub charge_range: f32,
+    /// Damage from bumping into the dino, at full ramming speed; 0 = harmless to touch
+    pub contact_damage: f32,
+}
+
+impl DinoAttackStats {
+    /// Attackers give up past this distance (times `give_up_multiplier`)
+    pub fn engage_range(&self) -> f32 {
+        self.aggro_range.max(self.charge_range)
+    }
+}
+
+/// Extra reach added to a dino's body radius for contact damage, roughly the vehicle's half-width
+const VEHICLE_CONTACT_RADIUS: f32 = 2.0;
+/// Share of contact damage dealt even at a crawl, i.e. being stepped on
+const STOMP_DAMAGE_FLOOR: f32 = 0.4;
+
+/// Dino attack tuning by species, read at attack time
+#[derive(Resource)]
+pub struct DinoCombatConfig {
+    pub stats: HashMap<DinoSpecies, DinoAttackStats>,
+    /// Bites land inside this distance of the vehicle
+    pub contact_distance: f32,
+    /// Attackers give up once the vehicle is this many aggro ranges away
+    pub give_up_multiplier: f32,
+    /// Scales damage up and cooldowns down; 1 = normal
+    pub difficulty: f32,
+}
+
+impl Default for DinoCombatConfig {
+    fn default() -> Self {
+        let stats = |damage, aggro_range, charge_range, contact_damage| DinoAttackStats {
+            damage,
+            cooldown: 2.0,
+            aggro_range,
+            charge_range,
+            contact_damage,
+        };
+        Self {
+            stats: HashMap::from([
+                (DinoSpecies::Triceratops, stats(15.0, 0.0, 12.0, 0.0)), // Charges when cornered
+                (DinoSpecies::Velociraptor, stats(10.0, 20.0, 0.0, 0.0)),
+                (DinoSpecies::Brachiosaurus, stats(5.0, 0.0, 0.0, 20.0)), // Tramples what runs into it
+                (DinoSpecies::Stegosaurus, stats(5.0, 0.0, 0.0, 0.0)),
+                (DinoSpecies::TRex, stats(25.0, 20.0, 0.0, 0.0)),
+            ]),
+            contact_distance: 3.0,
+            give_up_multiplier: 1.5,
+            difficulty: 1.0,
         }
     }
 }
 
+impl DinoCombatConfig {
+    /// Species missing from the table never attack
+    pub fn stats(&self, species: DinoSpecies) -> DinoAttackStats {
+        self.stats.get(&species).copied().unwrap_or(DinoAttackStats {
+            damage: 0.0,
+            cooldown: 2.0,
+            aggro_range: 0.0,
+            charge_range: 0.0,
+            contact_damage: 0.0,
+        })
+    }
+
+    pub fn damage(&self, species: DinoSpecies) -> f32 {
+        self.stats(species).damage * self.difficulty
+    }
+
+    /// Contact damage for a vehicle moving at `ram` (0 = stopped, 1 = top speed)
+    pub fn contact_damage(&self, species: DinoSpecies, ram: f32) -> f32 {
+        let ram = ram.clamp(0.0, 1.0);
+        self.stats(species).contact_damage * (STOMP_DAMAGE_FLOOR + (1.0 - STOMP_DAMAGE_FLOOR) * ram) * self.difficulty
+    }
+
+    pub fn cooldown(&self, species: DinoSpecies) -> std::time::Duration {
+        std::time::Duration::from_secs_f32(self.stats(species).cooldown / self.difficulty.max(0.1))
+    }
+}
+
 impl Plugin for DinoPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DinoSpawnConfig>()
+            .init_resource::<DinoCombatConfig>()
+            .init_resource::<DinoAIConfig>()
+            .init_resource::<DinoLodConfig>()
             .init_resource::<CoinSystem>()
             .add_event::<RespawnDinosEvent>()
             .add_event::<DinoAttackEvent>()
+            .add_event::<DinoKilledEvent>()
             .add_systems(Startup, spawn_dinosaurs)
             .add_systems(Update, (
                 handle_bullet_hits,
                 handle_respawn_dinos,
                 update_damage_reaction,
+                update_orphaned_babies,
                 update_dino_ai,
+                update_grazing,
                 update_dino_movement,
+                apply_dino_separation,
+                follow_terrain,
+                update_weak_points,
                 process_dino_attacks,
                 check_dino_death,
                 update_dino_death_animation,
+                update_dino_lod,
             ).chain().run_if(in_state(GameState::Playing)));
     }
 }
 
 #[derive(Event)]
-pub struct RespawnDinosEvent;
+pub struct RespawnDinosEvent {
+    /// Lay the herd out from the seed like a fresh hunt, rather than from the running stream
+    pub from_seed: bool,
+}
 
 #[derive(Event)]
 pub struct DinoAttackEvent {
     pub damage: f32,
+    /// Horizontal shove on the vehicle, away from the biter
+    pub knockback: Vec3,
+}
+
+/// Knockback speed per unit of the biter's body radius
+const KNOCKBACK_PER_RADIUS: f32 = 12.0;
+
+/// Event fired once when a dinosaur is killed
+#[derive(Event)]
+pub struct DinoKilledEvent {
+    pub entity: Entity,
+    pub species: DinoSpecies,
+    pub position: Vec3,
+    pub score: u32,
+    pub combo: u32,
+    /// Alpha, hit part and combo multipliers folded into `score`
+    pub multiplier: f32,
+    pub baby: bool,
 }
 
 fn spawn_dinosaurs(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    config: Res<DinoSpawnConfig>,
+    terrain: Res<TerrainSeed>,
+    rng: Res<GameRng>,
 ) {
-    let mut rng = rand::thread_rng();
-
-    // Spawn dinosaurs (now 5 species)
-    for i in 0..15 {
-        // Spawn T-Rex Boss only once (first dinosaur)
-        let species = if i == 0 && rng.gen_range(0..10) < 3 {
-            // 30% chance for T-Rex to spawn as first dinosaur
-            DinoSpecies::TRex
-        } else {
-            match rng.gen_range(0..5) {
-                0 => DinoSpecies::Triceratops,
-                1 => DinoSpecies::Velociraptor,
-                2 => DinoSpecies::Brachiosaurus,
-                3 => DinoSpecies::Stegosaurus,
-                _ => DinoSpecies::Triceratops, // Weight toward Triceratops
-            }
-        };
+    let mut layout = rng.layout(DINO_LAYOUT);
+    spawn_n_dinosaurs(&mut commands, &mut meshes, &mut materials, &mut layout, config.count, &config, &terrain);
+}
 
-        let x: f32 = rng.gen_range(-150.0..150.0);
-        let z: f32 = rng.gen_range(-150.0..150.0);
+/// Spawn `count` dinos with species and placement from the spawn config
+fn spawn_n_dinosaurs(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    rng: &mut impl Rng,
+    count: u32,
+    config: &DinoSpawnConfig,
+    terrain: &TerrainSeed,
+) {
+    for i in 0..count {
+        let species = config.pick_species(rng, i == 0);
 
-        // Don't spawn too close to origin
-        if x.abs() < 20.0 && z.abs() < 20.0 {
-            continue;
-        }
+        let Some((x, z)) = sample_spawn_position(rng, config) else {
+            warn!("No valid dino spawn position after {} attempts; spawn area is too small", MAX_SPAWN_ATTEMPTS);
+            return;
+        };
 
-        spawn_dinosaur(&mut commands, &mut meshes, &mut materials, species, Vec3::new(x, 0.0, z));
+        let position = Vec3::new(x, terrain.ground_height(x, z) - GROUND_LEVEL, z);
+        let alpha = species != DinoSpecies::TRex && rng.gen_bool(ALPHA_CHANCE);
+        let dino = spawn_dinosaur(commands, meshes, materials, species, position, alpha);
+        maybe_spawn_babies(commands, meshes, materials, rng, species, dino, position);
     }
 }
 
-fn spawn_dinosaur(
+pub fn spawn_dinosaur(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
     species: DinoSpecies,
     position: Vec3,
-) {
-    let (body_color, size, health, speed) = match species {
-        DinoSpecies::Triceratops => (Color::srgb(0.5, 0.35, 0.2), Vec3::new(1.5, 1.2, 2.5), 150.0, 8.0),
-        DinoSpecies::Velociraptor => (Color::srgb(0.4, 0.3, 0.25), Vec3::new(0.6, 0.5, 1.2), 60.0, 15.0),
-        DinoSpecies::Brachiosaurus => (Color::srgb(0.45, 0.4, 0.3), Vec3::new(2.5, 4.0, 4.0), 300.0, 4.0),
-        DinoSpecies::Stegosaurus => (Color::srgb(0.35, 0.4, 0.25), Vec3::new(1.8, 1.0, 3.0), 200.0, 6.0),
-        DinoSpecies::TRex => (Color::srgb(0.5, 0.3, 0.2), Vec3::new(2.2, 2.0, 3.5), 500.0, 10.0),
+    alpha: bool,
+) -> Entity {
+    let (body_color, size) = match species {
+        DinoSpecies::Triceratops => (Color::srgb(0.5, 0.35, 0.2), Vec3::new(1.5, 1.2, 2.5)),
+        DinoSpecies::Velociraptor => (Color::srgb(0.4, 0.3, 0.25), Vec3::new(0.6, 0.5, 1.2)),
+        DinoSpecies::Brachiosaurus => (Color::srgb(0.45, 0.4, 0.3), Vec3::new(2.5, 4.0, 4.0)),
+        DinoSpecies::Stegosaurus => (Color::srgb(0.35, 0.4, 0.25), Vec3::new(1.8, 1.0, 3.0)),
+        DinoSpecies::TRex => (Color::srgb(0.5, 0.3, 0.2), Vec3::new(2.2, 2.0, 3.5)),
+    };
+    let (health, speed) = (species.base_health(), species.base_speed());
+
+    let (body_material, head_material, leg_material) = if alpha {
+        // Pale gold hide with a faint glow so alphas stand out in the herd
+        let mut hide = |color: Color| materials.add(StandardMaterial {
+            base_color: color,
+            emissive: LinearRgba::rgb(0.25, 0.2, 0.05),
+            metallic: 0.3,
+            ..default()
+        });
+        (hide(Color::srgb(0.95, 0.88, 0.65)), hide(Color::srgb(0.9, 0.8, 0.55)), hide(Color::srgb(0.85, 0.75, 0.5)))
+    } else {
+        (
+            materials.add(body_color),
+            materials.add(Color::srgb(0.45, 0.32, 0.18)),
+            materials.add(Color::srgb(0.42, 0.28, 0.16)),
+        )
     };
 
-    let body_material = materials.add(body_color);
-    let head_material = materials.add(Color::srgb(0.45, 0.32, 0.18));
-    let leg_material = materials.add(Color::srgb(0.42, 0.28, 0.16));
+    let (health, speed) = if alpha {
+        (health * ALPHA_HEALTH_MULTIPLIER, speed * ALPHA_SPEED_MULTIPLIER)
+    } else {
+        (health, speed)
+    };
 
     // Main body
     let dino_entity = commands.spawn((
@@ -197,19 +622,25 @@ fn spawn_dinosaur(
             state: AIState::Roam,
             wander_target: None,
             flee_direction: Vec3::ZERO,
+            flee_target: None,
             move_speed: speed,
+            // Grace period before a fresh spawn can attack
             attack_cooldown: Timer::from_seconds(2.0, TimerMode::Once),
-            attack_range: if species == DinoSpecies::Velociraptor || species == DinoSpecies::TRex {
-                20.0 // Aggressive dinos have longer attack range
-            } else {
-                0.0 // Other dinos don't attack
-            },
         },
+        DinoLod { detailed: true },
+        StatusEffects::default(),
         Transform::from_translation(position),
         RigidBody::KinematicPositionBased,
         Collider::cuboid(size.x * 0.5, size.y * 0.5, size.z * 0.5),
     )).id();
 
+    if alpha {
+        commands.entity(dino_entity).insert((
+            Alpha,
+            Transform::from_translation(position).with_scale(Vec3::splat(ALPHA_SCALE)),
+        ));
+    }
+
     // Body mesh
     commands.spawn((
         Mesh3d(meshes.add(Capsule3d::new(size.x * 0.4, size.z * 0.6))),
@@ -228,12 +659,25 @@ fn spawn_dinosaur(
         DinoSpecies::TRex => Vec3::new(0.0, size.y * 0.75, size.z * 0.45),
     };
 
-    commands.spawn((
+    let head = commands.spawn((
         Mesh3d(meshes.add(Sphere { radius: head_size })),
         MeshMaterial3d(head_material.clone()),
         Transform::from_translation(head_pos),
         HitBox { part: BodyPart::Head },
-    )).set_parent(dino_entity);
+        DinoHead { rest: head_pos },
+    )).set_parent(dino_entity).id();
+
+    // Own material per head so each one can flash on its own
+    if species.has_weak_point() {
+        commands.entity(head).insert((
+            WeakPoint { flash: 0.0 },
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: Color::srgb(0.6, 0.3, 0.15),
+                emissive: LinearRgba::rgb(1.0, 0.45, 0.1) * WEAK_POINT_GLOW_MIN,
+                ..default()
+            })),
+        ));
+    }
 
     // Legs
     let leg_positions = [
@@ -256,6 +700,69 @@ fn spawn_dinosaur(
             HitBox { part: BodyPart::Legs },
         )).set_parent(dino_entity);
     }
+
+    dino_entity
+}
+
+/// Pick a spot inside the spawn square but away from the player's start, retrying a bounded number of times
+fn sample_spawn_position(rng: &mut impl Rng, config: &DinoSpawnConfig) -> Option<(f32, f32)> {
+    if config.spawn_radius <= 0.0 {
+        return None;
+    }
+
+    (0..MAX_SPAWN_ATTEMPTS).find_map(|_| {
+        let x: f32 = rng.gen_range(-config.spawn_radius..config.spawn_radius);
+        let z: f32 = rng.gen_range(-config.spawn_radius..config.spawn_radius);
+
+        // Don't spawn too close to origin
+        let near_origin = x.abs() < config.min_distance_from_player && z.abs() < config.min_distance_from_player;
+        (!near_origin).then_some((x, z))
+    })
+}
+
+/// Sometimes spawn 1-2 babies trailing a freshly spawned herbivore
+fn maybe_spawn_babies(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    rng: &mut impl Rng,
+    species: DinoSpecies,
+    parent: Entity,
+    position: Vec3,
+) {
+    if species.is_carnivore() || !rng.gen_bool(BABY_CHANCE) {
+        return;
+    }
+
+    for _ in 0..rng.gen_range(1..=2) {
+        let offset = Vec3::new(rng.gen_range(-3.0..3.0), 0.0, rng.gen_range(-3.0..3.0));
+        spawn_baby(commands, meshes, materials, species, Some(parent), position + offset);
+    }
+}
+
+/// A juvenile of `species`, trailing `parent` if it still has one
+pub fn spawn_baby(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    species: DinoSpecies,
+    parent: Option<Entity>,
+    position: Vec3,
+) -> Entity {
+    let baby = spawn_dinosaur(commands, meshes, materials, species, position, false);
+
+    if let Some(parent) = parent {
+        commands.entity(baby).insert(FollowParent { target: parent });
+    }
+    commands.entity(baby).insert((
+        Baby,
+        DinoHealth {
+            current: BABY_HEALTH,
+            max: BABY_HEALTH,
+        },
+        Transform::from_translation(position).with_scale(Vec3::splat(BABY_SCALE)),
+    ));
+    baby
 }
 
 fn handle_respawn_dinos(
@@ -264,33 +771,15 @@ fn handle_respawn_dinos(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     config: Res<DinoSpawnConfig>,
+    terrain: Res<TerrainSeed>,
+    mut rng: ResMut<GameRng>,
 ) {
-    for _event in events.read() {
-        let mut rng = rand::thread_rng();
-
-        for i in 0..config.count {
-            // First dinosaur might be a T-Rex
-            let species = if i == 0 && rng.gen_range(0..10) < 3 {
-                DinoSpecies::TRex
-            } else {
-                match rng.gen_range(0..5) {
-                    0 => DinoSpecies::Triceratops,
-                    1 => DinoSpecies::Velociraptor,
-                    2 => DinoSpecies::Brachiosaurus,
-                    3 => DinoSpecies::Stegosaurus,
-                    _ => DinoSpecies::Triceratops,
-                }
-            };
-
-            let x: f32 = rng.gen_range(-config.spawn_radius..config.spawn_radius);
-            let z: f32 = rng.gen_range(-config.spawn_radius..config.spawn_radius);
-
-            // Don't spawn too close to origin
-            if x.abs() < config.min_distance_from_player && z.abs() < config.min_distance_from_player {
-                continue;
-            }
-
-            spawn_dinosaur(&mut commands, &mut meshes, &mut materials, species, Vec3::new(x, 0.0, z));
+    for event in events.read() {
+        if event.from_seed {
+            let mut layout = rng.layout(DINO_LAYOUT);
+            spawn_n_dinosaurs(&mut commands, &mut meshes, &mut materials, &mut layout, config.count, &config, &terrain);
+        } else {
+            spawn_n_dinosaurs(&mut commands, &mut meshes, &mut materials, &mut *rng, config.count, &config, &terrain);
         }
     }
 }
@@ -298,18 +787,24 @@ fn handle_respawn_dinos(
 fn handle_bullet_hits(
     mut commands: Commands,
     mut events: EventReader<BulletHitEvent>,
-    mut dino_q: Query<(&mut DinoHealth, &mut DinoAI, &DinoSpecies)>,
+    mut dino_q: Query<(&mut DinoHealth, &mut DinoAI, &DinoSpecies, Option<&Baby>, Option<&Alpha>, &Transform)>,
     mut score: ResMut<GameScore>,
     mut combo: ResMut<ComboSystem>,
-    mut coins: ResMut<CoinSystem>,
+    mut coin_drops: EventWriter<CoinDropEvent>,
     mut time_attack: ResMut<crate::game_mode::TimeAttackMode>,
     _meshes: ResMut<Assets<Mesh>>,
     _materials: ResMut<Assets<StandardMaterial>>,
     mut kill_shake_events: EventWriter<crate::effects::KillShakeEvent>,
+    mut kill_feed: EventWriter<KillFeedEvent>,
+    mut killed_events: EventWriter<DinoKilledEvent>,
+    mut tier_events: EventWriter<ComboTierReachedEvent>,
+    mut bestiary: ResMut<Bestiary>,
+    mut kill_cam_events: EventWriter<crate::kill_cam::KillCamEvent>,
 ) {
     for event in events.read() {
-        if let Ok((mut health, mut ai, species)) = dino_q.get_mut(event.target) {
+        if let Ok((mut health, mut ai, species, baby, alpha, transform)) = dino_q.get_mut(event.target) {
             health.current -= event.damage;
+            bestiary.record_sighting(*species);
 
             // Add damage reaction - pause and flee faster
             if commands.get_entity(event.target).is_some() {
@@ -325,7 +820,7 @@ fn handle_bullet_hits(
                 ai.state = AIState::Dead;
 
                 // Add combo kill
-                combo.add_kill();
+                let new_tier = combo.add_kill();
 
                 // Increment time attack mode kill counter
                 if time_attack.is_active {
@@ -333,31 +828,93 @@ fn handle_bullet_hits(
                 }
 
                 // Calculate base score and coins based on species
-                let (base_score, coin_reward) = match species {
-                    DinoSpecies::Velociraptor => (150, 15),
-                    DinoSpecies::Triceratops => (200, 20),
-                    DinoSpecies::Stegosaurus => (175, 25),
-                    DinoSpecies::Brachiosaurus => (400, 30),
-                    DinoSpecies::TRex => (1000, 100), // Boss gives huge rewards
+                let (base_score, coin_reward) = species.kill_reward();
+                bestiary.record_kill(*species);
+
+                // Babies are only worth a token reward
+                let (base_score, coin_reward) = if baby.is_some() {
+                    (50, 5)
+                } else if alpha.is_some() {
+                    (base_score * ALPHA_SCORE_MULTIPLIER, coin_reward * ALPHA_COIN_MULTIPLIER)
+                } else {
+                    (base_score, coin_reward)
                 };
 
                 // Apply hit part multiplier to score
-                let base_score = match event.hit_part {
-                    BodyPart::Head => base_score * 2,
-                    BodyPart::Body => base_score,
-                    BodyPart::Legs => base_score / 2,
+                let part_multiplier = match event.hit_part {
+                    BodyPart::Head => 2.0,
+                    BodyPart::Body => 1.0,
+                    BodyPart::Legs => 0.5,
                 };
+                let base_score = (base_score as f32 * part_multiplier) as u32;
 
                 // Apply combo multiplier to score
                 let final_score = (base_score as f32 * combo.get_score_multiplier()) as u32;
                 score.score += final_score;
 
-                // Add coins (not affected by combo or hit part)
-                coins.total_coins += coin_reward;
+                // Everything stacked on the species' base score, for the popup breakdown
+                let alpha_multiplier = if alpha.is_some() && baby.is_none() { ALPHA_SCORE_MULTIPLIER as f32 } else { 1.0 };
+                let multiplier = alpha_multiplier * part_multiplier * combo.get_score_multiplier();
+
+                // Coins pop out as pickups (not affected by combo or hit part)
+                coin_drops.send(CoinDropEvent {
+                    position: transform.translation,
+                    amount: coin_reward,
+                    shower: *species == DinoSpecies::TRex && baby.is_none(),
+                });
+
+                killed_events.send(DinoKilledEvent {
+                    entity: event.target,
+                    species: *species,
+                    position: event.position,
+                    score: final_score,
+                    combo: combo.current_combo,
+                    multiplier,
+                    baby: baby.is_some(),
+                });
+
+                // Kill feed entries
+                if *species == DinoSpecies::TRex {
+                    kill_feed.send(KillFeedEvent::new(
+                        format!("BOSS DOWN! T-Rex +{}", final_score),
+                        Color::srgb(1.0, 0.3, 0.2),
+                    ));
+                } else if baby.is_some() {
+                    kill_feed.send(KillFeedEvent::new(
+                        format!("Baby {} +{}", species.name(), final_score),
+                        Color::srgb(0.8, 0.8, 0.8),
+                    ));
+                } else if alpha.is_some() {
+                    kill_feed.send(KillFeedEvent::new(
+                        format!("ALPHA {} +{}", species.name(), final_score),
+                        Color::srgb(1.0, 0.85, 0.3),
+                    ));
+                } else {
+                    kill_feed.send(KillFeedEvent::new(
+                        format!("{} +{}", species.name(), final_score),
+                        Color::WHITE,
+                    ));
+                }
+
+                if let Some(tier) = new_tier {
+                    kill_feed.send(KillFeedEvent::new(
+                        format!("{}! {}x COMBO", tier.name(), combo.current_combo),
+                        tier.color(),
+                    ));
+                    tier_events.send(ComboTierReachedEvent {
+                        tier,
+                        combo: combo.current_combo,
+                    });
+                }
 
                 // Trigger screen shake on kill
                 kill_shake_events.send(crate::effects::KillShakeEvent);
 
+                // The boss falls in slow motion
+                if *species == DinoSpecies::TRex && baby.is_none() {
+                    kill_cam_events.send(crate::kill_cam::KillCamEvent { target: event.target });
+                }
+
                 // Add death animation component
                 commands.entity(event.target).insert(DinoDeath {
                     timer: Timer::from_seconds(3.0, TimerMode::Once),
@@ -387,55 +944,187 @@ impl DamageReaction {
     }
 }
 
-fn update_dino_ai(
+pub fn update_dino_ai(
     time: Res<Time>,
+    mut commands: Commands,
     mut queries: ParamSet<(
-        Query<(&mut DinoAI, &Transform)>,
+        Query<
+            (Entity, &mut DinoAI, &Transform, &DinoSpecies, &DinoHealth, Option<&Attacking>, Option<&StatusEffects>),
+            Without<FollowParent>
+        >,
         Query<&Transform, (With<super::vehicle::PlayerVehicle>, Without<Dinosaur>)>,
     )>,
+    obstacle_q: Query<&Transform, (With<Obstacle>, Without<Dinosaur>)>,
+    noise: Res<PlayerNoise>,
+    combat: Res<DinoCombatConfig>,
+    config: Res<DinoAIConfig>,
+    mut rng: ResMut<GameRng>,
 ) {
     let vehicle_pos = queries.p1().get_single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
-    let mut rng = rand::thread_rng();
 
-    for (mut ai, transform) in queries.p0().iter_mut() {
-        if ai.state == AIState::Dead {
+    let obstacles: Vec<Vec3> = obstacle_q.iter().map(|t| t.translation).collect();
+
+    // A quiet, slow approach lets the player get much closer before dinos notice
+    let detection_scale = noise.detection_scale();
+
+    for (entity, mut ai, transform, species, health, attacking, status) in queries.p0().iter_mut() {
+        // Stunned dinos make no decisions until it wears off
+        if ai.state == AIState::Dead || status.is_some_and(StatusEffects::is_stunned) {
             continue;
         }
 
+        // Drop the attack sub-state once the attack is over
+        if ai.state != AIState::Attack && attacking.is_some() {
+            commands.entity(entity).remove::<Attacking>();
+        }
+
         // Update attack cooldown
         ai.attack_cooldown.tick(time.delta());
 
         let dino_pos = transform.translation;
         let distance_to_vehicle = (vehicle_pos - dino_pos).length();
 
-        // Attack behavior for aggressive dinos (Velociraptor, T-Rex)
-        if ai.attack_range > 0.0 && distance_to_vehicle < ai.attack_range && ai.attack_cooldown.finished() {
+        // Attack behavior for aggressive dinos (Velociraptor, T-Rex), and wounded ones cornered up close
+        let stats = combat.stats(*species);
+        let aggressive = stats.aggro_range > 0.0 && distance_to_vehicle < stats.aggro_range * detection_scale;
+        let cornered = stats.charge_range > 0.0 && health.current < health.max && distance_to_vehicle < stats.charge_range;
+        if (aggressive || cornered) && ai.attack_cooldown.finished() {
             if ai.state != AIState::Attack {
                 ai.state = AIState::Attack;
+                commands.entity(entity).insert(Attacking::new());
             }
-        } else if distance_to_vehicle < 30.0 && ai.state != AIState::Flee && ai.state != AIState::Attack {
+        } else if distance_to_vehicle < config.flee_detection_range * detection_scale && ai.state != AIState::Flee && ai.state != AIState::Attack {
             // Flee if player is close (and not attacking)
             ai.state = AIState::Flee;
             let flee_dir = (dino_pos - vehicle_pos).normalize();
             ai.flee_direction = Vec3::new(flee_dir.x, 0.0, flee_dir.z).normalize();
+
+            // Timid dinos prefer to break line of sight behind an obstacle
+            ai.flee_target = if rng.gen_bool(species.cover_seeking_chance()) {
+                find_cover(dino_pos, vehicle_pos, &obstacles)
+            } else {
+                None
+            };
+        }
+
+        // Stay hidden until the vehicle comes close, then bolt
+        if ai.state == AIState::Flee && ai.flee_target.is_some() && distance_to_vehicle < config.cover_break_distance {
+            ai.flee_target = None;
+            let flee_dir = dino_pos - vehicle_pos;
+            ai.flee_direction = Vec3::new(flee_dir.x, 0.0, flee_dir.z).normalize_or_zero();
         }
 
         // Return to roaming after fleeing far enough
-        if ai.state == AIState::Flee && distance_to_vehicle > 60.0 {
+        if ai.state == AIState::Flee && distance_to_vehicle > config.calm_distance {
             ai.state = AIState::Roam;
+            ai.flee_target = None;
+        }
+
+        // Herbivores occasionally stop to graze
+        if ai.state == AIState::Roam
+            && !species.is_carnivore()
+            && rng.gen_bool((config.graze_chance_per_second * time.delta_secs_f64()).min(1.0))
+        {
+            ai.state = AIState::Idle;
+            commands.entity(entity).insert(Grazing {
+                timer: Timer::from_seconds(sample_config_range(&mut *rng, &config.graze_seconds), TimerMode::Once),
+            });
         }
 
         // Roam behavior
         if ai.state == AIState::Roam {
-            if ai.wander_target.is_none() || (dino_pos - ai.wander_target.unwrap()).length() < 5.0 {
+            if ai.wander_target.is_none() || (dino_pos - ai.wander_target.unwrap()).length() < config.wander_arrive_distance {
                 let angle = rng.gen_range(0.0..std::f32::consts::PI * 2.0);
-                let dist = rng.gen_range(20.0..50.0);
+                let dist = sample_config_range(&mut *rng, &config.wander_distance);
                 ai.wander_target = Some(dino_pos + Vec3::new(angle.cos() * dist, 0.0, angle.sin() * dist));
             }
         }
     }
 }
 
+/// Babies whose parent died (or is gone) panic and flee from the vehicle
+fn update_orphaned_babies(
+    mut commands: Commands,
+    mut baby_q: Query<(Entity, &FollowParent, &mut DinoAI, &Transform)>,
+    parent_q: Query<&DinoAI, Without<FollowParent>>,
+    vehicle_q: Query<&Transform, (With<super::vehicle::PlayerVehicle>, Without<Dinosaur>)>,
+) {
+    let vehicle_pos = vehicle_q.get_single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
+
+    for (entity, follow, mut ai, transform) in baby_q.iter_mut() {
+        let parent_alive = parent_q.get(follow.target)
+            .is_ok_and(|parent_ai| parent_ai.state != AIState::Dead);
+        if parent_alive {
+            continue;
+        }
+
+        commands.entity(entity).remove::<FollowParent>();
+        if ai.state != AIState::Dead {
+            let away = transform.translation - vehicle_pos;
+            ai.state = AIState::Flee;
+            ai.flee_direction = Vec3::new(away.x, 0.0, away.z).normalize_or_zero();
+            commands.entity(entity).insert(DamageReaction::new());
+        }
+    }
+}
+
+/// Animate grazing heads and send dinos back to roaming when done or disturbed
+fn update_grazing(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut dino_q: Query<(Entity, &mut DinoAI, &mut Grazing)>,
+    mut head_q: Query<(&Parent, &DinoHead, &mut Transform)>,
+) {
+    for (parent, head, mut transform) in head_q.iter_mut() {
+        let Ok((entity, mut ai, mut grazing)) = dino_q.get_mut(parent.get()) else {
+            continue;
+        };
+
+        grazing.timer.tick(time.delta());
+
+        // Startled (fled, attacked, died) or done grazing
+        if ai.state != AIState::Idle || grazing.timer.finished() {
+            if ai.state == AIState::Idle {
+                ai.state = AIState::Roam;
+            }
+            transform.translation = head.rest;
+            commands.entity(entity).remove::<Grazing>();
+            continue;
+        }
+
+        // A few slow dips toward the ground
+        let dip = (grazing.timer.elapsed_secs() * 2.0).sin().abs();
+        transform.translation = head.rest + Vec3::new(0.0, -head.rest.y * 0.6, head.rest.z * 0.15) * dip;
+    }
+}
+
+/// Sample a tunable range, falling back to its start when it's been set empty (e.g. 3.0..3.0)
+fn sample_config_range(rng: &mut impl Rng, range: &std::ops::Range<f32>) -> f32 {
+    if range.is_empty() {
+        range.start
+    } else {
+        rng.gen_range(range.clone())
+    }
+}
+
+/// Pick a spot behind the nearest obstacle that puts it between the dino and the vehicle
+fn find_cover(dino_pos: Vec3, vehicle_pos: Vec3, obstacles: &[Vec3]) -> Option<Vec3> {
+    let away = (dino_pos - vehicle_pos).xz().normalize_or_zero();
+
+    obstacles.iter()
+        .filter(|obstacle| obstacle.xz().distance(dino_pos.xz()) < COVER_SEARCH_RADIUS)
+        // Only cover that doesn't mean running back toward the vehicle
+        .filter(|obstacle| (obstacle.xz() - dino_pos.xz()).dot(away) > -2.0)
+        .min_by(|a, b| {
+            a.xz().distance_squared(dino_pos.xz())
+                .total_cmp(&b.xz().distance_squared(dino_pos.xz()))
+        })
+        .map(|obstacle| {
+            let behind = (obstacle.xz() - vehicle_pos.xz()).normalize_or_zero() * COVER_OFFSET;
+            Vec3::new(obstacle.x + behind.x, dino_pos.y, obstacle.z + behind.y)
+        })
+}
+
 fn update_damage_reaction(
     time: Res<Time>,
     mut commands: Commands,
@@ -456,24 +1145,78 @@ fn update_damage_reaction(
         if ai.state != AIState::Dead && ai.state != AIState::Flee {
             ai.state = AIState::Flee;
         }
+
+        // Getting shot flushes a dino out of cover
+        ai.flee_target = None;
     }
 }
 
-fn update_dino_movement(
+pub fn update_dino_movement(
     time: Res<Time>,
     mut queries: ParamSet<(
-        Query<(&mut Transform, &DinoAI, Option<&DamageReaction>)>,
+        Query<(
+            Entity,
+            &mut Transform,
+            &DinoAI,
+            Option<&DamageReaction>,
+            Option<&mut Attacking>,
+            Option<&FollowParent>,
+            Option<&StatusEffects>,
+        )>,
         Query<&Transform, (With<super::vehicle::PlayerVehicle>, Without<Dinosaur>)>,
     )>,
+    config: Res<DinoAIConfig>,
 ) {
     let dt = time.delta_secs();
     let vehicle_pos = queries.p1().get_single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
 
-    for (mut transform, ai, damage_reaction) in queries.p0().iter_mut() {
-        if ai.state == AIState::Dead || ai.state == AIState::Idle {
+    // Parent positions for babies, read before any dino moves this frame
+    let positions: HashMap<Entity, Vec3> = queries.p0().iter()
+        .map(|(entity, transform, ..)| (entity, transform.translation))
+        .collect();
+
+    for (_, mut transform, ai, damage_reaction, attacking, follow, status) in queries.p0().iter_mut() {
+        if ai.state == AIState::Dead {
+            continue;
+        }
+        let status_factor = status.map_or(1.0, StatusEffects::speed_factor);
+
+        // Babies trail their parent instead of running their own AI
+        if let Some(follow) = follow {
+            let Some(parent_pos) = positions.get(&follow.target) else {
+                continue;
+            };
+
+            let to_parent = Vec3::new(parent_pos.x - transform.translation.x, 0.0, parent_pos.z - transform.translation.z);
+            if to_parent.length() > FOLLOW_DISTANCE {
+                let direction = to_parent.normalize();
+                let movement = direction * ai.move_speed * status_factor * config.baby_follow_speed_boost * dt;
+                transform.translation.x += movement.x;
+                transform.translation.z += movement.z;
+
+                let target_rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
+                transform.rotation = transform.rotation.slerp(target_rotation, smoothing(TURN_RATE, dt));
+            }
+            continue;
+        }
+
+        if ai.state == AIState::Idle {
             continue;
         }
 
+        // Attack telegraph: hold still facing the vehicle and rear up before charging
+        if let Some(mut attacking) = attacking {
+            if !attacking.windup.finished() {
+                attacking.windup.tick(time.delta());
+
+                let to_vehicle = vehicle_pos - transform.translation;
+                let facing = Quat::from_rotation_y(to_vehicle.x.atan2(to_vehicle.z));
+                let rear = Quat::from_rotation_x(-0.35 * (attacking.windup.fraction() * std::f32::consts::PI).sin());
+                transform.rotation = facing * rear;
+                continue;
+            }
+        }
+
         let direction = match ai.state {
             AIState::Roam => {
                 if let Some(target) = ai.wander_target {
@@ -482,7 +1225,15 @@ fn update_dino_movement(
                     Vec3::ZERO
                 }
             }
-            AIState::Flee => ai.flee_direction,
+            AIState::Flee => match ai.flee_target {
+                // Run to cover, then hold still behind it
+                Some(target) if target.xz().distance(transform.translation.xz()) > 1.0 => {
+                    let to_cover = target - transform.translation;
+                    Vec3::new(to_cover.x, 0.0, to_cover.z).normalize()
+                }
+                Some(_) => Vec3::ZERO,
+                None => ai.flee_direction,
+            },
             AIState::Attack => {
                 // Move toward vehicle when attacking
                 (vehicle_pos - transform.translation).normalize()
@@ -495,73 +1246,153 @@ fn update_dino_movement(
             let speed_boost = if ai.state == AIState::Flee && damage_reaction.is_some() {
                 damage_reaction.unwrap().flee_boost
             } else if ai.state == AIState::Attack {
-                1.5 // Move faster when attacking
+                config.attack_speed_boost
             } else {
                 1.0
             };
 
-            let movement = direction * ai.move_speed * speed_boost * dt;
+            let movement = direction * ai.move_speed * status_factor * speed_boost * dt;
             transform.translation.x += movement.x;
             transform.translation.z += movement.z;
 
             // Face movement direction
             let target_rotation = Quat::from_rotation_y(direction.x.atan2(direction.z));
-            transform.rotation = transform.rotation.slerp(target_rotation, 0.1);
+            transform.rotation = transform.rotation.slerp(target_rotation, smoothing(TURN_RATE, dt));
+        }
+    }
+}
+
+/// Push overlapping dinos apart, since their kinematic colliders don't resolve contacts
+fn apply_dino_separation(
+    mut dino_q: Query<(Entity, &mut Transform, &DinoAI, &DinoSpecies), With<Dinosaur>>,
+) {
+    let dinos: Vec<(Entity, Vec2, f32)> = dino_q.iter()
+        .filter(|(_, _, ai, _)| ai.state != AIState::Dead)
+        .map(|(entity, transform, _, species)| (entity, transform.translation.xz(), species.body_radius() * transform.scale.x))
+        .collect();
+
+    let mut pushes = vec![Vec2::ZERO; dinos.len()];
+
+    for i in 0..dinos.len() {
+        for j in (i + 1)..dinos.len() {
+            let (_, pos_a, radius_a) = dinos[i];
+            let (_, pos_b, radius_b) = dinos[j];
+
+            let delta = pos_a - pos_b;
+            let distance = delta.length();
+            let min_distance = radius_a + radius_b;
+            if distance >= min_distance {
+                continue;
+            }
+
+            // Exactly stacked dinos have no direction, pick one
+            let direction = if distance > 0.001 { delta / distance } else { Vec2::X };
+            let push = direction * (min_distance - distance) * 0.5 * SEPARATION_STIFFNESS;
+            pushes[i] += push;
+            pushes[j] -= push;
+        }
+    }
+
+    for ((entity, _, _), push) in dinos.iter().zip(pushes) {
+        if push == Vec2::ZERO {
+            continue;
+        }
+
+        if let Ok((_, mut transform, _, _)) = dino_q.get_mut(*entity) {
+            transform.translation.x += push.x;
+            transform.translation.z += push.y;
         }
     }
 }
 
+/// Keep living dinos standing on the terrain after they've moved this frame
+fn follow_terrain(
+    terrain: Res<TerrainSeed>,
+    mut dino_q: Query<&mut Transform, (With<Dinosaur>, Without<DinoDeath>)>,
+) {
+    for mut transform in dino_q.iter_mut() {
+        transform.translation.y = terrain.ground_height(transform.translation.x, transform.translation.z) - GROUND_LEVEL;
+    }
+}
+
 fn process_dino_attacks(
     time: Res<Time>,
-    mut dino_q: Query<(Entity, &mut DinoAI, &Transform, &DinoSpecies)>,
+    mut dino_q: Query<(&mut DinoAI, &Transform, &DinoSpecies, Option<&Attacking>)>,
     mut vehicle_queries: ParamSet<(
-        Query<&Transform, With<super::vehicle::PlayerVehicle>>,
+        Query<(&Transform, &super::vehicle::VehicleVelocity, &super::vehicle::Dodge), With<super::vehicle::PlayerVehicle>>,
         Query<&mut super::vehicle::VehicleHealth>,
     )>,
     mut attack_events: EventWriter<DinoAttackEvent>,
     mut hit_feedback: EventWriter<crate::effects::HitFeedbackEvent>,
+    combat: Res<DinoCombatConfig>,
 ) {
-    let vehicle_pos = vehicle_queries.p0().get_single().map(|t| t.translation).unwrap_or(Vec3::ZERO);
+    let (vehicle_pos, ram, dodging) = vehicle_queries.p0().get_single()
+        .map(|(t, velocity, dodge)| (t.translation, velocity.current.abs() / velocity.max_speed, dodge.is_dodging()))
+        .unwrap_or((Vec3::ZERO, 0.0, false));
 
-    for (entity, mut ai, dino_transform, species) in dino_q.iter_mut() {
-        if ai.state != AIState::Attack {
+    for (mut ai, dino_transform, species, attacking) in dino_q.iter_mut() {
+        if ai.state == AIState::Dead || !ai.attack_cooldown.finished() {
             continue;
         }
 
         let dino_pos = dino_transform.translation;
         let distance_to_vehicle = (vehicle_pos - dino_pos).length();
+        let attacking_now = ai.state == AIState::Attack;
 
-        // Check if dino has reached the vehicle to attack
-        if distance_to_vehicle < 3.0 && ai.attack_cooldown.finished() {
-            // Calculate damage based on species
-            let damage = match species {
-                DinoSpecies::Velociraptor => 10.0,
-                DinoSpecies::TRex => 25.0,
-                _ => 5.0,
-            };
-
-            // Apply damage to vehicle
-            if let Ok(mut vehicle_health) = vehicle_queries.p1().get_single_mut() {
-                vehicle_health.current -= damage;
-                vehicle_health.current = vehicle_health.current.max(0.0);
+        let damage = if attacking_now {
+            // No biting until the windup has played out
+            if attacking.is_some_and(|attacking| !attacking.windup.finished()) {
+                continue;
+            }
 
-                // Trigger hit feedback
-                hit_feedback.send(crate::effects::HitFeedbackEvent);
+            // If too far from vehicle while attacking, switch back to roaming
+            if distance_to_vehicle > combat.stats(*species).engage_range() * combat.give_up_multiplier {
+                ai.state = AIState::Roam;
             }
 
-            // Send attack event
-            attack_events.send(DinoAttackEvent { damage });
+            // Check if dino has reached the vehicle to attack
+            if distance_to_vehicle >= combat.contact_distance {
+                continue;
+            }
+            combat.damage(*species)
+        } else {
+            // Big herbivores hurt on contact: stepping on the vehicle, or being rammed at speed
+            let reach = species.body_radius() * dino_transform.scale.x + VEHICLE_CONTACT_RADIUS;
+            let touching = (vehicle_pos - dino_pos).xz().length() < reach;
+            let damage = combat.contact_damage(*species, ram) * dino_transform.scale.x;
+            if !touching || damage <= 0.0 {
+                continue;
+            }
+            damage
+        };
 
-            // Reset attack cooldown
-            ai.attack_cooldown.reset();
+        ai.attack_cooldown.set_duration(combat.cooldown(*species));
+        ai.attack_cooldown.reset();
 
-            // After attacking, switch to flee or roam
+        // After attacking, switch to flee or roam
+        if attacking_now {
             ai.state = AIState::Flee;
         }
-        // If too far from vehicle while attacking, switch back to roaming
-        else if distance_to_vehicle > ai.attack_range * 1.5 {
-            ai.state = AIState::Roam;
+
+        // A dodge makes the hit miss, spending the attack as usual
+        if dodging {
+            continue;
         }
+
+        // Apply damage to vehicle
+        if let Ok(mut vehicle_health) = vehicle_queries.p1().get_single_mut() {
+            vehicle_health.current -= damage;
+            vehicle_health.current = vehicle_health.current.max(0.0);
+            vehicle_health.last_damaged = time.elapsed_secs();
+
+            // Trigger hit feedback
+            hit_feedback.send(crate::effects::HitFeedbackEvent);
+        }
+
+        // Send attack event; bigger dinos shove harder
+        let away = (vehicle_pos - dino_pos).with_y(0.0).normalize_or_zero();
+        let knockback = away * species.body_radius() * dino_transform.scale.x * KNOCKBACK_PER_RADIUS;
+        attack_events.send(DinoAttackEvent { damage, knockback });
     }
 }
 
@@ -575,10 +1406,14 @@ fn check_dino_death(
 fn update_dino_death_animation(
     time: Res<Time>,
     mut commands: Commands,
-    mut dino_q: Query<(Entity, &mut DinoDeath, &mut Transform, &DinoSpecies)>,
+    terrain: Res<TerrainSeed>,
+    mut dino_q: Query<(Entity, &mut DinoDeath, &mut Transform, &DinoSpecies, Has<Towed>)>,
 ) {
-    for (entity, mut death, mut transform, species) in dino_q.iter_mut() {
-        death.timer.tick(time.delta());
+    for (entity, mut death, mut transform, species, towed) in dino_q.iter_mut() {
+        // A carcass on the tow tether stays until it is delivered or let go
+        if !towed {
+            death.timer.tick(time.delta());
+        }
 
         // Fall over animation
         let progress = 1.0 - (death.timer.elapsed_secs() / death.timer.duration().as_secs_f32());
@@ -595,7 +1430,8 @@ fn update_dino_death_animation(
             DinoSpecies::Stegosaurus => 1.0,
             DinoSpecies::TRex => 2.0,
         };
-        transform.translation.y = (height * 0.5) * (1.0 - progress * 0.8);
+        let ground = terrain.ground_height(transform.translation.x, transform.translation.z) - GROUND_LEVEL;
+        transform.translation.y = ground + (height * 0.5 * transform.scale.y) * (1.0 - progress * 0.8);
 
         // Change color to indicate death
         if death.timer.finished() {
@@ -604,3 +1440,60 @@ fn update_dino_death_animation(
         }
     }
 }
+
+/// Pulse weak point glow and flash it when the head takes a hit
+fn update_weak_points(
+    time: Res<Time>,
+    mut hits: EventReader<BulletHitEvent>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut weak_point_q: Query<(&mut WeakPoint, &Parent, &MeshMaterial3d<StandardMaterial>)>,
+) {
+    let head_hits: Vec<Entity> = hits.read()
+        .filter(|hit| matches!(hit.hit_part, BodyPart::Head))
+        .map(|hit| hit.target)
+        .collect();
+
+    let dt = time.delta_secs();
+    let pulse = (time.elapsed_secs() * 3.0).sin() * 0.5 + 0.5;
+    let rest_glow = WEAK_POINT_GLOW_MIN + (WEAK_POINT_GLOW_MAX - WEAK_POINT_GLOW_MIN) * pulse;
+
+    for (mut weak_point, parent, material) in weak_point_q.iter_mut() {
+        if head_hits.contains(&parent.get()) {
+            weak_point.flash = 1.0;
+        }
+        weak_point.flash = (weak_point.flash - WEAK_POINT_FLASH_DECAY * dt).max(0.0);
+
+        if let Some(mat) = materials.get_mut(&material.0) {
+            mat.emissive = LinearRgba::rgb(1.0, 0.45, 0.1) * (rest_glow + weak_point.flash * WEAK_POINT_FLASH_GLOW);
+        }
+    }
+}
+
+/// Hide head and leg meshes of far-away dinos, leaving only the body
+fn update_dino_lod(
+    config: Res<DinoLodConfig>,
+    mut dino_q: Query<(&Transform, &mut DinoLod, &Children), With<Dinosaur>>,
+    mut part_q: Query<(&HitBox, &mut Visibility)>,
+    vehicle_q: Query<&Transform, (With<super::vehicle::PlayerVehicle>, Without<Dinosaur>)>,
+) {
+    let Ok(vehicle_transform) = vehicle_q.get_single() else {
+        return;
+    };
+
+    for (transform, mut lod, children) in dino_q.iter_mut() {
+        let detailed = transform.translation.distance(vehicle_transform.translation) < config.detail_distance;
+        if lod.detailed == detailed {
+            continue;
+        }
+        lod.detailed = detailed;
+
+        let visibility = if detailed { Visibility::Inherited } else { Visibility::Hidden };
+        for child in children.iter() {
+            if let Ok((hitbox, mut child_visibility)) = part_q.get_mut(*child) {
+                if matches!(hitbox.part, BodyPart::Head | BodyPart::Legs) {
+                    *child_visibility = visibility;
+                }
+            }
+        }
+    }
+}
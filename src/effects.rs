@@ -1,5 +1,11 @@
 use bevy::prelude::*;
 use crate::pause::GameState;
+use rand::Rng;
+use crate::dino::{DinoAttackEvent, DinoKilledEvent};
+use crate::weapon::RocketExplosionEvent;
+use crate::terrain::TerrainSeed;
+use crate::rng::GameRng;
+use crate::smoothing;
 
 /// Event triggered when a kill happens
 #[derive(Event)]
@@ -14,6 +20,8 @@ pub struct HitFeedbackEvent;
 pub struct ScreenShake {
     pub intensity: f32,
     pub duration: Timer,
+    /// Offset currently applied on top of the camera's own position
+    pub offset: Vec3,
 }
 
 impl ScreenShake {
@@ -23,6 +31,9 @@ impl ScreenShake {
     }
 }
 
+/// How quickly the crosshair bloom spring loses speed, per second
+const CROSSHAIR_DAMPING: f32 = 13.0;
+
 /// Crosshair hit feedback
 #[derive(Resource, Default)]
 pub struct CrosshairFeedback {
@@ -30,19 +41,143 @@ pub struct CrosshairFeedback {
     pub velocity: f32,
 }
 
+/// Hit marker flash, kills show a bigger red marker
+#[derive(Resource)]
+pub struct HitMarkerState {
+    pub timer: Timer,
+    pub kill: bool,
+}
+
+impl Default for HitMarkerState {
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(HIT_MARKER_TIME, TimerMode::Once);
+        timer.tick(timer.duration());
+        Self { timer, kill: false }
+    }
+}
+
+const HIT_MARKER_TIME: f32 = 0.15;
+
+/// Red screen-edge overlay flashed when the vehicle is bitten
+#[derive(Component)]
+pub struct DamageVignette {
+    pub timer: Timer,
+    pub strength: f32,
+}
+
+const VIGNETTE_FADE_TIME: f32 = 0.6;
+
+/// Brief point light flare at a rocket impact
+#[derive(Component)]
+pub struct ExplosionLight {
+    pub timer: Timer,
+    pub intensity: f32,
+}
+
+/// Dark ground decal left behind by a rocket
+#[derive(Component)]
+pub struct ScorchMark {
+    pub timer: Timer,
+}
+
+const EXPLOSION_LIGHT_TIME: f32 = 0.2;
+const EXPLOSION_LIGHT_INTENSITY: f32 = 4_000_000.0;
+/// Extra explosions beyond this many live lights only get a scorch mark
+const MAX_EXPLOSION_LIGHTS: usize = 4;
+const SCORCH_LIFETIME: f32 = 8.0;
+/// Just above the ground, under footprints' lift so they draw on top
+const SCORCH_LIFT: f32 = 0.01;
+
+/// Opacity of a fresh scorch mark
+const SCORCH_ALPHA: f32 = 0.85;
+
+#[derive(Resource)]
+struct ScorchAssets {
+    mesh: Handle<Mesh>,
+    materials: FadeMaterials,
+}
+
+/// Alpha levels a fading decal snaps between
+const FADE_STEPS: usize = 8;
+
+/// Blended, unlit materials for one decal color, from faint up to `max_alpha`.
+/// Decals swap between these as they fade instead of each owning a material.
+pub struct FadeMaterials {
+    steps: Vec<Handle<StandardMaterial>>,
+    max_alpha: f32,
+}
+
+impl FadeMaterials {
+    pub fn new(materials: &mut Assets<StandardMaterial>, color: Color, max_alpha: f32) -> Self {
+        let steps = (1..=FADE_STEPS)
+            .map(|step| materials.add(StandardMaterial {
+                base_color: color.with_alpha(max_alpha * step as f32 / FADE_STEPS as f32),
+                alpha_mode: AlphaMode::Blend,
+                unlit: true,
+                ..default()
+            }))
+            .collect();
+        Self { steps, max_alpha }
+    }
+
+    /// The step nearest `alpha`, never fully transparent
+    pub fn at(&self, alpha: f32) -> Handle<StandardMaterial> {
+        let step = (alpha / self.max_alpha * FADE_STEPS as f32).round() as usize;
+        self.steps[step.clamp(1, FADE_STEPS) - 1].clone()
+    }
+}
+
+/// Gore toggle from the settings menu; off hides the on-screen blood splatter
+#[derive(Resource)]
+pub struct GoreSettings {
+    pub enabled: bool,
+}
+
+impl Default for GoreSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Full-screen layer holding the blood splatter blobs
+#[derive(Component)]
+pub struct BloodSplatterOverlay;
+
+/// One blob of blood on the "lens", fading out over its lifetime
+#[derive(Component)]
+pub struct BloodSplatter {
+    pub timer: Timer,
+    pub alpha: f32,
+}
+
+/// Kills closer than this to the vehicle splatter the screen
+const SPLATTER_RANGE: f32 = 8.0;
+const SPLATTER_LIFETIME: f32 = 2.0;
+const MAX_SPLATTERS: usize = 24;
+
 pub struct EffectsPlugin;
 
 impl Plugin for EffectsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ScreenShake>()
             .init_resource::<CrosshairFeedback>()
+            .init_resource::<HitMarkerState>()
+            .init_resource::<GoreSettings>()
             .add_event::<KillShakeEvent>()
             .add_event::<HitFeedbackEvent>()
+            .add_systems(Startup, (setup_damage_vignette, setup_scorch_assets, setup_blood_splatter_overlay))
             .add_systems(Update, (
                 handle_kill_shake,
                 handle_hit_feedback,
+                handle_damage_feedback,
+                update_damage_vignette,
+                (spawn_blood_splatter, update_blood_splatter).chain(),
+                spawn_explosion_effects,
+                update_explosion_lights,
+                update_scorch_marks,
                 update_screen_shake,
                 update_crosshair_feedback,
+                (trigger_hit_marker, update_hit_marker).chain(),
             ).run_if(in_state(GameState::Playing)));
     }
 }
@@ -66,16 +201,300 @@ fn handle_hit_feedback(
     }
 }
 
+fn trigger_hit_marker(
+    mut hit_events: EventReader<HitFeedbackEvent>,
+    mut kill_events: EventReader<KillShakeEvent>,
+    mut marker: ResMut<HitMarkerState>,
+) {
+    if hit_events.read().count() > 0 {
+        // A plain hit doesn't downgrade a kill marker that is still showing
+        if marker.timer.finished() {
+            marker.kill = false;
+        }
+        marker.timer.reset();
+    }
+
+    if kill_events.read().count() > 0 {
+        marker.kill = true;
+        marker.timer.reset();
+    }
+}
+
+fn update_hit_marker(
+    time: Res<Time>,
+    mut marker: ResMut<HitMarkerState>,
+    mut tick_q: Query<(&crate::ui::HitMarkerTick, &mut Node, &mut BackgroundColor)>,
+) {
+    marker.timer.tick(time.delta());
+
+    let alpha = 1.0 - marker.timer.fraction();
+    let (color, length, gap) = if marker.kill {
+        (Color::srgba(1.0, 0.15, 0.1, alpha), 12.0, 16.0)
+    } else {
+        (Color::srgba(1.0, 1.0, 1.0, alpha), 8.0, 14.0)
+    };
+
+    // Fixed offsets from the screen center, independent of the crosshair bloom
+    for (tick, mut node, mut background) in tick_q.iter_mut() {
+        background.0 = color;
+        *node = crate::ui::hit_marker_node(tick.direction, length, gap);
+    }
+}
+
+fn setup_damage_vignette(mut commands: Commands) {
+    let mut timer = Timer::from_seconds(VIGNETTE_FADE_TIME, TimerMode::Once);
+    timer.tick(timer.duration());
+
+    // Full-screen node with only a thick border, so just the edges are tinted
+    commands.spawn((
+        DamageVignette { timer, strength: 0.0 },
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            border: UiRect::all(Val::Px(60.0)),
+            ..default()
+        },
+        BorderColor(Color::NONE),
+    ));
+}
+
+fn handle_damage_feedback(
+    mut events: EventReader<DinoAttackEvent>,
+    mut shake: ResMut<ScreenShake>,
+    mut vignette_q: Query<&mut DamageVignette>,
+) {
+    for event in events.read() {
+        // Bigger bites shake harder
+        shake.trigger((event.damage * 0.03).min(0.8), 0.25);
+
+        for mut vignette in vignette_q.iter_mut() {
+            vignette.strength = (event.damage / 25.0).clamp(0.3, 0.7);
+            vignette.timer.reset();
+        }
+    }
+}
+
+fn update_damage_vignette(
+    time: Res<Time>,
+    mut vignette_q: Query<(&mut DamageVignette, &mut BorderColor)>,
+) {
+    for (mut vignette, mut border) in vignette_q.iter_mut() {
+        vignette.timer.tick(time.delta());
+
+        let alpha = vignette.strength * (1.0 - vignette.timer.fraction());
+        border.0 = Color::srgba(0.8, 0.0, 0.0, alpha);
+    }
+}
+
+fn setup_blood_splatter_overlay(mut commands: Commands) {
+    commands.spawn((
+        BloodSplatterOverlay,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+    ));
+}
+
+/// A kill close to the vehicle sprays a few blobs of blood across the screen
+fn spawn_blood_splatter(
+    mut commands: Commands,
+    mut events: EventReader<DinoKilledEvent>,
+    gore: Res<GoreSettings>,
+    overlay_q: Query<Entity, With<BloodSplatterOverlay>>,
+    vehicle_q: Query<&Transform, With<crate::vehicle::PlayerVehicle>>,
+    splatter_q: Query<(), With<BloodSplatter>>,
+    mut rng: ResMut<GameRng>,
+) {
+    if !gore.enabled {
+        events.clear();
+        return;
+    }
+
+    let Ok(overlay) = overlay_q.get_single() else {
+        return;
+    };
+    let Ok(vehicle_transform) = vehicle_q.get_single() else {
+        return;
+    };
+
+    let mut live = splatter_q.iter().count();
+
+    for event in events.read() {
+        let distance = event.position.distance(vehicle_transform.translation);
+        if distance > SPLATTER_RANGE {
+            continue;
+        }
+
+        // Closer kills leave more, darker blood
+        let closeness = 1.0 - distance / SPLATTER_RANGE;
+        let count = (2.0 + closeness * 5.0) as usize;
+
+        commands.entity(overlay).with_children(|parent| {
+            for _ in 0..count.min(MAX_SPLATTERS.saturating_sub(live)) {
+                let size = rng.gen_range(40.0..140.0) * (0.6 + closeness * 0.4);
+                let alpha = rng.gen_range(0.45..0.75) * (0.5 + closeness * 0.5);
+                parent.spawn((
+                    BloodSplatter {
+                        timer: Timer::from_seconds(SPLATTER_LIFETIME, TimerMode::Once),
+                        alpha,
+                    },
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Percent(rng.gen_range(0.0..92.0)),
+                        top: Val::Percent(rng.gen_range(0.0..88.0)),
+                        width: Val::Px(size),
+                        height: Val::Px(size * rng.gen_range(0.6..1.2)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.45, 0.0, 0.0, alpha)),
+                    BorderRadius::MAX,
+                ));
+                live += 1;
+            }
+        });
+    }
+}
+
+fn update_blood_splatter(
+    time: Res<Time>,
+    mut commands: Commands,
+    gore: Res<GoreSettings>,
+    mut splatter_q: Query<(Entity, &mut BloodSplatter, &mut BackgroundColor)>,
+) {
+    for (entity, mut splatter, mut background) in splatter_q.iter_mut() {
+        splatter.timer.tick(time.delta());
+
+        // Turning gore off clears the screen right away
+        if splatter.timer.finished() || !gore.enabled {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+
+        background.0.set_alpha(splatter.alpha * (1.0 - splatter.timer.fraction()));
+    }
+}
+
+fn setup_scorch_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(ScorchAssets {
+        mesh: meshes.add(Circle::new(1.0)),
+        materials: FadeMaterials::new(&mut materials, Color::srgb(0.05, 0.04, 0.03), SCORCH_ALPHA),
+    });
+}
+
+fn spawn_explosion_effects(
+    mut commands: Commands,
+    mut events: EventReader<RocketExplosionEvent>,
+    scorch_assets: Res<ScorchAssets>,
+    terrain: Res<TerrainSeed>,
+    light_q: Query<(), With<ExplosionLight>>,
+) {
+    let mut live_lights = light_q.iter().count();
+
+    for event in events.read() {
+        if live_lights < MAX_EXPLOSION_LIGHTS {
+            commands.spawn((
+                ExplosionLight {
+                    timer: Timer::from_seconds(EXPLOSION_LIGHT_TIME, TimerMode::Once),
+                    intensity: EXPLOSION_LIGHT_INTENSITY,
+                },
+                PointLight {
+                    color: Color::srgb(1.0, 0.6, 0.2),
+                    intensity: EXPLOSION_LIGHT_INTENSITY,
+                    range: event.radius * 4.0,
+                    shadows_enabled: false,
+                    ..default()
+                },
+                Transform::from_translation(event.position + Vec3::Y),
+            ));
+            live_lights += 1;
+        }
+
+        // Scorch is sized to the blast and laid flat on the ground
+        let radius = event.radius * 0.6;
+        commands.spawn((
+            ScorchMark {
+                timer: Timer::from_seconds(SCORCH_LIFETIME, TimerMode::Once),
+            },
+            Mesh3d(scorch_assets.mesh.clone()),
+            MeshMaterial3d(scorch_assets.materials.at(SCORCH_ALPHA)),
+            Transform::from_translation(Vec3::new(
+                event.position.x,
+                terrain.ground_height(event.position.x, event.position.z) + SCORCH_LIFT,
+                event.position.z,
+            ))
+                .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+                .with_scale(Vec3::splat(radius)),
+        ));
+    }
+}
+
+fn update_explosion_lights(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut light_q: Query<(Entity, &mut ExplosionLight, &mut PointLight)>,
+) {
+    for (entity, mut flash, mut light) in light_q.iter_mut() {
+        flash.timer.tick(time.delta());
+
+        if flash.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // Flare up fast, then fade
+        let t = flash.timer.fraction();
+        let envelope = if t < 0.2 { t / 0.2 } else { 1.0 - (t - 0.2) / 0.8 };
+        light.intensity = flash.intensity * envelope;
+    }
+}
+
+fn update_scorch_marks(
+    time: Res<Time>,
+    mut commands: Commands,
+    scorch_assets: Res<ScorchAssets>,
+    mut scorch_q: Query<(Entity, &mut ScorchMark, &mut MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (entity, mut scorch, mut material) in scorch_q.iter_mut() {
+        scorch.timer.tick(time.delta());
+
+        if scorch.timer.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // Hold full darkness, then fade over the last quarter
+        let t = scorch.timer.fraction();
+        if t > 0.75 {
+            let faded = scorch_assets.materials.at(SCORCH_ALPHA * (1.0 - t) / 0.25);
+            if material.0 != faded {
+                material.0 = faded;
+            }
+        }
+    }
+}
+
 fn update_screen_shake(
     time: Res<Time>,
     mut shake: ResMut<ScreenShake>,
     mut camera_q: Query<&mut Transform, (With<crate::camera::MainCamera>, Without<crate::ui::Crosshair>)>,
+    mut rng: ResMut<GameRng>,
 ) {
     if shake.duration.finished() {
         shake.intensity = 0.0;
-        // Reset camera position
-        if let Ok(mut transform) = camera_q.get_single_mut() {
-            transform.translation = Vec3::ZERO;
+        // Take back the last offset so the camera rests where its owner put it
+        if shake.offset != Vec3::ZERO {
+            if let Ok(mut transform) = camera_q.get_single_mut() {
+                transform.translation -= shake.offset;
+            }
+            shake.offset = Vec3::ZERO;
         }
         return;
     }
@@ -87,34 +506,34 @@ fn update_screen_shake(
     // Decay shake over time
     let current_intensity = shake.intensity * (1.0 - (elapsed / total));
 
-    // Apply random offset to camera
+    // Swap last frame's random offset for a new one, leaving the follow position intact
     if let Ok(mut transform) = camera_q.get_single_mut() {
-        let offset_x = (rand::random::<f32>() - 0.5) * 2.0 * current_intensity;
-        let offset_y = (rand::random::<f32>() - 0.5) * current_intensity;
-        transform.translation = Vec3::new(offset_x, offset_y, transform.translation.z);
+        let offset_x = rng.gen_range(-1.0..1.0) * current_intensity;
+        let offset_y = rng.gen_range(-0.5..0.5) * current_intensity;
+        let offset = Vec3::new(offset_x, offset_y, 0.0);
+        transform.translation += offset - shake.offset;
+        shake.offset = offset;
     }
 }
 
 fn update_crosshair_feedback(
     time: Res<Time>,
     mut feedback: ResMut<CrosshairFeedback>,
-    mut crosshair_q: Query<&mut Sprite, With<crate::ui::Crosshair>>,
+    mut crosshair_q: Query<(&crate::ui::Crosshair, &mut Node)>,
 ) {
     // Spring back to normal
     feedback.velocity += (1.0 - feedback.scale) * 15.0 * time.delta_secs();
     feedback.scale += feedback.velocity * time.delta_secs();
-    feedback.velocity *= 0.8; // Damping
+    feedback.velocity *= 1.0 - smoothing(CROSSHAIR_DAMPING, time.delta_secs());
 
     // Clamp scale
     feedback.scale = feedback.scale.clamp(1.0, 3.0);
 
-    for mut sprite in crosshair_q.iter_mut() {
-        if let Some(ref mut size) = sprite.custom_size {
-            let horizontal_size = Vec2::new(20.0 * feedback.scale, 2.0);
-            let vertical_size = Vec2::new(2.0, 20.0 * feedback.scale);
-
-            // Apply to both crosshair lines
-            sprite.custom_size = Some(horizontal_size);
-        }
+    // Each line only stretches along its own axis
+    for (line, mut node) in crosshair_q.iter_mut() {
+        *node = crate::ui::crosshair_line_node(match line {
+            crate::ui::Crosshair::Horizontal => Vec2::new(20.0 * feedback.scale, 2.0),
+            crate::ui::Crosshair::Vertical => Vec2::new(2.0, 20.0 * feedback.scale),
+        });
     }
 }
@@ -1,17 +1,64 @@
 use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
 use bevy_rapier3d::prelude::*;
 use rand::Rng;
-use crate::pause::GameState;
-use crate::vehicle::PlayerVehicle;
+use crate::pause::{GameState, RestartGameEvent};
+use crate::vehicle::{PlayerVehicle, VehicleHealth};
+use crate::terrain::TerrainSeed;
+use crate::rng::{GameRng, BARREL_LAYOUT};
+use crate::weapon::RocketExplosionEvent;
 
 #[derive(Component)]
 pub struct WaterBody {
     pub slow_factor: f32, // Reduces vehicle speed to this factor (0.5 = 50% speed)
+    pub half_extents: Vec2, // XZ half size, the radius for round lakes
+    pub round: bool,
 }
 
+/// Water surface height above the ground it covers
+const WATER_LIFT: f32 = 0.2;
+/// Target spacing between water mesh vertices, fine enough to follow the hills
+const WATER_CELL_SIZE: f32 = 5.0;
+
 #[derive(Component)]
 pub struct Obstacle;
 
+/// Red barrel that blows up when shot or caught in a blast
+#[derive(Component, Default)]
+pub struct ExplosiveBarrel {
+    /// Running once the barrel is set off; it flashes, then explodes
+    pub fuse: Option<Timer>,
+}
+
+impl ExplosiveBarrel {
+    pub fn ignite(&mut self) {
+        if self.fuse.is_none() {
+            self.fuse = Some(Timer::from_seconds(BARREL_FUSE_TIME, TimerMode::Once));
+        }
+    }
+}
+
+const BARREL_COUNT: u32 = 14;
+const BARREL_RADIUS: f32 = 0.5;
+const BARREL_HEIGHT: f32 = 1.2;
+/// Bullets passing this close to a barrel's center set it off
+pub const BARREL_HIT_RADIUS: f32 = 1.0;
+/// Flash time between being set off and exploding, also the delay between links of a chain reaction
+const BARREL_FUSE_TIME: f32 = 0.15;
+const BARREL_DAMAGE: f32 = 120.0;
+const BARREL_BLAST_RADIUS: f32 = 9.0;
+/// Damage to the vehicle at the center of a barrel blast, falling off to 0 at the edge
+const BARREL_VEHICLE_DAMAGE: f32 = 30.0;
+
+/// Barrel mesh and its normal and flashing materials
+#[derive(Resource)]
+pub struct BarrelAssets {
+    pub mesh: Handle<Mesh>,
+    pub material: Handle<StandardMaterial>,
+    pub flash_material: Handle<StandardMaterial>,
+}
+
 pub struct EnvironmentPlugin;
 
 impl Plugin for EnvironmentPlugin {
@@ -19,7 +66,10 @@ impl Plugin for EnvironmentPlugin {
         app.add_systems(Startup, spawn_environment)
             .add_systems(Update, (
                 apply_water_effects,
-            ).run_if(in_state(GameState::Playing)));
+                update_barrels,
+            ).run_if(in_state(GameState::Playing)))
+            // After the restart has picked its seed
+            .add_systems(Update, respawn_barrels.after(crate::pause::handle_restart_game));
     }
 }
 
@@ -27,6 +77,8 @@ fn spawn_environment(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    terrain: Res<TerrainSeed>,
+    mut rng: ResMut<GameRng>,
 ) {
     let water_material = materials.add(StandardMaterial {
         base_color: Color::srgba(0.2, 0.5, 0.8, 0.7),
@@ -36,18 +88,16 @@ fn spawn_environment(
 
     let obstacle_material = materials.add(Color::srgb(0.4, 0.4, 0.45));
 
-    let mut rng = rand::thread_rng();
-
     // Spawn water bodies (rivers and lakes)
     // Create a river flowing through the map
     for i in -5..5 {
         let z = i as f32 * 30.0;
-        let width = 15.0 + (rand::random::<f32>() * 5.0);
+        let width = rng.gen_range(15.0..20.0);
 
         commands.spawn((
-            WaterBody { slow_factor: 0.5 },
-            Transform::from_xyz(0.0, -0.3, z),
-            Mesh3d(meshes.add(Plane3d::new(Vec3::Y, Vec2::new(500.0, width)))),
+            WaterBody { slow_factor: 0.5, half_extents: Vec2::new(500.0, width), round: false },
+            Transform::from_xyz(0.0, 0.0, z),
+            Mesh3d(meshes.add(draped_water_mesh(&terrain, Vec2::new(0.0, z), Vec2::new(500.0, width), false))),
             MeshMaterial3d(water_material.clone()),
         ));
 
@@ -66,9 +116,9 @@ fn spawn_environment(
         let radius = rng.gen_range(10.0..20.0);
 
         commands.spawn((
-            WaterBody { slow_factor: 0.5 },
-            Transform::from_xyz(x, -0.3, z),
-            Mesh3d(meshes.add(Circle { radius })),
+            WaterBody { slow_factor: 0.5, half_extents: Vec2::splat(radius), round: true },
+            Transform::from_xyz(x, 0.0, z),
+            Mesh3d(meshes.add(draped_water_mesh(&terrain, Vec2::new(x, z), Vec2::splat(radius), true))),
             MeshMaterial3d(water_material.clone()),
         ));
     }
@@ -86,7 +136,8 @@ fn spawn_environment(
 
         commands.spawn((
             Obstacle,
-            Transform::from_xyz(x, scale * 0.3, z).with_scale(Vec3::splat(scale)),
+            // Center at 60% of the radius so the rock sits slightly sunk into the ground
+            Transform::from_xyz(x, terrain.ground_height(x, z) + scale * 0.3, z).with_scale(Vec3::splat(scale)),
             Mesh3d(meshes.add(Sphere { radius: 0.5 })),
             MeshMaterial3d(obstacle_material.clone()),
             RigidBody::Fixed,
@@ -107,7 +158,7 @@ fn spawn_environment(
 
         commands.spawn((
             Obstacle,
-            Transform::from_xyz(x, 0.5, z)
+            Transform::from_xyz(x, terrain.ground_height(x, z) + 0.4, z)
                 .with_rotation(Quat::from_rotation_y(rotation))
                 .with_scale(Vec3::new(0.8, 0.8, 6.0)),
             Mesh3d(meshes.add(Cylinder::new(0.5, 1.0))),
@@ -116,6 +167,150 @@ fn spawn_environment(
             Collider::cylinder(0.5, 3.0),
         ));
     }
+
+    let barrel_assets = BarrelAssets {
+        mesh: meshes.add(Cylinder::new(BARREL_RADIUS, BARREL_HEIGHT)),
+        material: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.75, 0.08, 0.06),
+            metallic: 0.4,
+            perceptual_roughness: 0.5,
+            ..default()
+        }),
+        flash_material: materials.add(StandardMaterial {
+            base_color: Color::srgb(1.0, 0.85, 0.6),
+            emissive: LinearRgba::rgb(12.0, 6.0, 1.5),
+            ..default()
+        }),
+    };
+    spawn_barrels(&mut commands, &barrel_assets, &terrain, &mut rng.layout(BARREL_LAYOUT));
+    commands.insert_resource(barrel_assets);
+}
+
+fn spawn_barrels(commands: &mut Commands, assets: &BarrelAssets, terrain: &TerrainSeed, rng: &mut impl Rng) {
+    for _ in 0..BARREL_COUNT {
+        let x: f32 = rng.gen_range(-130.0..130.0);
+        let z: f32 = rng.gen_range(-130.0..130.0);
+
+        // Don't spawn too close to origin
+        if x.abs() < 15.0 && z.abs() < 15.0 {
+            continue;
+        }
+
+        commands.spawn((
+            ExplosiveBarrel::default(),
+            Transform::from_xyz(x, terrain.ground_height(x, z) + BARREL_HEIGHT * 0.5, z),
+            Mesh3d(assets.mesh.clone()),
+            MeshMaterial3d(assets.material.clone()),
+            RigidBody::Fixed,
+            Collider::cylinder(BARREL_HEIGHT * 0.5, BARREL_RADIUS),
+        ));
+    }
+}
+
+fn update_barrels(
+    time: Res<Time>,
+    mut commands: Commands,
+    assets: Res<BarrelAssets>,
+    mut barrel_q: Query<(Entity, &mut ExplosiveBarrel, &Transform, &mut MeshMaterial3d<StandardMaterial>)>,
+    mut vehicle_q: Query<(&Transform, &mut VehicleHealth), (With<PlayerVehicle>, Without<ExplosiveBarrel>)>,
+    mut explosion_events: EventWriter<RocketExplosionEvent>,
+) {
+    for (entity, mut barrel, transform, mut material) in barrel_q.iter_mut() {
+        let Some(fuse) = barrel.fuse.as_mut() else {
+            continue;
+        };
+
+        // Pre-explosion flash
+        if fuse.elapsed_secs() == 0.0 {
+            material.0 = assets.flash_material.clone();
+        }
+
+        fuse.tick(time.delta());
+        if !fuse.finished() {
+            continue;
+        }
+
+        let position = transform.translation;
+        explosion_events.send(RocketExplosionEvent {
+            position,
+            damage: BARREL_DAMAGE,
+            radius: BARREL_BLAST_RADIUS,
+            environmental: true,
+        });
+
+        // Unlike the player's rockets, barrels don't care who is standing next to them
+        for (vehicle_transform, mut health) in vehicle_q.iter_mut() {
+            let distance = vehicle_transform.translation.distance(position);
+            if distance < BARREL_BLAST_RADIUS {
+                health.current = (health.current - BARREL_VEHICLE_DAMAGE * (1.0 - distance / BARREL_BLAST_RADIUS)).max(0.0);
+                health.last_damaged = time.elapsed_secs();
+            }
+        }
+
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// A restarted hunt gets a fresh set of barrels
+fn respawn_barrels(
+    mut commands: Commands,
+    mut events: EventReader<RestartGameEvent>,
+    assets: Option<Res<BarrelAssets>>,
+    terrain: Res<TerrainSeed>,
+    barrel_q: Query<Entity, With<ExplosiveBarrel>>,
+    rng: Res<GameRng>,
+) {
+    let Some(assets) = assets else {
+        return;
+    };
+    if events.read().count() == 0 {
+        return;
+    }
+
+    for entity in barrel_q.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    spawn_barrels(&mut commands, &assets, &terrain, &mut rng.layout(BARREL_LAYOUT));
+}
+
+/// Water surface laid over the terrain around `center`, in the local space of an entity at
+/// ground level 0 there. Round bodies squeeze the grid into a disk of radius `half_extents.x`.
+fn draped_water_mesh(terrain: &TerrainSeed, center: Vec2, half_extents: Vec2, round: bool) -> Mesh {
+    let cells_x = ((half_extents.x * 2.0 / WATER_CELL_SIZE).ceil() as u32).max(1);
+    let cells_z = ((half_extents.y * 2.0 / WATER_CELL_SIZE).ceil() as u32).max(1);
+
+    let mut positions = Vec::new();
+    for iz in 0..=cells_z {
+        for ix in 0..=cells_x {
+            // -1..1 across the body
+            let u = ix as f32 / cells_x as f32 * 2.0 - 1.0;
+            let v = iz as f32 / cells_z as f32 * 2.0 - 1.0;
+            let (u, v) = if round {
+                (u * (1.0 - v * v * 0.5).sqrt(), v * (1.0 - u * u * 0.5).sqrt())
+            } else {
+                (u, v)
+            };
+            let local = Vec2::new(u, v) * half_extents;
+            let world = center + local;
+            positions.push([local.x, terrain.ground_height(world.x, world.y) + WATER_LIFT, local.y]);
+        }
+    }
+
+    let row = cells_x + 1;
+    let mut indices = Vec::new();
+    for iz in 0..cells_z {
+        for ix in 0..cells_x {
+            let i = iz * row + ix;
+            // Counter-clockwise seen from above
+            indices.extend([i, i + row, i + 1, i + 1, i + row, i + row + 1]);
+        }
+    }
+
+    let normals = vec![[0.0, 1.0, 0.0]; positions.len()];
+    Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::RENDER_WORLD)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_indices(Indices::U32(indices))
 }
 
 fn apply_water_effects(
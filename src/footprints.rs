@@ -0,0 +1,175 @@
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+use crate::pause::GameState;
+use crate::dino::{AIState, DinoAI, DinoSpecies, Dinosaur};
+use crate::effects::FadeMaterials;
+use crate::terrain::TerrainSeed;
+
+/// Oldest footprints are removed once this many exist
+const MAX_FOOTPRINTS: usize = 300;
+/// Just above the ground to avoid z-fighting
+const FOOTPRINT_LIFT: f32 = 0.02;
+/// Opacity of a fresh print
+const FOOTPRINT_ALPHA: f32 = 0.8;
+
+/// Per-dino stride tracking, added the first time a dino moves
+#[derive(Component)]
+pub struct FootprintTrail {
+    pub last_print: Vec3,
+    pub left_foot: bool,
+}
+
+#[derive(Component)]
+pub struct Footprint {
+    pub lifetime: Timer,
+    pub species: DinoSpecies,
+}
+
+/// Spawn order of live footprints, used to despawn the oldest first
+#[derive(Resource, Default)]
+pub struct FootprintRegistry {
+    pub prints: VecDeque<Entity>,
+}
+
+#[derive(Resource)]
+struct FootprintMesh(Handle<Mesh>);
+
+/// Fade steps for each species' print color
+#[derive(Resource)]
+struct FootprintMaterials(HashMap<DinoSpecies, FadeMaterials>);
+
+pub struct FootprintPlugin;
+
+impl Plugin for FootprintPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FootprintRegistry>()
+            .add_systems(Startup, setup_footprint_assets)
+            .add_systems(Update, (
+                spawn_footprints,
+                update_footprints,
+            ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// (stride length, print radius, lifetime seconds, color) per species
+fn footprint_style(species: DinoSpecies) -> (f32, f32, f32, Color) {
+    match species {
+        DinoSpecies::Velociraptor => (1.5, 0.2, 10.0, Color::srgb(0.3, 0.22, 0.18)),
+        DinoSpecies::Triceratops => (2.5, 0.4, 10.0, Color::srgb(0.35, 0.25, 0.12)),
+        DinoSpecies::Stegosaurus => (2.5, 0.4, 10.0, Color::srgb(0.25, 0.28, 0.15)),
+        DinoSpecies::Brachiosaurus => (4.0, 0.7, 12.0, Color::srgb(0.3, 0.27, 0.2)),
+        DinoSpecies::TRex => (3.5, 0.8, 20.0, Color::srgb(0.35, 0.18, 0.1)),
+    }
+}
+
+fn setup_footprint_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(FootprintMesh(meshes.add(Circle::new(1.0))));
+
+    let species = [
+        DinoSpecies::Velociraptor,
+        DinoSpecies::Triceratops,
+        DinoSpecies::Stegosaurus,
+        DinoSpecies::Brachiosaurus,
+        DinoSpecies::TRex,
+    ];
+    commands.insert_resource(FootprintMaterials(
+        species
+            .into_iter()
+            .map(|species| (species, FadeMaterials::new(&mut materials, footprint_style(species).3, FOOTPRINT_ALPHA)))
+            .collect(),
+    ));
+}
+
+fn spawn_footprints(
+    mut commands: Commands,
+    footprint_materials: Res<FootprintMaterials>,
+    footprint_mesh: Res<FootprintMesh>,
+    mut registry: ResMut<FootprintRegistry>,
+    terrain: Res<TerrainSeed>,
+    mut dino_q: Query<(Entity, &Transform, &DinoAI, &DinoSpecies, Option<&mut FootprintTrail>), With<Dinosaur>>,
+) {
+    for (entity, transform, ai, species, trail) in dino_q.iter_mut() {
+        let Some(mut trail) = trail else {
+            commands.entity(entity).insert(FootprintTrail {
+                last_print: transform.translation,
+                left_foot: false,
+            });
+            continue;
+        };
+
+        if ai.state == AIState::Dead {
+            continue;
+        }
+
+        let (stride, radius, lifetime, _) = footprint_style(*species);
+
+        let moved = transform.translation.xz().distance(trail.last_print.xz());
+        if moved < stride {
+            continue;
+        }
+
+        trail.last_print = transform.translation;
+        trail.left_foot = !trail.left_foot;
+
+        // Alternate feet to either side of the direction of travel
+        let side = if trail.left_foot { -1.0 } else { 1.0 };
+        let offset = transform.rotation * Vec3::X * radius * 1.2 * side;
+        let (x, z) = (transform.translation.x + offset.x, transform.translation.z + offset.z);
+        let position = Vec3::new(x, terrain.ground_height(x, z) + FOOTPRINT_LIFT, z);
+
+        let print = commands.spawn((
+            Footprint {
+                lifetime: Timer::from_seconds(lifetime, TimerMode::Once),
+                species: *species,
+            },
+            Mesh3d(footprint_mesh.0.clone()),
+            MeshMaterial3d(footprint_materials.0[species].at(FOOTPRINT_ALPHA)),
+            // Circle faces +Z, lay it flat on the ground
+            Transform::from_translation(position)
+                .with_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+                .with_scale(Vec3::new(radius, radius * 1.4, 1.0)),
+        )).id();
+
+        registry.prints.push_back(print);
+
+        // Keep the total bounded, oldest first
+        while registry.prints.len() > MAX_FOOTPRINTS {
+            if let Some(oldest) = registry.prints.pop_front() {
+                if let Some(mut entity_commands) = commands.get_entity(oldest) {
+                    entity_commands.despawn();
+                }
+            }
+        }
+    }
+}
+
+fn update_footprints(
+    time: Res<Time>,
+    mut commands: Commands,
+    footprint_materials: Res<FootprintMaterials>,
+    mut registry: ResMut<FootprintRegistry>,
+    mut footprint_q: Query<(Entity, &mut Footprint, &mut MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (entity, mut footprint, mut material) in footprint_q.iter_mut() {
+        footprint.lifetime.tick(time.delta());
+
+        if footprint.lifetime.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // Fade out over the lifetime
+        let alpha = FOOTPRINT_ALPHA * (1.0 - footprint.lifetime.fraction());
+        let faded = footprint_materials.0[&footprint.species].at(alpha);
+        if material.0 != faded {
+            material.0 = faded;
+        }
+    }
+
+    // Drop registry entries for prints that expired on their own
+    registry.prints.retain(|entity| footprint_q.contains(*entity));
+}
@@ -0,0 +1,184 @@
+use bevy::prelude::*;
+use rand::Rng;
+use crate::pause::GameState;
+use crate::game_mode::TimeAttackMode;
+use crate::vehicle::PlayerVehicle;
+use crate::terrain::TerrainSeed;
+use crate::rng::GameRng;
+
+const MAX_CANISTERS: usize = 5;
+const CANISTER_SPAWN_INTERVAL: f32 = 20.0;
+const CANISTER_PICKUP_RADIUS: f32 = 3.0;
+/// Share of the tank a canister refills
+const CANISTER_REFILL: f32 = 0.4;
+const CANISTER_SPAWN_RANGE: f32 = 120.0;
+/// Canisters hover this high above the ground, bobbing around it
+const CANISTER_HOVER: f32 = 1.1;
+
+/// Vehicle fuel, drained by throttle in handle_vehicle_movement
+#[derive(Resource)]
+pub struct Fuel {
+    pub current: f32,
+    pub capacity: f32,
+    pub drain_rate: f32, // Units per second at full throttle and top speed
+    pub enabled_in_free_hunt: bool,
+    pub active: bool, // Whether fuel matters in the current mode
+}
+
+impl Default for Fuel {
+    fn default() -> Self {
+        Self {
+            current: 100.0,
+            capacity: 100.0,
+            drain_rate: 1.5,
+            enabled_in_free_hunt: false,
+            active: false,
+        }
+    }
+}
+
+impl Fuel {
+    /// The vehicle can accelerate unless fuel is in play and the tank is dry
+    pub fn can_accelerate(&self) -> bool {
+        !self.active || self.current > 0.0
+    }
+
+    pub fn drain(&mut self, amount: f32) {
+        if self.active {
+            self.current = (self.current - amount).max(0.0);
+        }
+    }
+
+    pub fn refill(&mut self, fraction: f32) {
+        self.current = (self.current + self.capacity * fraction).min(self.capacity);
+    }
+
+    pub fn fraction(&self) -> f32 {
+        self.current / self.capacity
+    }
+}
+
+#[derive(Component)]
+pub struct FuelCanister;
+
+#[derive(Resource)]
+struct CanisterSpawner {
+    timer: Timer,
+}
+
+#[derive(Resource)]
+struct CanisterAssets {
+    mesh: Handle<Mesh>,
+    material: Handle<StandardMaterial>,
+}
+
+pub struct FuelPlugin;
+
+impl Plugin for FuelPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Fuel>()
+            .insert_resource(CanisterSpawner {
+                timer: Timer::from_seconds(CANISTER_SPAWN_INTERVAL, TimerMode::Repeating),
+            })
+            .add_systems(Startup, setup_canister_assets)
+            .add_systems(Update, (
+                update_fuel_mode,
+                spawn_fuel_canisters,
+                collect_fuel_canisters,
+                animate_fuel_canisters,
+            ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// Fuel always counts in Time Attack; Free Hunt only when the flag is on
+fn update_fuel_mode(
+    time_attack: Res<TimeAttackMode>,
+    mut fuel: ResMut<Fuel>,
+) {
+    let active = time_attack.is_active || fuel.enabled_in_free_hunt;
+    if fuel.active != active {
+        fuel.active = active;
+        fuel.current = fuel.capacity;
+    }
+}
+
+fn setup_canister_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(CanisterAssets {
+        mesh: meshes.add(Cuboid::new(0.8, 1.2, 0.5)),
+        material: materials.add(StandardMaterial {
+            base_color: Color::srgb(0.85, 0.15, 0.1),
+            emissive: LinearRgba::new(0.4, 0.05, 0.0, 1.0),
+            ..default()
+        }),
+    });
+}
+
+fn spawn_fuel_canisters(
+    time: Res<Time>,
+    mut commands: Commands,
+    canister_assets: Res<CanisterAssets>,
+    fuel: Res<Fuel>,
+    mut spawner: ResMut<CanisterSpawner>,
+    terrain: Res<TerrainSeed>,
+    canister_q: Query<Entity, With<FuelCanister>>,
+    mut rng: ResMut<GameRng>,
+) {
+    if !fuel.active {
+        // Clear leftovers when switching back to casual play
+        for entity in canister_q.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    spawner.timer.tick(time.delta());
+    if !spawner.timer.just_finished() || canister_q.iter().count() >= MAX_CANISTERS {
+        return;
+    }
+
+    let x = rng.gen_range(-CANISTER_SPAWN_RANGE..CANISTER_SPAWN_RANGE);
+    let z = rng.gen_range(-CANISTER_SPAWN_RANGE..CANISTER_SPAWN_RANGE);
+
+    commands.spawn((
+        FuelCanister,
+        Mesh3d(canister_assets.mesh.clone()),
+        MeshMaterial3d(canister_assets.material.clone()),
+        Transform::from_xyz(x, terrain.ground_height(x, z) + CANISTER_HOVER, z),
+    ));
+}
+
+fn collect_fuel_canisters(
+    mut commands: Commands,
+    mut fuel: ResMut<Fuel>,
+    vehicle_q: Query<&Transform, With<PlayerVehicle>>,
+    canister_q: Query<(Entity, &Transform), With<FuelCanister>>,
+) {
+    let Ok(vehicle_transform) = vehicle_q.get_single() else {
+        return;
+    };
+
+    for (entity, transform) in canister_q.iter() {
+        let distance = transform.translation.xz().distance(vehicle_transform.translation.xz());
+        if distance < CANISTER_PICKUP_RADIUS {
+            fuel.refill(CANISTER_REFILL);
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn animate_fuel_canisters(
+    time: Res<Time>,
+    terrain: Res<TerrainSeed>,
+    mut canister_q: Query<&mut Transform, With<FuelCanister>>,
+) {
+    let t = time.elapsed_secs();
+    for mut transform in canister_q.iter_mut() {
+        transform.rotate_y(1.5 * time.delta_secs());
+        let ground = terrain.ground_height(transform.translation.x, transform.translation.z);
+        transform.translation.y = ground + CANISTER_HOVER + (t * 2.0).sin() * 0.2;
+    }
+}
@@ -1,7 +1,14 @@
 use bevy::prelude::*;
-use crate::pause::GameState;
+use crate::pause::{GameState, RestartGameEvent, RestartProgress};
 use crate::GameScore;
 use crate::combo::ComboSystem;
+use crate::round_start::RoundStart;
+use crate::main_menu::{spawn_main_menu, MenuButton, RetrySameSeedButton};
+use crate::vehicle::SelectedVehicle;
+use crate::save::SaveFile;
+use crate::run_save::RunSaveFile;
+use crate::rng::GameRng;
+use crate::weapon::AccuracyStats;
 
 #[derive(Resource, Default)]
 pub struct TimeAttackMode {
@@ -47,6 +54,16 @@ impl TimeAttackMode {
     }
 }
 
+/// Color of a rank letter on the results screen
+fn rank_color(rank: &str) -> Color {
+    match rank {
+        "S" => Color::srgb(1.0, 0.8, 0.1),
+        "A" => Color::srgb(0.3, 0.9, 0.3),
+        "B" => Color::srgb(0.3, 0.6, 1.0),
+        _ => Color::srgb(0.7, 0.7, 0.7),
+    }
+}
+
 pub struct GameModePlugin;
 
 impl Plugin for GameModePlugin {
@@ -55,7 +72,10 @@ impl Plugin for GameModePlugin {
             .add_systems(Update, (
                 update_time_attack,
                 check_time_attack_end,
-            ).run_if(in_state(GameState::Playing)));
+            ).run_if(in_state(GameState::Playing)))
+            .add_systems(OnEnter(GameState::Paused), setup_time_attack_results)
+            .add_systems(Update, handle_results_input.run_if(in_state(GameState::Paused)))
+            .add_systems(OnExit(GameState::Paused), cleanup_time_attack_results);
     }
 }
 
@@ -63,8 +83,10 @@ fn update_time_attack(
     time: Res<Time>,
     mut mode: ResMut<TimeAttackMode>,
     combo: Res<ComboSystem>,
+    round_start: Res<RoundStart>,
 ) {
-    if !mode.is_active {
+    // The clock starts on "GO"
+    if !mode.is_active || round_start.is_counting() {
         return;
     }
 
@@ -91,3 +113,166 @@ pub struct TimeAttackText;
 
 #[derive(Component)]
 pub struct TimeAttackResultText;
+
+/// Root of the results overlay shown when Time Attack runs out
+#[derive(Component)]
+pub struct TimeAttackResults;
+
+#[derive(Component)]
+pub struct RetryButton;
+
+/// Leaves the results for the main menu
+#[derive(Component)]
+pub struct ResultsMenuButton;
+
+fn setup_time_attack_results(
+    mut commands: Commands,
+    mode: Res<TimeAttackMode>,
+    score: Res<GameScore>,
+    accuracy: Res<AccuracyStats>,
+    rng: Res<GameRng>,
+) {
+    if !mode.is_finished() {
+        return;
+    }
+
+    let rank = mode.get_rank();
+
+    commands.spawn((
+        TimeAttackResults,
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(16.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.05, 0.1, 0.92)),
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("TIME'S UP!"),
+            TextFont {
+                font_size: 48.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.8, 0.2)),
+        ));
+
+        // Big letter grade
+        parent.spawn((
+            Text::new(rank),
+            TextFont {
+                font_size: 160.0,
+                ..default()
+            },
+            TextColor(rank_color(rank)),
+        ));
+
+        parent.spawn((
+            TimeAttackResultText,
+            Text::new(format!(
+                "Kills: {}\nMax Combo: {}\nAccuracy: {:.0}% ({}/{})\nFinal Score: {}\nSeed: {}",
+                mode.kills,
+                mode.max_combo,
+                accuracy.percent(),
+                accuracy.shots_hit,
+                accuracy.shots_fired,
+                score.score,
+                rng.seed()
+            )),
+            TextFont {
+                font_size: 26.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.85, 0.85, 0.85)),
+            TextLayout::new_with_justify(JustifyText::Center),
+            Node {
+                margin: UiRect::bottom(Val::Px(20.0)),
+                ..default()
+            },
+        ));
+
+        parent.spawn(Node {
+            column_gap: Val::Px(20.0),
+            ..default()
+        }).with_children(|row| {
+            spawn_results_button(row, RetryButton, "Retry", Color::srgb(0.7, 0.3, 0.2));
+            spawn_results_button(row, RetrySameSeedButton, "Same Seed", Color::srgb(0.45, 0.35, 0.55));
+            spawn_results_button(row, ResultsMenuButton, "Menu", Color::srgb(0.2, 0.4, 0.7));
+        });
+    });
+}
+
+fn spawn_results_button(parent: &mut ChildBuilder, marker: impl Component, label: &str, color: Color) {
+    parent.spawn((
+        marker,
+        Button,
+        Node {
+            width: Val::Px(180.0),
+            height: Val::Px(50.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(color),
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 24.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn handle_results_input(
+    mut commands: Commands,
+    mut mode: ResMut<TimeAttackMode>,
+    save_file: Res<SaveFile>,
+    run_save: Res<RunSaveFile>,
+    rng: Res<GameRng>,
+    selected_vehicle: Res<SelectedVehicle>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut restart_events: EventWriter<RestartGameEvent>,
+    interaction_q: Query<
+        (&Interaction, Has<RetryButton>, Has<RetrySameSeedButton>, Has<ResultsMenuButton>),
+        // The main menu has its own same-seed button
+        (Changed<Interaction>, Without<MenuButton>),
+    >,
+    results_q: Query<Entity, With<TimeAttackResults>>,
+) {
+    for (interaction, is_retry, is_same_seed, is_menu) in interaction_q.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        if is_retry || is_same_seed {
+            // Fresh hunt, fresh clock
+            mode.start();
+            restart_events.send(RestartGameEvent {
+                progress: RestartProgress::Continue,
+                same_seed: is_same_seed,
+            });
+            next_state.set(GameState::Playing);
+        } else if is_menu {
+            mode.stop();
+            for entity in results_q.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            spawn_main_menu(&mut commands, &mode, &save_file, &run_save, rng.seed(), selected_vehicle.0);
+        }
+    }
+}
+
+fn cleanup_time_attack_results(
+    mut commands: Commands,
+    results_q: Query<Entity, With<TimeAttackResults>>,
+) {
+    for entity in results_q.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
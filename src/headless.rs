@@ -0,0 +1,45 @@
+use std::time::Duration;
+use bevy::prelude::*;
+use bevy::app::ScheduleRunnerPlugin;
+use bevy::diagnostic::DiagnosticsPlugin;
+use bevy::scene::ScenePlugin;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use bevy_rapier3d::prelude::DebugRenderContext;
+use crate::save::SaveFile;
+use crate::GamePlugin;
+
+/// Fixed step each update advances the clock by, so runs don't depend on how fast the host is
+const HEADLESS_STEP: Duration = Duration::from_nanos(1_000_000_000 / 60);
+
+/// The game without a window, renderer or audio device, for CI and integration tests.
+/// Step it with `App::update`; `run` loops at 60 updates a second.
+/// Meshes, materials and UI nodes are still spawned, they just never get drawn.
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(HEADLESS_STEP)),
+        TransformPlugin,
+        HierarchyPlugin,
+        bevy::input::InputPlugin,
+        StatesPlugin,
+        AssetPlugin::default(),
+        ScenePlugin,
+        DiagnosticsPlugin,
+        bevy::log::LogPlugin::default(),
+    ))
+    // Asset storage that DefaultPlugins would otherwise register through the render and audio plugins
+    .init_asset::<Mesh>()
+    .init_asset::<StandardMaterial>()
+    .init_asset::<AudioSource>()
+    // F4 toggles the collider wireframes, which aren't drawn here
+    .init_resource::<DebugRenderContext>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(HEADLESS_STEP))
+    // Never touch the player's save file
+    .insert_resource(SaveFile {
+        exists: false,
+        enabled: false,
+    })
+    .add_plugins(GamePlugin);
+    app
+}
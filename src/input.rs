@@ -3,9 +3,16 @@ use bevy::window::CursorGrabMode;
 use bevy::input::mouse::MouseMotion;
 use crate::weapon_system::{WeaponType, WeaponSwitchedEvent, WeaponInventory};
 use crate::pause::GameState;
+use crate::shop::ShopState;
+use crate::minimap::TacticalMapState;
+use crate::round_start::RoundStart;
+use crate::kill_cam::KillCam;
 
 pub struct InputPlugin;
 
+/// Two presses of A or D within this many seconds trigger a dodge
+const DOUBLE_TAP_WINDOW: f32 = 0.25;
+
 #[derive(Resource, Default, Clone)]
 pub struct PlayerInput {
     pub move_forward: bool,
@@ -14,16 +21,57 @@ pub struct PlayerInput {
     pub move_right: bool,
     pub shooting: bool,
     pub mouse_position: Vec2,
+    pub mouse_delta: Vec2, // Scaled mouse movement for the current frame
     pub turret_left: bool,
     pub turret_right: bool,
     pub lock_target: bool,
+    pub cycle_target: bool, // Step the lock to the next visible dino
     pub pause: bool,
+    pub toggle_shop: bool,
     pub weapon_switch_1: bool,
     pub weapon_switch_2: bool,
     pub weapon_switch_3: bool,
     pub weapon_scroll: f32, // Positive = next weapon, Negative = previous
+    pub cycle_ammo: bool,
     pub camera_up: bool,
     pub camera_down: bool,
+    pub handbrake: bool,
+    pub tether: bool, // Fire or release the tow tether
+    pub dodge: f32, // -1 = dodge left, 1 = dodge right, 0 = none this frame
+    pub bullet_time: bool, // Held: slow the world down
+}
+
+/// Mouse aim tuning, applied to raw motion before it reaches gameplay
+#[derive(Resource)]
+pub struct MouseSettings {
+    pub sensitivity: f32,
+    pub invert_y: bool,
+    pub aim_assist: bool, // Soft free-aim pull toward nearby dinos, off by default
+    /// Right-click toggles a lock on the nearest dino that holds until it dies or leaves range;
+    /// off, every right-click cycles to the next dino
+    pub sticky_lock: bool,
+}
+
+impl Default for MouseSettings {
+    fn default() -> Self {
+        Self {
+            sensitivity: 1.0,
+            invert_y: false,
+            aim_assist: false,
+            sticky_lock: false,
+        }
+    }
+}
+
+impl MouseSettings {
+    pub fn adjust_sensitivity(&mut self, delta: f32) {
+        self.sensitivity = (self.sensitivity + delta).clamp(0.1, 5.0);
+    }
+
+    /// Bullet hit radius multiplier, slightly generous with aim assist on
+    pub fn hitbox_scale(&self) -> f32 {
+        if self.aim_assist { 1.2 } else { 1.0 }
+    }
 }
 
 #[derive(Resource, Default)]
@@ -36,8 +84,11 @@ impl Plugin for InputPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<PlayerInput>()
             .init_resource::<TargetLock>()
+            .init_resource::<MouseSettings>()
             .add_event::<WeaponSwitchedEvent>()
             .add_systems(Startup, grab_cursor)
+            .add_systems(OnEnter(GameState::Paused), clear_player_input)
+            .add_systems(OnEnter(GameState::FreeCam), clear_player_input)
             .add_systems(Update, (
                 handle_key_input,
                 handle_mouse_input,
@@ -55,10 +106,47 @@ fn grab_cursor(mut window_q: Query<&mut Window>) {
     }
 }
 
+/// Input systems stop while paused, so drop held keys rather than replaying them on resume
+fn clear_player_input(mut input: ResMut<PlayerInput>) {
+    *input = PlayerInput::default();
+}
+
 fn handle_key_input(
+    time: Res<Time>,
     keyboard: Res<ButtonInput<KeyCode>>,
+    shop_state: Res<ShopState>,
+    target_lock: Res<TargetLock>,
+    round_start: Res<RoundStart>,
+    kill_cam: Res<KillCam>,
     mut input: ResMut<PlayerInput>,
+    mut last_tap: Local<Option<(KeyCode, f32)>>,
 ) {
+    input.pause = keyboard.just_pressed(KeyCode::Escape);
+    input.toggle_shop = keyboard.just_pressed(KeyCode::Tab) && !round_start.is_counting();
+
+    // Gameplay keys are ignored while the shop overlay is open, the round is counting down
+    // or the kill-cam plays; the debug console swallows keys before they get here
+    if shop_state.is_open || round_start.is_counting() || kill_cam.is_active() {
+        input.move_forward = false;
+        input.move_backward = false;
+        input.move_left = false;
+        input.move_right = false;
+        input.turret_left = false;
+        input.turret_right = false;
+        input.weapon_switch_1 = false;
+        input.weapon_switch_2 = false;
+        input.weapon_switch_3 = false;
+        input.cycle_ammo = false;
+        input.cycle_target = false;
+        input.camera_up = false;
+        input.camera_down = false;
+        input.handbrake = false;
+        input.tether = false;
+        input.dodge = 0.0;
+        input.bullet_time = false;
+        return;
+    }
+
     input.move_forward = keyboard.pressed(KeyCode::KeyW) || keyboard.pressed(KeyCode::ArrowUp);
     input.move_backward = keyboard.pressed(KeyCode::KeyS) || keyboard.pressed(KeyCode::ArrowDown);
     input.move_left = keyboard.pressed(KeyCode::KeyA) || keyboard.pressed(KeyCode::ArrowLeft);
@@ -66,22 +154,60 @@ fn handle_key_input(
 
     input.turret_left = keyboard.pressed(KeyCode::KeyQ);
     input.turret_right = keyboard.pressed(KeyCode::KeyE);
-    input.pause = keyboard.just_pressed(KeyCode::Escape);
+
+    input.tether = keyboard.just_pressed(KeyCode::KeyG);
+    input.bullet_time = keyboard.pressed(KeyCode::KeyF);
+
+    // Dodge: C rolls toward the side being steered (right if none), or double-tap A/D
+    input.dodge = 0.0;
+    let now = time.elapsed_secs();
+    for (key, side) in [(KeyCode::KeyA, -1.0), (KeyCode::KeyD, 1.0)] {
+        if !keyboard.just_pressed(key) {
+            continue;
+        }
+        match *last_tap {
+            Some((last_key, at)) if last_key == key && now - at < DOUBLE_TAP_WINDOW => {
+                input.dodge = side;
+                *last_tap = None;
+            }
+            _ => *last_tap = Some((key, now)),
+        }
+    }
+    if keyboard.just_pressed(KeyCode::KeyC) {
+        input.dodge = if input.move_left && !input.move_right { -1.0 } else { 1.0 };
+    }
 
     // Weapon switching
     input.weapon_switch_1 = keyboard.just_pressed(KeyCode::Digit1);
     input.weapon_switch_2 = keyboard.just_pressed(KeyCode::Digit2);
     input.weapon_switch_3 = keyboard.just_pressed(KeyCode::Digit3);
+    input.cycle_ammo = keyboard.just_pressed(KeyCode::KeyT);
+    input.cycle_target = keyboard.just_pressed(KeyCode::KeyX);
 
     // Camera angle adjustment (Page Up/Page Down)
     input.camera_up = keyboard.pressed(KeyCode::PageUp);
     input.camera_down = keyboard.pressed(KeyCode::PageDown);
+
+    // Handbrake on Left Shift, or Space when it isn't firing at a locked target
+    input.handbrake = keyboard.pressed(KeyCode::ShiftLeft)
+        || (keyboard.pressed(KeyCode::Space) && target_lock.locked_entity.is_none());
 }
 
 fn handle_mouse_input(
     mut input: ResMut<PlayerInput>,
     mouse_button: Res<ButtonInput<MouseButton>>,
+    shop_state: Res<ShopState>,
+    map_state: Res<TacticalMapState>,
+    round_start: Res<RoundStart>,
+    kill_cam: Res<KillCam>,
 ) {
+    // Clicks go to the shop or to waypoint placement on the map, and do nothing before "GO"
+    if shop_state.is_open || map_state.open || round_start.is_counting() || kill_cam.is_active() {
+        input.shooting = false;
+        input.lock_target = false;
+        return;
+    }
+
     input.shooting = mouse_button.pressed(MouseButton::Left);
     input.lock_target = mouse_button.just_pressed(MouseButton::Right);
 }
@@ -89,16 +215,42 @@ fn handle_mouse_input(
 fn handle_mouse_motion(
     mut input: ResMut<PlayerInput>,
     mut mouse_motion: EventReader<MouseMotion>,
+    settings: Res<MouseSettings>,
+    shop_state: Res<ShopState>,
+    round_start: Res<RoundStart>,
+    kill_cam: Res<KillCam>,
 ) {
+    input.mouse_delta = Vec2::ZERO;
+
+    if shop_state.is_open || round_start.is_counting() || kill_cam.is_active() {
+        mouse_motion.clear();
+        return;
+    }
+
     for event in mouse_motion.read() {
-        input.mouse_position += event.delta;
+        let mut delta = event.delta * settings.sensitivity;
+        if settings.invert_y {
+            delta.y = -delta.y;
+        }
+
+        input.mouse_delta += delta;
+        input.mouse_position += delta;
     }
 }
 
 fn handle_mouse_wheel(
     mut input: ResMut<PlayerInput>,
     mut mouse_wheel: EventReader<bevy::input::mouse::MouseWheel>,
+    shop_state: Res<ShopState>,
 ) {
+    input.weapon_scroll = 0.0;
+
+    // The wheel scrolls the shop list while it is open
+    if shop_state.is_open {
+        mouse_wheel.clear();
+        return;
+    }
+
     for event in mouse_wheel.read() {
         // Accumulate scroll value
         input.weapon_scroll += event.y;
@@ -110,18 +262,15 @@ fn handle_weapon_switching(
     mut weapon_inventory: ResMut<WeaponInventory>,
     mut weapon_events: EventWriter<WeaponSwitchedEvent>,
 ) {
-    let mut switched = None;
+    let previous = weapon_inventory.current_weapon;
 
     // Check keyboard shortcuts first
     if input.weapon_switch_1 {
         weapon_inventory.switch_to(WeaponType::MachineGun);
-        switched = Some(WeaponType::MachineGun);
     } else if input.weapon_switch_2 {
         weapon_inventory.switch_to(WeaponType::Shotgun);
-        switched = Some(WeaponType::Shotgun);
     } else if input.weapon_switch_3 {
         weapon_inventory.switch_to(WeaponType::RocketLauncher);
-        switched = Some(WeaponType::RocketLauncher);
     }
     // Check mouse wheel
     else if input.weapon_scroll.abs() > 0.1 {
@@ -130,10 +279,14 @@ fn handle_weapon_switching(
         } else {
             weapon_inventory.previous_weapon();
         }
-        switched = Some(weapon_inventory.current_weapon);
     }
 
-    if let Some(weapon) = switched {
-        weapon_events.send(WeaponSwitchedEvent { new_weapon: weapon });
+    if input.cycle_ammo {
+        weapon_inventory.next_ammo();
+    }
+
+    // Locked weapons and single-weapon cycling don't count as a switch
+    if weapon_inventory.current_weapon != previous {
+        weapon_events.send(WeaponSwitchedEvent { new_weapon: weapon_inventory.current_weapon });
     }
 }
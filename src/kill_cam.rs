@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+use crate::camera::MainCamera;
+use crate::effects::ScreenShake;
+use crate::pause::{GameState, RestartGameEvent};
+use crate::smoothing;
+
+/// Real seconds the kill-cam holds the camera
+const KILL_CAM_SECONDS: f32 = 3.0;
+/// Game speed while the kill-cam runs
+const KILL_CAM_TIME_SCALE: f32 = 0.3;
+/// Final stretch of the kill-cam over which game speed eases back to normal
+const TIME_RAMP_SECONDS: f32 = 0.8;
+/// Camera orbit around the falling boss
+const ORBIT_RADIUS: f32 = 16.0;
+const ORBIT_HEIGHT: f32 = 6.0;
+/// Radians the camera swings around the boss over the whole kill-cam
+const ORBIT_SWEEP: f32 = 1.2;
+/// Rate the camera eases toward the orbit, per second, so it swings in rather than cutting
+const CAMERA_RATE: f32 = 7.5;
+
+/// Sent when the T-Rex boss goes down
+#[derive(Event)]
+pub struct KillCamEvent {
+    pub target: Entity,
+}
+
+/// Slow-motion boss kill shot; camera_follow and gameplay input stand down while it runs
+#[derive(Resource)]
+pub struct KillCam {
+    pub target: Option<Entity>,
+    pub focus: Vec3,
+    start_angle: f32,
+    timer: Timer,
+}
+
+impl Default for KillCam {
+    // Starts finished, so nothing plays until a boss dies
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(KILL_CAM_SECONDS, TimerMode::Once);
+        timer.tick(timer.duration());
+        Self {
+            target: None,
+            focus: Vec3::ZERO,
+            start_angle: 0.0,
+            timer,
+        }
+    }
+}
+
+impl KillCam {
+    pub fn is_active(&self) -> bool {
+        !self.timer.finished()
+    }
+}
+
+pub struct KillCamPlugin;
+
+impl Plugin for KillCamPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<KillCam>()
+            .add_event::<KillCamEvent>()
+            .add_systems(Update, (
+                start_kill_cam,
+                update_kill_cam,
+            ).chain().run_if(in_state(GameState::Playing)))
+            .add_systems(Update, cancel_kill_cam_on_restart);
+    }
+}
+
+fn start_kill_cam(
+    mut events: EventReader<KillCamEvent>,
+    mut kill_cam: ResMut<KillCam>,
+    mut shake: ResMut<ScreenShake>,
+    target_q: Query<&Transform, Without<MainCamera>>,
+    camera_q: Query<&Transform, With<MainCamera>>,
+) {
+    for event in events.read() {
+        let Ok(target) = target_q.get(event.target) else {
+            continue;
+        };
+
+        kill_cam.target = Some(event.target);
+        kill_cam.focus = target.translation;
+        // Begin the orbit on the side the camera is already looking from
+        kill_cam.start_angle = camera_q
+            .get_single()
+            .map(|camera| {
+                let offset = camera.translation - target.translation;
+                offset.x.atan2(offset.z)
+            })
+            .unwrap_or(0.0);
+        kill_cam.timer.reset();
+
+        // The ground-shaking thud, stretched out by the slow motion
+        shake.trigger(0.8, 0.6);
+    }
+}
+
+fn update_kill_cam(
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut kill_cam: ResMut<KillCam>,
+    target_q: Query<&Transform, Without<MainCamera>>,
+    mut camera_q: Query<&mut Transform, With<MainCamera>>,
+) {
+    if !kill_cam.is_active() {
+        return;
+    }
+
+    // Runs on real time so the slow motion doesn't stretch the kill-cam itself
+    kill_cam.timer.tick(real_time.delta());
+    if kill_cam.timer.finished() {
+        virtual_time.set_relative_speed(1.0);
+        kill_cam.target = None;
+        return;
+    }
+
+    let ramp = (1.0 - kill_cam.timer.remaining_secs() / TIME_RAMP_SECONDS).clamp(0.0, 1.0);
+    virtual_time.set_relative_speed(KILL_CAM_TIME_SCALE + (1.0 - KILL_CAM_TIME_SCALE) * ramp);
+
+    // Follow the carcass while it still exists
+    if let Some(target) = kill_cam.target.and_then(|entity| target_q.get(entity).ok()) {
+        kill_cam.focus = target.translation;
+    }
+
+    let Ok(mut camera) = camera_q.get_single_mut() else {
+        return;
+    };
+
+    let t = kill_cam.timer.fraction();
+    let angle = kill_cam.start_angle + ORBIT_SWEEP * t * t * (3.0 - 2.0 * t);
+    let eye = kill_cam.focus + Vec3::new(angle.sin() * ORBIT_RADIUS, ORBIT_HEIGHT, angle.cos() * ORBIT_RADIUS);
+
+    camera.translation = camera.translation.lerp(eye, smoothing(CAMERA_RATE, real_time.delta_secs()));
+    camera.look_at(kill_cam.focus + Vec3::Y * 2.0, Vec3::Y);
+}
+
+/// A restart mid kill-cam hands the camera and game speed straight back
+fn cancel_kill_cam_on_restart(
+    mut events: EventReader<RestartGameEvent>,
+    mut kill_cam: ResMut<KillCam>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if events.read().count() == 0 || !kill_cam.is_active() {
+        return;
+    }
+
+    let remaining = kill_cam.timer.remaining();
+    kill_cam.timer.tick(remaining);
+    kill_cam.target = None;
+    virtual_time.set_relative_speed(1.0);
+}
@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use crate::pause::GameState;
+
+/// Maximum number of lines shown in the kill feed
+const MAX_FEED_LINES: usize = 5;
+/// Seconds an entry stays on screen before it is gone
+const ENTRY_LIFETIME: f32 = 4.0;
+/// Seconds at the end of an entry's lifetime spent fading out
+const ENTRY_FADE_TIME: f32 = 1.0;
+
+/// Event to append a line to the kill feed
+#[derive(Event)]
+pub struct KillFeedEvent {
+    pub message: String,
+    pub color: Color,
+}
+
+impl KillFeedEvent {
+    pub fn new(message: impl Into<String>, color: Color) -> Self {
+        Self {
+            message: message.into(),
+            color,
+        }
+    }
+}
+
+struct KillFeedEntry {
+    message: String,
+    color: Color,
+    lifetime: Timer,
+}
+
+/// Ring buffer of recent feed entries, newest first
+#[derive(Resource, Default)]
+pub struct KillFeed {
+    entries: VecDeque<KillFeedEntry>,
+}
+
+impl KillFeed {
+    pub fn push(&mut self, message: String, color: Color) {
+        if self.entries.len() == MAX_FEED_LINES {
+            self.entries.pop_back();
+        }
+
+        self.entries.push_front(KillFeedEntry {
+            message,
+            color,
+            lifetime: Timer::from_seconds(ENTRY_LIFETIME, TimerMode::Once),
+        });
+    }
+}
+
+/// One reusable text line of the feed, index 0 is the newest entry
+#[derive(Component)]
+pub struct KillFeedLine(pub usize);
+
+pub struct KillFeedPlugin;
+
+impl Plugin for KillFeedPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<KillFeed>()
+            .add_event::<KillFeedEvent>()
+            .add_systems(Startup, setup_kill_feed)
+            .add_systems(Update, (
+                collect_kill_feed_events,
+                update_kill_feed,
+            ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn setup_kill_feed(mut commands: Commands) {
+    // Feed container (right side, below the combo counter)
+    commands.spawn(Node {
+        position_type: PositionType::Absolute,
+        right: Val::Px(20.0),
+        top: Val::Px(110.0),
+        flex_direction: FlexDirection::Column,
+        align_items: AlignItems::FlexEnd,
+        row_gap: Val::Px(2.0),
+        ..default()
+    }).with_children(|parent| {
+        for i in 0..MAX_FEED_LINES {
+            parent.spawn((
+                KillFeedLine(i),
+                Text::new(""),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        }
+    });
+}
+
+fn collect_kill_feed_events(
+    mut events: EventReader<KillFeedEvent>,
+    mut feed: ResMut<KillFeed>,
+) {
+    for event in events.read() {
+        feed.push(event.message.clone(), event.color);
+    }
+}
+
+fn update_kill_feed(
+    time: Res<Time>,
+    mut feed: ResMut<KillFeed>,
+    mut line_q: Query<(&KillFeedLine, &mut Text, &mut TextColor)>,
+) {
+    for entry in feed.entries.iter_mut() {
+        entry.lifetime.tick(time.delta());
+    }
+
+    // Expired entries are always the oldest, so drop them from the back
+    while feed.entries.back().is_some_and(|entry| entry.lifetime.finished()) {
+        feed.entries.pop_back();
+    }
+
+    for (line, mut text, mut color) in line_q.iter_mut() {
+        let Some(entry) = feed.entries.get(line.0) else {
+            text.0.clear();
+            continue;
+        };
+
+        // Fade out during the last part of the lifetime
+        let remaining = entry.lifetime.remaining_secs();
+        let alpha = (remaining / ENTRY_FADE_TIME).min(1.0);
+
+        text.0.clone_from(&entry.message);
+        color.0 = entry.color.with_alpha(alpha);
+    }
+}
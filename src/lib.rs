@@ -0,0 +1,294 @@
+use bevy::prelude::*;
+use bevy_rapier3d::prelude::*;
+
+pub mod camera;
+pub mod input;
+pub mod vehicle;
+pub mod dino;
+pub mod weapon;
+pub mod ui;
+pub mod pause;
+pub mod weapon_system;
+pub mod damage_popup;
+pub mod combo;
+pub mod effects;
+pub mod game_mode;
+pub mod main_menu;
+pub mod environment;
+pub mod minimap;
+pub mod shop;
+pub mod settings;
+pub mod kill_feed;
+pub mod audio;
+pub mod music;
+pub mod footprints;
+pub mod contract;
+pub mod save;
+pub mod stealth;
+pub mod skid_marks;
+pub mod fuel;
+pub mod debug_overlay;
+pub mod terrain;
+pub mod coins;
+pub mod round_start;
+pub mod bestiary;
+pub mod proximity;
+pub mod tow;
+pub mod kill_cam;
+pub mod bullet_time;
+pub mod console;
+pub mod rng;
+pub mod headless;
+pub mod run_save;
+pub mod status_effects;
+
+use camera::CameraPlugin;
+use input::InputPlugin;
+use vehicle::VehiclePlugin;
+use dino::DinoPlugin;
+use weapon::WeaponPlugin;
+use ui::UIPlugin;
+use pause::{PausePlugin, GameState};
+use combo::ComboPlugin;
+use damage_popup::DamagePopupPlugin;
+use effects::EffectsPlugin;
+use game_mode::GameModePlugin;
+use main_menu::MainMenuPlugin;
+use environment::EnvironmentPlugin;
+use minimap::MinimapPlugin;
+use shop::ShopPlugin;
+use settings::SettingsPlugin;
+use kill_feed::KillFeedPlugin;
+use audio::GameAudioPlugin;
+use music::MusicPlugin;
+use footprints::FootprintPlugin;
+use contract::ContractPlugin;
+use save::SavePlugin;
+use stealth::StealthPlugin;
+use skid_marks::SkidMarkPlugin;
+use fuel::FuelPlugin;
+use debug_overlay::DebugOverlayPlugin;
+use coins::CoinPlugin;
+use round_start::RoundStartPlugin;
+use bestiary::BestiaryPlugin;
+use proximity::ProximityPlugin;
+use tow::TowPlugin;
+use kill_cam::KillCamPlugin;
+use bullet_time::BulletTimePlugin;
+use console::ConsolePlugin;
+use run_save::RunSavePlugin;
+use status_effects::StatusEffectsPlugin;
+
+/// All gameplay plugins, resources and world setup. Runs on top of `DefaultPlugins` in the game,
+/// or the windowless platform from `headless::headless_app` in tests
+pub struct GamePlugin;
+
+impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RapierPhysicsPlugin::<NoUserData>::default())
+            .insert_resource(ClearColor(Color::srgb(0.52, 0.77, 0.98)))
+            .insert_resource(GameScore { score: 0 })
+            .init_resource::<terrain::TerrainSeed>()
+            .init_resource::<rng::GameRng>()
+            .add_plugins((
+                CameraPlugin,
+                InputPlugin,
+                VehiclePlugin,
+                DinoPlugin,
+                WeaponPlugin,
+                UIPlugin,
+                PausePlugin,
+                ComboPlugin,
+                DamagePopupPlugin,
+                EffectsPlugin,
+                GameModePlugin,
+                MainMenuPlugin,
+                EnvironmentPlugin,
+                MinimapPlugin,
+                ShopPlugin,
+            ))
+            .add_plugins((
+                SettingsPlugin,
+                KillFeedPlugin,
+                GameAudioPlugin,
+                MusicPlugin,
+                FootprintPlugin,
+                ContractPlugin,
+                SavePlugin,
+                StealthPlugin,
+                SkidMarkPlugin,
+                FuelPlugin,
+                DebugOverlayPlugin,
+                CoinPlugin,
+                RoundStartPlugin,
+                BestiaryPlugin,
+                ProximityPlugin,
+            ))
+            .add_plugins((
+                TowPlugin,
+                KillCamPlugin,
+                BulletTimePlugin,
+                ConsolePlugin,
+                RunSavePlugin,
+                StatusEffectsPlugin,
+            ))
+            .add_systems(Startup, setup)
+            .add_systems(Update, update_score.run_if(resource_changed::<GameScore>))
+            .enable_state_scoped_entities::<GameState>();
+    }
+}
+
+#[derive(Resource)]
+pub struct GameScore {
+    pub score: u32,
+}
+
+/// Blend factor for easing toward a target at `rate` per second; unlike a fixed per-frame
+/// factor it covers the same ground over a second at any frame rate
+pub fn smoothing(rate: f32, dt: f32) -> f32 {
+    1.0 - (-rate * dt).exp()
+}
+
+fn setup(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    terrain: Res<terrain::TerrainSeed>,
+    mut rng: ResMut<rng::GameRng>,
+) {
+    // Light
+    commands.spawn((
+        DirectionalLight {
+            illuminance: 15000.0,
+            shadows_enabled: true,
+            ..default()
+        },
+        Transform::from_rotation(Quat::from_euler(EulerRot::ZYX, 0.0, 1.0, -0.5)),
+    ));
+
+    // Ambient light
+    commands.insert_resource(AmbientLight {
+        color: Color::srgb(0.9, 0.85, 0.8),
+        brightness: 800.0,
+    });
+
+    // Fog (using bevy's built-in fog - add to camera instead)
+    // Note: In Bevy 0.15, fog is configured differently
+
+    // Ground: rolling hills with a flat spawn area, and a collider built from the same heights
+    let (ground_mesh, ground_collider) = terrain.build();
+    commands.spawn((
+        Transform::default(),
+        Mesh3d(meshes.add(ground_mesh)),
+        MeshMaterial3d(materials.add(Color::srgb(0.2, 0.5, 0.15))),
+        RigidBody::Fixed,
+        ground_collider,
+    ));
+
+    // Spawn some trees
+    spawn_trees(&mut commands, &mut meshes, &mut materials, &terrain, &mut *rng);
+
+    // Spawn some rocks
+    spawn_rocks(&mut commands, &mut meshes, &mut materials, &terrain, &mut *rng);
+
+    // HUD text for instructions (top center, under the weapon name)
+    commands.spawn(Node {
+        position_type: PositionType::Absolute,
+        top: Val::Px(55.0),
+        width: Val::Percent(100.0),
+        justify_content: JustifyContent::Center,
+        ..default()
+    }).with_children(|parent| {
+        parent.spawn((
+            Text::new("WASD: Move | Mouse: Aim | Left Click: Shoot"),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(Color::srgba(1.0, 1.0, 1.0, 0.8)),
+        ));
+    });
+}
+
+fn spawn_trees(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    terrain: &terrain::TerrainSeed,
+    rng: &mut impl rand::Rng,
+) {
+    let trunk_material = materials.add(Color::srgb(0.4, 0.25, 0.15));
+    let leaves_material = materials.add(Color::srgb(0.1, 0.4, 0.15));
+    let trunk_mesh = meshes.add(Cylinder::new(0.5, 8.0));
+
+    for _ in 0..100 {
+        let x = (rng.gen_range(-200.0..200.0) as f32).floor();
+        let z = (rng.gen_range(-200.0..200.0) as f32).floor();
+
+        // Skip area near spawn
+        if x.abs() < 10.0 && z.abs() < 10.0 {
+            continue;
+        }
+
+        // Trunk base on the ground, canopy stacked above it
+        let ground = terrain.ground_height(x, z);
+        let tree_transform = Transform::from_xyz(x, ground + 4.0, z);
+
+        // Trunk, solid like the other obstacles; the canopy stays decorative
+        commands.spawn((
+            environment::Obstacle,
+            Mesh3d(trunk_mesh.clone()),
+            MeshMaterial3d(trunk_material.clone()),
+            tree_transform,
+            RigidBody::Fixed,
+            Collider::cylinder(4.0, 0.5),
+        ));
+
+        // Leaves (multiple cones for a pine tree look)
+        for i in 0..4 {
+            let y = ground + 6.5 + i as f32 * 1.5;
+            let scale = 3.0 - i as f32 * 0.5;
+            commands.spawn((
+                Mesh3d(meshes.add(Cone {
+                    radius: scale,
+                    height: 2.5,
+                })),
+                MeshMaterial3d(leaves_material.clone()),
+                Transform::from_xyz(x, y, z),
+            ));
+        }
+    }
+}
+
+fn spawn_rocks(
+    commands: &mut Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    materials: &mut ResMut<Assets<StandardMaterial>>,
+    terrain: &terrain::TerrainSeed,
+    rng: &mut impl rand::Rng,
+) {
+    let rock_material = materials.add(Color::srgb(0.4, 0.4, 0.45));
+
+    for _ in 0..50 {
+        let x = rng.gen_range(-150.0..150.0);
+        let z = rng.gen_range(-150.0..150.0);
+        let scale = rng.gen_range(0.5..2.0);
+
+        // Mesh radius times the transform scale, sunk a little so slopes don't show a gap
+        let radius = scale * 0.5 * scale;
+        let y = terrain.ground_height(x, z) + radius * 0.6;
+
+        commands.spawn((
+            Mesh3d(meshes.add(Sphere { radius: scale * 0.5 })),
+            MeshMaterial3d(rock_material.clone()),
+            Transform::from_xyz(x, y, z).with_scale(Vec3::splat(scale)),
+        ));
+    }
+}
+
+/// ScoreText is a UI `Text` node (see ui::setup_ui), so this writes the on-screen score
+fn update_score(mut score_text: Query<&mut Text, With<ui::ScoreText>>, score: Res<GameScore>) {
+    for mut text in score_text.iter_mut() {
+        text.0 = format!("Score: {}", score.score);
+    }
+}
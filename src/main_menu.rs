@@ -1,6 +1,15 @@
 use bevy::prelude::*;
 use crate::pause::GameState;
 use crate::game_mode::TimeAttackMode;
+use crate::pause::{RestartGameEvent, RestartProgress};
+use crate::save::SaveFile;
+use crate::run_save::{LoadRunEvent, RunSaveFile, SaveRunEvent};
+use crate::rng::GameRng;
+use crate::round_start::RoundStart;
+use crate::weapon::AccuracyStats;
+use crate::bestiary::BestiaryButton;
+use crate::camera::PAUSE_ORBIT_BUTTON;
+use crate::vehicle::{SelectedVehicle, SwapChassisEvent, VehicleType};
 
 #[derive(Component)]
 pub struct MainMenu;
@@ -14,6 +23,31 @@ pub struct StartButton;
 #[derive(Component)]
 pub struct TimeAttackButton;
 
+/// Restart keeping saved progression
+#[derive(Component)]
+pub struct ContinueButton;
+
+/// Restart with progression wiped
+#[derive(Component)]
+pub struct NewGameButton;
+
+/// Restart keeping progression and the seed, for another go at the same layout;
+/// also on the Time Attack results screen
+#[derive(Component)]
+pub struct RetrySameSeedButton;
+
+/// Cycles the chassis the next restart puts the player in
+#[derive(Component)]
+pub struct VehicleSelectButton;
+
+/// Snapshot the hunt in progress and return to it
+#[derive(Component)]
+pub struct SaveGameButton;
+
+/// Swap the hunt in progress for the saved one
+#[derive(Component)]
+pub struct LoadGameButton;
+
 #[derive(Component)]
 pub struct QuitButton;
 
@@ -31,7 +65,7 @@ impl Plugin for MainMenuPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<MenuState>()
             .add_systems(OnEnter(GameState::Paused), setup_main_menu)
-            .add_systems(Update, handle_menu_input.run_if(in_state(GameState::Paused)))
+            .add_systems(Update, (handle_menu_input, hide_menu_while_orbiting).run_if(in_state(GameState::Paused)))
             .add_systems(OnExit(GameState::Paused), cleanup_main_menu);
     }
 }
@@ -39,6 +73,27 @@ impl Plugin for MainMenuPlugin {
 fn setup_main_menu(
     mut commands: Commands,
     mode: Res<TimeAttackMode>,
+    save_file: Res<SaveFile>,
+    run_save: Res<RunSaveFile>,
+    rng: Res<GameRng>,
+    selected_vehicle: Res<SelectedVehicle>,
+) {
+    // A finished Time Attack shows its results screen first
+    if mode.is_finished() {
+        return;
+    }
+
+    spawn_main_menu(&mut commands, &mode, &save_file, &run_save, rng.seed(), selected_vehicle.0);
+}
+
+/// Spawn the main menu overlay
+pub fn spawn_main_menu(
+    commands: &mut Commands,
+    mode: &TimeAttackMode,
+    save_file: &SaveFile,
+    run_save: &RunSaveFile,
+    seed: u64,
+    vehicle: VehicleType,
 ) {
     let is_game_active = mode.kills > 0 || mode.is_active;
 
@@ -93,101 +148,46 @@ fn setup_main_menu(
 
         // Resume Button (only if game was active)
         if is_game_active {
-            parent.spawn((
-                ResumeButton,
-                MenuButton,
-                Node {
-                    width: Val::Px(250.0),
-                    height: Val::Px(50.0),
-                    justify_content: JustifyContent::Center,
-                    align_items: AlignItems::Center,
-                    ..default()
-                },
-                BackgroundColor(Color::srgb(0.2, 0.6, 0.2)),
-            )).with_children(|parent| {
-                parent.spawn((
-                    Text::new("Resume Game"),
-                    TextFont {
-                        font_size: 24.0,
-                        ..default()
-                    },
-                    TextColor(Color::WHITE),
-                ));
-            });
+            spawn_menu_button(parent, ResumeButton, "Resume Game", Color::srgb(0.2, 0.6, 0.2));
         }
 
-        // Start New Game Button
-        parent.spawn((
-            StartButton,
-            MenuButton,
-            Node {
-                width: Val::Px(250.0),
-                height: Val::Px(50.0),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            BackgroundColor(Color::srgb(0.2, 0.4, 0.7)),
-        )).with_children(|parent| {
-            parent.spawn((
-                Text::new("Free Hunt Mode"),
-                TextFont {
-                    font_size: 24.0,
-                    ..default()
-                },
-                TextColor(Color::WHITE),
-            ));
-        });
+        // Mid-run snapshot, off when saving is disabled
+        if save_file.enabled {
+            spawn_menu_button(parent, SaveGameButton, "Save Game", Color::srgb(0.25, 0.45, 0.6));
+        }
+        if run_save.exists {
+            spawn_menu_button(parent, LoadGameButton, "Load Game", Color::srgb(0.3, 0.4, 0.6));
+        }
+
+        // Continue from the saved progression
+        if save_file.exists {
+            spawn_menu_button(parent, ContinueButton, "Continue", Color::srgb(0.2, 0.5, 0.5));
+        }
+
+        // Same herd in the same spots
+        spawn_menu_button(parent, RetrySameSeedButton, "Retry (same seed)", Color::srgb(0.45, 0.35, 0.55));
+
+        // Chassis for the next restart
+        spawn_menu_button(parent, VehicleSelectButton, &vehicle_label(vehicle), Color::srgb(0.35, 0.4, 0.3));
+
+        // Wipe progression and start over
+        spawn_menu_button(parent, NewGameButton, "New Game", Color::srgb(0.5, 0.4, 0.2));
+
+        // Free hunt without restarting
+        spawn_menu_button(parent, StartButton, "Free Hunt Mode", Color::srgb(0.2, 0.4, 0.7));
 
         // Time Attack Button
-        parent.spawn((
-            TimeAttackButton,
-            MenuButton,
-            Node {
-                width: Val::Px(250.0),
-                height: Val::Px(50.0),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            BackgroundColor(Color::srgb(0.7, 0.3, 0.2)),
-        )).with_children(|parent| {
-            parent.spawn((
-                Text::new("Time Attack (5 min)"),
-                TextFont {
-                    font_size: 24.0,
-                    ..default()
-                },
-                TextColor(Color::WHITE),
-            ));
-        });
+        spawn_menu_button(parent, TimeAttackButton, "Time Attack (5 min)", Color::srgb(0.7, 0.3, 0.2));
+
+        // Species codex
+        spawn_menu_button(parent, BestiaryButton, "Bestiary", Color::srgb(0.3, 0.5, 0.25));
 
         // Quit Button
-        parent.spawn((
-            QuitButton,
-            MenuButton,
-            Node {
-                width: Val::Px(250.0),
-                height: Val::Px(50.0),
-                justify_content: JustifyContent::Center,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            BackgroundColor(Color::srgb(0.6, 0.2, 0.2)),
-        )).with_children(|parent| {
-            parent.spawn((
-                Text::new("Quit Game"),
-                TextFont {
-                    font_size: 24.0,
-                    ..default()
-                },
-                TextColor(Color::WHITE),
-            ));
-        });
+        spawn_menu_button(parent, QuitButton, "Quit Game", Color::srgb(0.6, 0.2, 0.2));
 
         // Instructions
         parent.spawn((
-            Text::new("WASD: Move | Mouse: Aim | Click: Shoot | 1/2/3: Weapons | ESC: Pause"),
+            Text::new("WASD: Move | Mouse: Aim | Click: Shoot | 1/2/3: Weapons | C: Dodge | F: Slow-Mo | G: Tow | ESC: Pause"),
             TextFont {
                 font_size: 16.0,
                 ..default()
@@ -198,20 +198,78 @@ fn setup_main_menu(
                 ..default()
             },
         ));
+
+        parent.spawn((
+            Text::new(format!("Seed: {}", seed)),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.5, 0.5, 0.5)),
+        ));
+    });
+}
+
+fn vehicle_label(vehicle: VehicleType) -> String {
+    format!("Vehicle: {}", vehicle.name())
+}
+
+fn spawn_menu_button(parent: &mut ChildBuilder, marker: impl Component, label: &str, color: Color) {
+    parent.spawn((
+        marker,
+        MenuButton,
+        Button,
+        Node {
+            width: Val::Px(250.0),
+            height: Val::Px(50.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(color),
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 24.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
     });
 }
 
 fn handle_menu_input(
     mut next_state: ResMut<NextState<GameState>>,
     mut interaction_q: Query<
-        (&Interaction, &mut BackgroundColor),
+        (Entity, &Interaction, &mut BackgroundColor),
         (With<MenuButton>, Changed<Interaction>)
     >,
     button_types: Query<
-        (Option<&ResumeButton>, Option<&StartButton>, Option<&TimeAttackButton>, Option<&QuitButton>),
+        (
+            Option<&ResumeButton>,
+            Option<&SaveGameButton>,
+            Option<&LoadGameButton>,
+            Option<&ContinueButton>,
+            Option<&NewGameButton>,
+            Option<&RetrySameSeedButton>,
+            Option<&VehicleSelectButton>,
+            Option<&StartButton>,
+            Option<&TimeAttackButton>,
+            Option<&QuitButton>,
+        ),
         With<MenuButton>
     >,
+    children_q: Query<&Children>,
+    mut text_q: Query<&mut Text>,
+    mut selected_vehicle: ResMut<SelectedVehicle>,
     mut time_attack: ResMut<TimeAttackMode>,
+    mut round_start: ResMut<RoundStart>,
+    mut accuracy: ResMut<AccuracyStats>,
+    mut restart_events: EventWriter<RestartGameEvent>,
+    mut swap_chassis_events: EventWriter<SwapChassisEvent>,
+    mut save_run_events: EventWriter<SaveRunEvent>,
+    mut load_run_events: EventWriter<LoadRunEvent>,
     mut app_exit_events: ResMut<Events<bevy::app::AppExit>>,
     keyboard: Res<ButtonInput<KeyCode>>,
 ) {
@@ -221,21 +279,64 @@ fn handle_menu_input(
         return;
     }
 
-    for (interaction, mut bg_color) in interaction_q.iter_mut() {
-        let (is_resume, is_start, is_time_attack, is_quit) = button_types.get_single().ok().unwrap_or_default();
+    for (entity, interaction, mut bg_color) in interaction_q.iter_mut() {
+        let Ok((
+            is_resume, is_save, is_load, is_continue, is_new_game, is_same_seed, is_vehicle, is_start, is_time_attack, is_quit,
+        )) = button_types.get(entity) else {
+            continue;
+        };
 
         match *interaction {
             Interaction::Pressed => {
                 if is_resume.is_some() {
                     // Resume game
                     next_state.set(GameState::Playing);
+                } else if is_save.is_some() {
+                    // Save, then drop straight back into the hunt
+                    save_run_events.send(SaveRunEvent);
+                    next_state.set(GameState::Playing);
+                } else if is_load.is_some() {
+                    // load_run rebuilds the hunt and resumes play
+                    load_run_events.send(LoadRunEvent);
+                } else if is_continue.is_some() {
+                    // Restart the hunt with saved progression
+                    time_attack.stop();
+                    restart_events.send(RestartGameEvent::new(RestartProgress::Continue));
+                    next_state.set(GameState::Playing);
+                } else if is_new_game.is_some() {
+                    // Restart the hunt from scratch
+                    time_attack.stop();
+                    restart_events.send(RestartGameEvent::new(RestartProgress::NewGame));
+                    next_state.set(GameState::Playing);
+                } else if is_same_seed.is_some() {
+                    // Restart on the layout just played
+                    time_attack.stop();
+                    restart_events.send(RestartGameEvent {
+                        progress: RestartProgress::Continue,
+                        same_seed: true,
+                    });
+                    next_state.set(GameState::Playing);
+                } else if is_vehicle.is_some() {
+                    // Stay in the menu; the chassis is swapped in when the hunt starts
+                    selected_vehicle.0 = selected_vehicle.0.next();
+                    for child in children_q.iter_descendants(entity) {
+                        if let Ok(mut text) = text_q.get_mut(child) {
+                            text.0 = vehicle_label(selected_vehicle.0);
+                        }
+                    }
                 } else if is_start.is_some() {
                     // Start free hunt mode
                     time_attack.stop();
+                    swap_chassis_events.send(SwapChassisEvent);
+                    round_start.begin();
+                    accuracy.reset();
                     next_state.set(GameState::Playing);
                 } else if is_time_attack.is_some() {
                     // Start time attack mode
                     time_attack.start();
+                    swap_chassis_events.send(SwapChassisEvent);
+                    round_start.begin();
+                    accuracy.reset();
                     next_state.set(GameState::Playing);
                 } else if is_quit.is_some() {
                     // Quit game
@@ -254,6 +355,17 @@ fn handle_menu_input(
     }
 }
 
+/// Get the menu out of the way while the camera orbits, which also keeps its buttons from taking clicks
+fn hide_menu_while_orbiting(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut menu_q: Query<&mut Visibility, With<MainMenu>>,
+) {
+    let visibility = if mouse.pressed(PAUSE_ORBIT_BUTTON) { Visibility::Hidden } else { Visibility::Inherited };
+    for mut menu_visibility in menu_q.iter_mut() {
+        menu_visibility.set_if_neq(visibility);
+    }
+}
+
 fn cleanup_main_menu(
     mut commands: Commands,
     menu_q: Query<Entity, With<MainMenu>>,
@@ -1,8 +1,13 @@
 use bevy::prelude::*;
+use std::collections::HashMap;
+use bevy::ui::RelativeCursorPosition;
+use bevy::window::CursorGrabMode;
 use crate::pause::GameState;
 use crate::vehicle::PlayerVehicle;
-use crate::dino::Dinosaur;
+use crate::dino::{DinoSpecies, Dinosaur};
 use crate::input::TargetLock;
+use crate::environment::{Obstacle, WaterBody};
+use crate::terrain::TerrainSeed;
 
 #[derive(Component)]
 pub struct MinimapContainer;
@@ -19,17 +24,169 @@ pub struct EnemyDot;
 #[derive(Component)]
 pub struct LockedTargetIndicator;
 
+/// Static map feature (water or obstacle), spawned once and repositioned as the player moves
+#[derive(Component)]
+pub struct MinimapFeature {
+    pub center: Vec2, // World XZ
+    pub half_extents: Vec2,
+}
+
+/// Obstacles are drawn at least this big so small rocks still show up
+const MIN_OBSTACLE_EXTENT: f32 = 1.5;
+
+/// Full-screen map overlay shown while M is held
+#[derive(Component)]
+pub struct TacticalMap;
+
+/// The square map area inside the overlay, parent of all tactical markers
+#[derive(Component)]
+pub struct TacticalMapArea;
+
+#[derive(Component)]
+pub struct TacticalDinoDot;
+
+#[derive(Component)]
+pub struct TacticalPlayerMarker;
+
+const TACTICAL_MAP_SIZE: f32 = 600.0;
+/// World half-extent covered by the tactical map, centered on the origin
+const TACTICAL_WORLD_EXTENT: f32 = 250.0;
+const TACTICAL_SCALE: f32 = TACTICAL_MAP_SIZE / (TACTICAL_WORLD_EXTENT * 2.0);
+
+/// Whether the tactical map is open; the cursor is free and clicks place waypoints instead of firing
+#[derive(Resource, Default)]
+pub struct TacticalMapState {
+    pub open: bool,
+}
+
+/// Player-placed navigation target in world XZ, cleared on arrival
+#[derive(Resource, Default)]
+pub struct Waypoint {
+    pub position: Option<Vec2>,
+}
+
+/// Distance at which the waypoint counts as reached
+const WAYPOINT_ARRIVAL_RADIUS: f32 = 5.0;
+/// Height of the beam marking the waypoint in the world
+const WAYPOINT_BEAM_HEIGHT: f32 = 30.0;
+/// Distance of the guide arrow from the screen center
+const WAYPOINT_ARROW_RADIUS: f32 = 110.0;
+
+/// World beam at the waypoint
+#[derive(Component)]
+pub struct WaypointMarker;
+
+/// Screen-center anchor rotated toward the waypoint; its child is the arrow
+#[derive(Component)]
+pub struct WaypointArrow;
+
+#[derive(Component)]
+pub struct WaypointDistanceText;
+
+/// Waypoint dot on the minimap, pinned to the edge when out of range
+#[derive(Component)]
+pub struct MinimapWaypointDot;
+
+#[derive(Component)]
+pub struct TacticalWaypointDot;
+
+/// Minimap pixels per world unit, from closest to widest
+const ZOOM_LEVELS: [f32; 3] = [1.0, 0.5, 0.25];
+
+/// Selected minimap zoom, kept for the whole session
+#[derive(Resource)]
+pub struct MinimapZoom {
+    pub level: usize,
+}
+
+impl Default for MinimapZoom {
+    fn default() -> Self {
+        Self { level: 1 }
+    }
+}
+
+impl MinimapZoom {
+    pub fn scale(&self) -> f32 {
+        ZOOM_LEVELS[self.level]
+    }
+
+    pub fn zoom_in(&mut self) {
+        self.level = self.level.saturating_sub(1);
+    }
+
+    pub fn zoom_out(&mut self) {
+        self.level = (self.level + 1).min(ZOOM_LEVELS.len() - 1);
+    }
+}
+
+/// Radar sweep toggle from the settings menu; off reveals every dino on the minimap all the time
+#[derive(Resource)]
+pub struct MinimapSettings {
+    pub radar_sweep: bool,
+}
+
+impl Default for MinimapSettings {
+    fn default() -> Self {
+        Self { radar_sweep: true }
+    }
+}
+
+/// Seconds for the scan radius to grow out and shrink back
+const SWEEP_PERIOD: f32 = 4.0;
+/// World-space scan radius at the low and high point of the sweep
+const SWEEP_MIN_RADIUS: f32 = 25.0;
+const SWEEP_MAX_RADIUS: f32 = 160.0;
+/// Seconds a dino's dot takes to fade once the sweep has passed it
+const SWEEP_FADE_TIME: f32 = 2.0;
+
+/// Current scan and when each dino was last caught in it
+#[derive(Resource)]
+pub struct RadarSweep {
+    pub timer: Timer,
+    pub radius: f32,
+    pub last_seen: HashMap<Entity, f32>,
+}
+
+impl Default for RadarSweep {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(SWEEP_PERIOD, TimerMode::Repeating),
+            radius: SWEEP_MIN_RADIUS,
+            last_seen: HashMap::new(),
+        }
+    }
+}
+
+/// Ring on the minimap tracing the scan radius
+#[derive(Component)]
+pub struct SweepRing;
+
 pub struct MinimapPlugin;
 
 impl Plugin for MinimapPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, setup_minimap)
-            .add_systems(Update, update_minimap.run_if(in_state(GameState::Playing)));
+        app.init_resource::<MinimapZoom>()
+            .init_resource::<MinimapSettings>()
+            .init_resource::<RadarSweep>()
+            .init_resource::<TacticalMapState>()
+            .init_resource::<Waypoint>()
+            .add_systems(Startup, (setup_minimap, setup_waypoint_marker))
+            .add_systems(Update, (
+                handle_minimap_zoom,
+                spawn_minimap_features,
+                update_minimap_features,
+                update_minimap,
+                toggle_tactical_map,
+                update_tactical_map,
+                handle_waypoint_click,
+                update_waypoint,
+            ).chain().run_if(in_state(GameState::Playing)));
     }
 }
 
 const MINIMAP_SIZE: f32 = 150.0;
-const MINIMAP_SCALE: f32 = 0.5; // 1 unit on minimap = 2 units in world
+/// Keeps edge-clamped dots fully inside the frame
+const MINIMAP_EDGE_MARGIN: f32 = 3.0;
 
 fn setup_minimap(mut commands: Commands) {
     // Minimap container - positioned in bottom right corner
@@ -41,10 +198,12 @@ fn setup_minimap(mut commands: Commands) {
             position_type: PositionType::Absolute,
             right: Val::Px(20.0),
             bottom: Val::Px(20.0),
+            overflow: Overflow::clip(),
             ..default()
         },
+        RelativeCursorPosition::default(),
     )).with_children(|parent| {
-        // Background
+        // Background, below the map features
         parent.spawn((
             MinimapBackground,
             Node {
@@ -52,6 +211,7 @@ fn setup_minimap(mut commands: Commands) {
                 height: Val::Percent(100.0),
                 ..default()
             },
+            ZIndex(-2),
             BackgroundColor(Color::srgba(0.1, 0.15, 0.2, 0.8)),
             BorderColor(Color::srgba(0.5, 0.5, 0.5, 0.5)),
         ));
@@ -70,14 +230,486 @@ fn setup_minimap(mut commands: Commands) {
             BackgroundColor(Color::srgb(0.2, 0.8, 0.2)),
             BorderRadius::MAX,
         ));
+
+        parent.spawn((
+            SweepRing,
+            Node {
+                position_type: PositionType::Absolute,
+                border: UiRect::all(Val::Px(1.0)),
+                display: Display::None,
+                ..default()
+            },
+            BorderColor(Color::srgba(0.3, 0.9, 0.5, 0.35)),
+            BorderRadius::MAX,
+        ));
+
+        parent.spawn((
+            MinimapWaypointDot,
+            Node {
+                width: Val::Px(8.0),
+                height: Val::Px(8.0),
+                position_type: PositionType::Absolute,
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(1.0, 0.85, 0.1)),
+        ));
+    });
+
+    // Guide arrow orbiting the screen center, pointing at the waypoint
+    commands.spawn((
+        WaypointArrow,
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Percent(50.0),
+            top: Val::Percent(50.0),
+            display: Display::None,
+            ..default()
+        },
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(-3.0),
+                top: Val::Px(-WAYPOINT_ARROW_RADIUS - 18.0),
+                width: Val::Px(6.0),
+                height: Val::Px(18.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(1.0, 0.85, 0.1, 0.9)),
+            BorderRadius::top(Val::Px(3.0)),
+        ));
+    });
+
+    commands.spawn((
+        WaypointDistanceText,
+        Text::new(""),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::srgb(1.0, 0.85, 0.1)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(85.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        TextLayout::new_with_justify(JustifyText::Center),
+    ));
+
+    // Tactical map overlay (screen center), hidden until M is held
+    commands.spawn((
+        TacticalMap,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            display: Display::None,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.4)),
+        GlobalZIndex(5),
+    )).with_children(|parent| {
+        parent.spawn((
+            TacticalMapArea,
+            Node {
+                width: Val::Px(TACTICAL_MAP_SIZE),
+                height: Val::Px(TACTICAL_MAP_SIZE),
+                border: UiRect::all(Val::Px(2.0)),
+                overflow: Overflow::clip(),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.15, 0.2, 0.9)),
+            BorderColor(Color::srgba(0.6, 0.6, 0.6, 0.8)),
+            RelativeCursorPosition::default(),
+        )).with_children(|area| {
+            area.spawn((
+                TacticalWaypointDot,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(10.0),
+                    height: Val::Px(10.0),
+                    display: Display::None,
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(1.0, 0.85, 0.1)),
+            ));
+
+            // Player marker: a dot with a heading tick, rotated to the vehicle's yaw
+            area.spawn((
+                TacticalPlayerMarker,
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Px(12.0),
+                    height: Val::Px(12.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.2, 0.8, 0.2)),
+                BorderRadius::MAX,
+            )).with_children(|marker| {
+                marker.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(5.0),
+                        top: Val::Px(-10.0),
+                        width: Val::Px(2.0),
+                        height: Val::Px(12.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::WHITE),
+                ));
+            });
+        });
     });
 }
 
+fn setup_waypoint_marker(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.spawn((
+        WaypointMarker,
+        Mesh3d(meshes.add(Cylinder::new(0.6, WAYPOINT_BEAM_HEIGHT))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgba(1.0, 0.85, 0.1, 0.45),
+            emissive: LinearRgba::rgb(2.0, 1.6, 0.2),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        })),
+        Transform::default(),
+        Visibility::Hidden,
+    ));
+}
+
+/// Map a world XZ position onto the tactical map, in pixels from its top-left corner
+fn tactical_map_position(world: Vec2) -> Vec2 {
+    (world + Vec2::splat(TACTICAL_WORLD_EXTENT)) * TACTICAL_SCALE
+}
+
+/// Inverse of `tactical_map_position`
+fn tactical_map_to_world(map: Vec2) -> Vec2 {
+    map / TACTICAL_SCALE - Vec2::splat(TACTICAL_WORLD_EXTENT)
+}
+
+/// Inverse of the player-relative projection in `update_minimap`
+fn minimap_to_world(map: Vec2, vehicle_pos: Vec2, scale: f32) -> Vec2 {
+    vehicle_pos + (map - Vec2::splat(MINIMAP_SIZE / 2.0)) / scale
+}
+
+/// Add a node for each water body and obstacle the first time it exists
+fn spawn_minimap_features(
+    mut commands: Commands,
+    minimap_q: Query<Entity, With<MinimapContainer>>,
+    tactical_q: Query<Entity, With<TacticalMapArea>>,
+    water_q: Query<(&WaterBody, &Transform), Added<WaterBody>>,
+    obstacle_q: Query<&Transform, Added<Obstacle>>,
+) {
+    let Ok(minimap_entity) = minimap_q.get_single() else {
+        return;
+    };
+    let Ok(tactical_entity) = tactical_q.get_single() else {
+        return;
+    };
+
+    let water = water_q.iter().map(|(water, transform)| {
+        let radius = if water.round { BorderRadius::MAX } else { BorderRadius::ZERO };
+        (transform.translation.xz(), water.half_extents, Color::srgba(0.2, 0.45, 0.85, 0.7), radius)
+    });
+    let obstacles = obstacle_q.iter().map(|transform| {
+        // Axis-aligned footprint from the scale; rotated logs are approximated
+        let extent = Vec2::new(transform.scale.x, transform.scale.z).max(Vec2::splat(MIN_OBSTACLE_EXTENT)) * 0.5;
+        (transform.translation.xz(), extent, Color::srgba(0.55, 0.55, 0.6, 0.8), BorderRadius::ZERO)
+    });
+
+    let features: Vec<_> = water.chain(obstacles).collect();
+    if features.is_empty() {
+        return;
+    }
+
+    commands.entity(minimap_entity).with_children(|parent| {
+        for &(center, half_extents, color, radius) in &features {
+            parent.spawn((
+                MinimapFeature { center, half_extents },
+                Node {
+                    position_type: PositionType::Absolute,
+                    display: Display::None,
+                    ..default()
+                },
+                BackgroundColor(color),
+                radius,
+                ZIndex(-1),
+            ));
+        }
+    });
+
+    // The tactical map is world-fixed, so its copies never move
+    commands.entity(tactical_entity).with_children(|parent| {
+        for (center, half_extents, color, radius) in features {
+            let top_left = tactical_map_position(center - half_extents);
+            let size = half_extents * 2.0 * TACTICAL_SCALE;
+            parent.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(top_left.x),
+                    top: Val::Px(top_left.y),
+                    width: Val::Px(size.x),
+                    height: Val::Px(size.y),
+                    ..default()
+                },
+                BackgroundColor(color),
+                radius,
+                ZIndex(-1),
+            ));
+        }
+    });
+}
+
+/// Show the tactical map while M is held, freeing the cursor so either map can be clicked
+fn toggle_tactical_map(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut map_state: ResMut<TacticalMapState>,
+    mut map_q: Query<&mut Node, With<TacticalMap>>,
+    mut window_q: Query<&mut Window>,
+) {
+    let open = keyboard.pressed(KeyCode::KeyM);
+    let display = if open { Display::Flex } else { Display::None };
+    for mut node in map_q.iter_mut() {
+        if node.display != display {
+            node.display = display;
+        }
+    }
+
+    if map_state.open != open {
+        map_state.open = open;
+        if let Ok(mut window) = window_q.get_single_mut() {
+            window.cursor_options.grab_mode = if open { CursorGrabMode::None } else { CursorGrabMode::Locked };
+            window.cursor_options.visible = open;
+        }
+    }
+}
+
+/// Left click on the minimap or tactical map drops a waypoint there; right click clears it
+fn handle_waypoint_click(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    map_state: Res<TacticalMapState>,
+    zoom: Res<MinimapZoom>,
+    mut waypoint: ResMut<Waypoint>,
+    minimap_q: Query<&RelativeCursorPosition, With<MinimapContainer>>,
+    tactical_q: Query<&RelativeCursorPosition, With<TacticalMapArea>>,
+    vehicle_q: Query<&Transform, With<PlayerVehicle>>,
+) {
+    if !map_state.open {
+        return;
+    }
+
+    if mouse_button.just_pressed(MouseButton::Right) {
+        waypoint.position = None;
+        return;
+    }
+
+    if !mouse_button.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    if let Some(cursor) = tactical_q.get_single().ok().filter(|c| c.mouse_over()).and_then(|c| c.normalized) {
+        waypoint.position = Some(tactical_map_to_world(cursor * TACTICAL_MAP_SIZE));
+    } else if let Some(cursor) = minimap_q.get_single().ok().filter(|c| c.mouse_over()).and_then(|c| c.normalized) {
+        let Ok(vehicle_transform) = vehicle_q.get_single() else {
+            return;
+        };
+        let vehicle_pos = vehicle_transform.translation.xz();
+        waypoint.position = Some(minimap_to_world(cursor * MINIMAP_SIZE, vehicle_pos, zoom.scale()));
+    }
+}
+
+/// Place the beam, map dots and guide arrow, and clear the waypoint once reached
+fn update_waypoint(
+    mut waypoint: ResMut<Waypoint>,
+    zoom: Res<MinimapZoom>,
+    terrain: Res<TerrainSeed>,
+    vehicle_q: Query<&Transform, (With<PlayerVehicle>, Without<WaypointMarker>)>,
+    mut marker_q: Query<(&mut Transform, &mut Visibility), With<WaypointMarker>>,
+    mut arrow_q: Query<(&mut Node, &mut Transform), (With<WaypointArrow>, Without<PlayerVehicle>, Without<WaypointMarker>)>,
+    mut minimap_dot_q: Query<&mut Node, (With<MinimapWaypointDot>, Without<WaypointArrow>, Without<TacticalWaypointDot>)>,
+    mut tactical_dot_q: Query<&mut Node, (With<TacticalWaypointDot>, Without<WaypointArrow>, Without<MinimapWaypointDot>)>,
+    mut text_q: Query<&mut Text, With<WaypointDistanceText>>,
+) {
+    let Ok(vehicle_transform) = vehicle_q.get_single() else {
+        return;
+    };
+    let vehicle_pos = vehicle_transform.translation.xz();
+
+    if waypoint.position.is_some_and(|target| target.distance(vehicle_pos) < WAYPOINT_ARRIVAL_RADIUS) {
+        waypoint.position = None;
+    }
+
+    let Some(target) = waypoint.position else {
+        for (_, mut visibility) in marker_q.iter_mut() {
+            *visibility = Visibility::Hidden;
+        }
+        for (mut node, _) in arrow_q.iter_mut() {
+            node.display = Display::None;
+        }
+        for mut node in minimap_dot_q.iter_mut().chain(tactical_dot_q.iter_mut()) {
+            node.display = Display::None;
+        }
+        for mut text in text_q.iter_mut() {
+            text.0.clear();
+        }
+        return;
+    };
+
+    for (mut transform, mut visibility) in marker_q.iter_mut() {
+        let ground = terrain.ground_height(target.x, target.y);
+        transform.translation = Vec3::new(target.x, ground + WAYPOINT_BEAM_HEIGHT / 2.0, target.y);
+        *visibility = Visibility::Visible;
+    }
+
+    // Screen up is world -Z from the follow camera; UI rotation is clockwise
+    let to_target = target - vehicle_pos;
+    for (mut node, mut transform) in arrow_q.iter_mut() {
+        node.display = Display::Flex;
+        transform.rotation = Quat::from_rotation_z(to_target.x.atan2(-to_target.y));
+    }
+
+    let half = MINIMAP_SIZE / 2.0;
+    let limit = half - MINIMAP_EDGE_MARGIN;
+    let rel = (to_target * zoom.scale()).clamp(Vec2::splat(-limit), Vec2::splat(limit));
+    for mut node in minimap_dot_q.iter_mut() {
+        node.display = Display::Flex;
+        node.left = Val::Px(half + rel.x - 4.0);
+        node.top = Val::Px(half + rel.y - 4.0);
+    }
+
+    let map_pos = tactical_map_position(target);
+    for mut node in tactical_dot_q.iter_mut() {
+        node.display = Display::Flex;
+        node.left = Val::Px(map_pos.x - 5.0);
+        node.top = Val::Px(map_pos.y - 5.0);
+    }
+
+    for mut text in text_q.iter_mut() {
+        text.0 = format!("Waypoint {:.0} m", to_target.length());
+    }
+}
+
+fn update_tactical_map(
+    mut commands: Commands,
+    map_q: Query<&Node, (With<TacticalMap>, Without<TacticalPlayerMarker>)>,
+    area_q: Query<Entity, With<TacticalMapArea>>,
+    vehicle_q: Query<&Transform, With<PlayerVehicle>>,
+    dino_q: Query<(&Transform, &DinoSpecies), (With<Dinosaur>, Without<PlayerVehicle>)>,
+    dot_q: Query<Entity, With<TacticalDinoDot>>,
+    mut marker_q: Query<(&mut Node, &mut Transform), (With<TacticalPlayerMarker>, Without<PlayerVehicle>, Without<Dinosaur>)>,
+) {
+    for entity in dot_q.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    // Nothing to draw while hidden
+    if map_q.get_single().map_or(true, |node| node.display == Display::None) {
+        return;
+    }
+
+    let Ok(area_entity) = area_q.get_single() else {
+        return;
+    };
+
+    if let Ok(vehicle_transform) = vehicle_q.get_single() {
+        let pos = tactical_map_position(vehicle_transform.translation.xz());
+        let (yaw, _, _) = vehicle_transform.rotation.to_euler(EulerRot::YXZ);
+        for (mut node, mut transform) in marker_q.iter_mut() {
+            node.left = Val::Px(pos.x - 6.0);
+            node.top = Val::Px(pos.y - 6.0);
+            // Map up is world -Z; UI rotation is clockwise, world yaw is counter-clockwise
+            transform.rotation = Quat::from_rotation_z(-yaw);
+        }
+    }
+
+    commands.entity(area_entity).with_children(|parent| {
+        for (transform, species) in dino_q.iter() {
+            let pos = tactical_map_position(transform.translation.xz());
+            let size = if *species == DinoSpecies::TRex { 12.0 } else { 7.0 };
+            parent.spawn((
+                TacticalDinoDot,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(pos.x - size / 2.0),
+                    top: Val::Px(pos.y - size / 2.0),
+                    width: Val::Px(size),
+                    height: Val::Px(size),
+                    ..default()
+                },
+                BackgroundColor(species.map_color()),
+                BorderRadius::MAX,
+            ));
+        }
+    });
+}
+
+/// Reposition features around the player and hide the ones fully off the map
+fn update_minimap_features(
+    zoom: Res<MinimapZoom>,
+    vehicle_q: Query<&Transform, With<PlayerVehicle>>,
+    mut feature_q: Query<(&MinimapFeature, &mut Node)>,
+) {
+    let Ok(vehicle_transform) = vehicle_q.get_single() else {
+        return;
+    };
+
+    let scale = zoom.scale();
+    let half = MINIMAP_SIZE / 2.0;
+    let vehicle_pos = vehicle_transform.translation.xz();
+
+    for (feature, mut node) in feature_q.iter_mut() {
+        let rel = (feature.center - vehicle_pos) * scale;
+        let size = feature.half_extents * scale;
+
+        let visible = (rel.x.abs() - size.x) < half && (rel.y.abs() - size.y) < half;
+        if !visible {
+            if node.display != Display::None {
+                node.display = Display::None;
+            }
+            continue;
+        }
+
+        node.display = Display::Flex;
+        node.left = Val::Px(half + rel.x - size.x);
+        node.top = Val::Px(half + rel.y - size.y);
+        node.width = Val::Px(size.x * 2.0);
+        node.height = Val::Px(size.y * 2.0);
+    }
+}
+
+/// +/- zoom the minimap in and out
+fn handle_minimap_zoom(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut zoom: ResMut<MinimapZoom>,
+) {
+    if keyboard.just_pressed(KeyCode::Equal) || keyboard.just_pressed(KeyCode::NumpadAdd) {
+        zoom.zoom_in();
+    }
+    if keyboard.just_pressed(KeyCode::Minus) || keyboard.just_pressed(KeyCode::NumpadSubtract) {
+        zoom.zoom_out();
+    }
+}
+
 fn update_minimap(
     mut commands: Commands,
+    time: Res<Time>,
+    zoom: Res<MinimapZoom>,
+    settings: Res<MinimapSettings>,
+    mut sweep: ResMut<RadarSweep>,
     minimap_q: Query<Entity, With<MinimapContainer>>,
+    mut ring_q: Query<&mut Node, With<SweepRing>>,
     vehicle_q: Query<&Transform, With<PlayerVehicle>>,
-    dino_q: Query<&Transform, (With<Dinosaur>, Without<PlayerVehicle>)>,
+    dino_q: Query<(Entity, &Transform), (With<Dinosaur>, Without<PlayerVehicle>)>,
     target_lock: Res<TargetLock>,
     existing_enemy_dots: Query<Entity, With<EnemyDot>>,
     existing_locked_indicator: Query<Entity, With<LockedTargetIndicator>>,
@@ -91,6 +723,34 @@ fn update_minimap(
     };
 
     let vehicle_pos = vehicle_transform.translation;
+    let scale = zoom.scale();
+    let half = MINIMAP_SIZE / 2.0;
+
+    // The scan radius eases out to its widest and back once per period
+    sweep.timer.tick(time.delta());
+    let phase = sweep.timer.fraction() * std::f32::consts::TAU;
+    sweep.radius = SWEEP_MIN_RADIUS + (SWEEP_MAX_RADIUS - SWEEP_MIN_RADIUS) * (1.0 - phase.cos()) * 0.5;
+    let now = time.elapsed_secs();
+    let radius = sweep.radius;
+    for (entity, transform) in dino_q.iter() {
+        if transform.translation.xz().distance(vehicle_pos.xz()) < radius {
+            sweep.last_seen.insert(entity, now);
+        }
+    }
+    sweep.last_seen.retain(|entity, seen| dino_q.contains(*entity) && now - *seen < SWEEP_FADE_TIME);
+
+    for mut node in ring_q.iter_mut() {
+        if settings.radar_sweep {
+            let ring = radius * scale;
+            node.display = Display::Flex;
+            node.left = Val::Px(half - ring);
+            node.top = Val::Px(half - ring);
+            node.width = Val::Px(ring * 2.0);
+            node.height = Val::Px(ring * 2.0);
+        } else {
+            node.display = Display::None;
+        }
+    }
 
     // Remove old enemy dots
     for entity in existing_enemy_dots.iter() {
@@ -103,48 +763,59 @@ fn update_minimap(
     }
 
     // Spawn new enemy dots
-    for dino_transform in dino_q.iter() {
+    for (entity, dino_transform) in dino_q.iter() {
         let dino_pos = dino_transform.translation;
 
+        // Dots fade out after the sweep passes, and dinos it hasn't reached stay hidden
+        let visibility = if settings.radar_sweep {
+            match sweep.last_seen.get(&entity) {
+                Some(seen) => 1.0 - (now - seen) / SWEEP_FADE_TIME,
+                None => continue,
+            }
+        } else {
+            1.0
+        };
+
         // Calculate relative position
-        let rel_x = (dino_pos.x - vehicle_pos.x) * MINIMAP_SCALE;
-        let rel_z = (dino_pos.z - vehicle_pos.z) * MINIMAP_SCALE;
-
-        // Only show if within minimap bounds
-        if rel_x.abs() < MINIMAP_SIZE / 2.0 && rel_z.abs() < MINIMAP_SIZE / 2.0 {
-            // Convert to screen coordinates (center is MINIMAP_SIZE/2)
-            let screen_x = MINIMAP_SIZE / 2.0 + rel_x;
-            let screen_y = MINIMAP_SIZE / 2.0 + rel_z;
-
-            commands.entity(minimap_entity).with_children(|parent| {
-                parent.spawn((
-                    EnemyDot,
-                    Node {
-                        width: Val::Px(6.0),
-                        height: Val::Px(6.0),
-                        position_type: PositionType::Absolute,
-                        left: Val::Px(screen_x - 3.0),
-                        top: Val::Px(screen_y - 3.0),
-                        ..default()
-                    },
-                    BackgroundColor(Color::srgb(0.8, 0.2, 0.2)),
-                    BorderRadius::MAX,
-                ));
-            });
-        }
+        let rel_x = (dino_pos.x - vehicle_pos.x) * scale;
+        let rel_z = (dino_pos.z - vehicle_pos.z) * scale;
+
+        // Out-of-range dinos are pinned to the edge as smaller, dimmer dots
+        let in_range = rel_x.abs() < half && rel_z.abs() < half;
+        let limit = half - MINIMAP_EDGE_MARGIN;
+        let screen_x = half + rel_x.clamp(-limit, limit);
+        let screen_y = half + rel_z.clamp(-limit, limit);
+        let (size, alpha) = if in_range { (6.0, 1.0) } else { (4.0, 0.5) };
+        let color = Color::srgba(0.8, 0.2, 0.2, alpha * visibility);
+
+        commands.entity(minimap_entity).with_children(|parent| {
+            parent.spawn((
+                EnemyDot,
+                Node {
+                    width: Val::Px(size),
+                    height: Val::Px(size),
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(screen_x - size / 2.0),
+                    top: Val::Px(screen_y - size / 2.0),
+                    ..default()
+                },
+                BackgroundColor(color),
+                BorderRadius::MAX,
+            ));
+        });
     }
 
     // Show locked target indicator
     if let Some(locked_entity) = target_lock.locked_entity {
-        if let Ok(dino_transform) = dino_q.get(locked_entity) {
+        if let Ok((_, dino_transform)) = dino_q.get(locked_entity) {
             let dino_pos = dino_transform.translation;
 
-            let rel_x = (dino_pos.x - vehicle_pos.x) * MINIMAP_SCALE;
-            let rel_z = (dino_pos.z - vehicle_pos.z) * MINIMAP_SCALE;
+            let rel_x = (dino_pos.x - vehicle_pos.x) * scale;
+            let rel_z = (dino_pos.z - vehicle_pos.z) * scale;
 
-            if rel_x.abs() < MINIMAP_SIZE / 2.0 && rel_z.abs() < MINIMAP_SIZE / 2.0 {
-                let screen_x = MINIMAP_SIZE / 2.0 + rel_x;
-                let screen_y = MINIMAP_SIZE / 2.0 + rel_z;
+            if rel_x.abs() < half && rel_z.abs() < half {
+                let screen_x = half + rel_x;
+                let screen_y = half + rel_z;
 
                 commands.entity(minimap_entity).with_children(|parent| {
                     // Yellow circle around locked target
@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use bevy::audio::{AudioSinkPlayback, Volume};
+use crate::pause::GameState;
+use crate::audio::{load_if_present, AudioSettings};
+use crate::dino::{DinoHealth, DinoSpecies};
+
+/// Seconds for one track to fade fully out while the next fades in
+const CROSSFADE_TIME: f32 = 2.0;
+/// Music sits under the sound effects
+const MUSIC_VOLUME: f32 = 0.4;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MusicTrack {
+    Menu,
+    Gameplay,
+    Boss,
+}
+
+impl MusicTrack {
+    fn path(&self) -> &'static str {
+        match self {
+            MusicTrack::Menu => "music/menu.ogg",
+            MusicTrack::Gameplay => "music/gameplay.ogg",
+            MusicTrack::Boss => "music/boss.ogg",
+        }
+    }
+}
+
+/// One looping music track; all tracks keep playing and are crossfaded by volume
+#[derive(Component)]
+pub struct MusicChannel {
+    pub track: MusicTrack,
+    pub fade: f32, // 0.0 = silent, 1.0 = full volume
+}
+
+/// The track that should currently be audible
+#[derive(Resource)]
+pub struct CurrentMusic {
+    pub track: MusicTrack,
+}
+
+impl Default for CurrentMusic {
+    fn default() -> Self {
+        Self { track: MusicTrack::Gameplay }
+    }
+}
+
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CurrentMusic>()
+            .add_systems(Startup, spawn_music_channels)
+            .add_systems(Update, (
+                select_music_track,
+                crossfade_music,
+            ).chain());
+    }
+}
+
+/// Tracks without a file under assets/ get no channel, and that part of the game stays quiet
+fn spawn_music_channels(mut commands: Commands, asset_server: Res<AssetServer>) {
+    for track in [MusicTrack::Menu, MusicTrack::Gameplay, MusicTrack::Boss] {
+        let Some(music) = load_if_present(&asset_server, track.path()) else {
+            continue;
+        };
+        commands.spawn((
+            MusicChannel { track, fade: 0.0 },
+            AudioPlayer::new(music),
+            PlaybackSettings::LOOP.with_volume(Volume::new(0.0)),
+        ));
+    }
+}
+
+fn select_music_track(
+    state: Res<State<GameState>>,
+    dino_q: Query<(&DinoSpecies, &DinoHealth)>,
+    mut current: ResMut<CurrentMusic>,
+) {
+    let boss_alive = dino_q.iter()
+        .any(|(species, health)| *species == DinoSpecies::TRex && health.current > 0.0);
+
+    let track = match state.get() {
+        GameState::Paused => MusicTrack::Menu,
+        GameState::Playing | GameState::FreeCam if boss_alive => MusicTrack::Boss,
+        GameState::Playing | GameState::FreeCam => MusicTrack::Gameplay,
+    };
+
+    if current.track != track {
+        current.track = track;
+    }
+}
+
+fn crossfade_music(
+    time: Res<Time<Real>>,
+    current: Res<CurrentMusic>,
+    settings: Res<AudioSettings>,
+    mut channel_q: Query<(&mut MusicChannel, Option<&AudioSink>)>,
+) {
+    // Real time so fading continues while the game is paused
+    let step = time.delta_secs() / CROSSFADE_TIME;
+
+    for (mut channel, sink) in channel_q.iter_mut() {
+        let target = if channel.track == current.track { 1.0 } else { 0.0 };
+        channel.fade = if channel.fade < target {
+            (channel.fade + step).min(target)
+        } else {
+            (channel.fade - step).max(target)
+        };
+
+        // Sink is only available once the track has finished loading
+        if let Some(sink) = sink {
+            sink.set_volume(channel.fade * settings.volume() * MUSIC_VOLUME);
+        }
+    }
+}
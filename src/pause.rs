@@ -2,12 +2,16 @@ use bevy::prelude::*;
 use bevy::window::CursorGrabMode;
 use crate::input::PlayerInput;
 use crate::dino::RespawnDinosEvent;
+use crate::rng::GameRng;
+use crate::vehicle::{PlayerVehicle, VehicleHealth, VehicleVelocity, VEHICLE_SPAWN_POSITION};
 
 #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum GameState {
     #[default]
     Playing,
     Paused,
+    /// Detached photo camera; the simulation is frozen like in the menu
+    FreeCam,
 }
 
 pub struct PausePlugin;
@@ -17,8 +21,10 @@ impl Plugin for PausePlugin {
         app.init_state::<GameState>()
             .add_event::<RestartGameEvent>()
             .add_systems(OnEnter(GameState::Playing), setup_cursor)
-            .add_systems(OnEnter(GameState::Paused), show_cursor)
-            .add_systems(OnExit(GameState::Paused), hide_cursor)
+            .add_systems(OnEnter(GameState::Paused), (show_cursor, pause_game_time))
+            .add_systems(OnExit(GameState::Paused), (hide_cursor, resume_game_time))
+            .add_systems(OnEnter(GameState::FreeCam), pause_game_time)
+            .add_systems(OnExit(GameState::FreeCam), resume_game_time)
             .add_systems(Update, (
                 handle_pause_input.run_if(in_state(GameState::Playing)),
                 handle_restart_game,
@@ -26,8 +32,32 @@ impl Plugin for PausePlugin {
     }
 }
 
-#[derive(Event)]
-pub struct RestartGameEvent;
+/// Where a restart takes progression from
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RestartProgress {
+    /// Keep saved progression
+    Continue,
+    /// Wipe it
+    NewGame,
+}
+
+/// Restart the hunt
+#[derive(Event, Clone, Copy, PartialEq, Eq)]
+pub struct RestartGameEvent {
+    pub progress: RestartProgress,
+    /// Replay the current seed, so the same herd spawns in the same spots
+    pub same_seed: bool,
+}
+
+impl RestartGameEvent {
+    /// Restart on a freshly rolled seed
+    pub fn new(progress: RestartProgress) -> Self {
+        Self {
+            progress,
+            same_seed: false,
+        }
+    }
+}
 
 #[derive(Component)]
 pub struct PauseMenu;
@@ -48,6 +78,16 @@ fn setup_cursor(mut window_q: Query<&mut Window>) {
     }
 }
 
+/// Freeze virtual time so timers and elapsed-time clocks don't jump on resume.
+/// Menus and music run on real time and keep working.
+fn pause_game_time(mut time: ResMut<Time<Virtual>>) {
+    time.pause();
+}
+
+fn resume_game_time(mut time: ResMut<Time<Virtual>>) {
+    time.unpause();
+}
+
 fn show_cursor(mut window_q: Query<&mut Window>) {
     if let Ok(mut window) = window_q.get_single_mut() {
         window.cursor_options.grab_mode = CursorGrabMode::None;
@@ -218,7 +258,7 @@ fn handle_pause_menu_input(
 ) {
     // Handle keyboard shortcuts
     if keyboard.just_pressed(KeyCode::KeyR) {
-        restart_events.send(RestartGameEvent);
+        restart_events.send(RestartGameEvent::new(RestartProgress::Continue));
         next_state.set(GameState::Playing);
         return;
     }
@@ -237,7 +277,7 @@ fn handle_pause_menu_input(
         if resume_opt.is_some() {
             next_state.set(GameState::Playing);
         } else if restart_opt.is_some() {
-            restart_events.send(RestartGameEvent);
+            restart_events.send(RestartGameEvent::new(RestartProgress::Continue));
             next_state.set(GameState::Playing);
         } else if quit_opt.is_some() {
             app_exit.send(bevy::app::AppExit::Success);
@@ -245,24 +285,59 @@ fn handle_pause_menu_input(
     }
 }
 
-fn handle_restart_game(
+pub fn handle_restart_game(
     mut events: EventReader<RestartGameEvent>,
     mut commands: Commands,
     dino_q: Query<Entity, With<crate::dino::Dinosaur>>,
     bullet_q: Query<Entity, With<crate::weapon::Bullet>>,
+    coin_q: Query<Entity, With<crate::coins::Coin>>,
+    mut vehicle_q: Query<
+        (&mut Transform, &mut VehicleHealth, &mut VehicleVelocity, &mut crate::vehicle::Knockback),
+        With<PlayerVehicle>
+    >,
     mut score: ResMut<crate::GameScore>,
+    mut combo: ResMut<crate::combo::ComboSystem>,
     mut target_lock: ResMut<crate::input::TargetLock>,
+    mut fuel: ResMut<crate::fuel::Fuel>,
+    mut round_start: ResMut<crate::round_start::RoundStart>,
+    mut accuracy: ResMut<crate::weapon::AccuracyStats>,
     mut respawn_events: EventWriter<RespawnDinosEvent>,
+    mut rng: ResMut<GameRng>,
 ) {
-    for _event in events.read() {
+    for event in events.read() {
+        // Count the fresh round in
+        round_start.begin();
+
+        let seed = if event.same_seed { rng.seed() } else { rand::random() };
+        rng.reseed(seed);
+
         // Reset score
         score.score = 0;
 
+        // Reset combo streak
+        combo.reset();
+        accuracy.reset();
+
+        // Start with a full tank
+        fuel.current = fuel.capacity;
+
+        // Put the vehicle back at spawn, stopped and fully repaired
+        for (mut transform, mut health, mut velocity, mut knockback) in vehicle_q.iter_mut() {
+            transform.translation = VEHICLE_SPAWN_POSITION;
+            transform.rotation = Quat::IDENTITY;
+            velocity.current = 0.0;
+            velocity.turn_rate = 0.0;
+            velocity.lateral = 0.0;
+            velocity.drift = 0.0;
+            knockback.velocity = Vec3::ZERO;
+            health.current = health.max;
+        }
+
         // Reset target lock
         target_lock.locked_entity = None;
         target_lock.lock_position = None;
 
-        // Despawn all dinosaurs
+        // Despawn all dinosaurs (this also drops any DamageReaction/FlashDamage/DinoDeath on them)
         for entity in dino_q.iter() {
             commands.entity(entity).despawn_recursive();
         }
@@ -272,10 +347,15 @@ fn handle_restart_game(
             commands.entity(entity).despawn_recursive();
         }
 
+        // Uncollected coins belong to the old hunt
+        for entity in coin_q.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+
         // Note: Blood particles will despawn themselves on their timer
         // No need to manually despawn them
 
         // Respawn dinosaurs
-        respawn_events.send(RespawnDinosEvent);
+        respawn_events.send(RespawnDinosEvent { from_seed: true });
     }
 }
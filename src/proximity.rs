@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use crate::pause::GameState;
+use crate::dino::{AIState, DinoAI, DinoSpecies, Dinosaur};
+use crate::vehicle::PlayerVehicle;
+
+/// Threats inside this distance of the vehicle raise the proximity warning
+pub const DANGER_RADIUS: f32 = 35.0;
+/// Border pulse rate, in pulses per second, at the edge of the radius and point blank
+const PULSE_RATE_FAR: f32 = 1.0;
+const PULSE_RATE_NEAR: f32 = 3.0;
+
+/// Nearest dangerous dino, recomputed every frame
+#[derive(Resource, Default)]
+pub struct ProximityWarning {
+    /// 0 at the edge of the danger radius up to 1 on top of the vehicle; None when nothing is near
+    pub closeness: Option<f32>,
+    pulse_phase: f32,
+}
+
+/// Thin red HUD border that pulses while a threat is close
+#[derive(Component)]
+pub struct ProximityBorder;
+
+pub struct ProximityPlugin;
+
+impl Plugin for ProximityPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ProximityWarning>()
+            .add_systems(Startup, setup_proximity_border)
+            .add_systems(Update, (
+                update_nearest_threat,
+                update_proximity_border,
+            ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn setup_proximity_border(mut commands: Commands) {
+    commands.spawn((
+        ProximityBorder,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            border: UiRect::all(Val::Px(14.0)),
+            ..default()
+        },
+        BorderColor(Color::NONE),
+    ));
+}
+
+fn update_nearest_threat(
+    mut warning: ResMut<ProximityWarning>,
+    vehicle_q: Query<&Transform, With<PlayerVehicle>>,
+    dino_q: Query<(&Transform, &DinoSpecies, &DinoAI), With<Dinosaur>>,
+) {
+    let Ok(vehicle) = vehicle_q.get_single() else {
+        warning.closeness = None;
+        return;
+    };
+
+    // The boss is always a threat; other carnivores only once they're coming for the vehicle
+    let nearest = dino_q
+        .iter()
+        .filter(|(_, species, ai)| match ai.state {
+            AIState::Dead => false,
+            AIState::Attack => species.is_carnivore(),
+            _ => **species == DinoSpecies::TRex,
+        })
+        .map(|(transform, _, _)| transform.translation.distance(vehicle.translation))
+        .fold(f32::MAX, f32::min);
+
+    warning.closeness = (nearest < DANGER_RADIUS).then(|| 1.0 - nearest / DANGER_RADIUS);
+}
+
+fn update_proximity_border(
+    time: Res<Time>,
+    mut warning: ResMut<ProximityWarning>,
+    mut border_q: Query<&mut BorderColor, With<ProximityBorder>>,
+) {
+    let alpha = match warning.closeness {
+        Some(closeness) => {
+            let rate = PULSE_RATE_FAR + (PULSE_RATE_NEAR - PULSE_RATE_FAR) * closeness;
+            warning.pulse_phase = (warning.pulse_phase + rate * time.delta_secs()).fract();
+            let pulse = 0.5 + 0.5 * (warning.pulse_phase * std::f32::consts::TAU).cos();
+            (0.15 + 0.45 * closeness) * pulse
+        }
+        None => {
+            warning.pulse_phase = 0.0;
+            0.0
+        }
+    };
+
+    for mut border in border_q.iter_mut() {
+        border.0 = Color::srgba(0.9, 0.05, 0.05, alpha);
+    }
+}
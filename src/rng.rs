@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+/// Environment variable that fixes the gameplay seed, e.g. `DINO_SEED=42`
+const SEED_ENV_VAR: &str = "DINO_SEED";
+
+/// Parts of the map a restart lays out again, each with its own `GameRng::layout` generator
+pub const DINO_LAYOUT: u64 = 1;
+pub const BARREL_LAYOUT: u64 = 2;
+
+/// Seedable source for all gameplay randomness (spawns, AI rolls, spread, effects).
+/// The same seed replays the same rolls, so bug reports can include it.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl Default for GameRng {
+    // Seed from DINO_SEED when set, otherwise a fresh random seed each launch
+    fn default() -> Self {
+        let seed = std::env::var(SEED_ENV_VAR)
+            .ok()
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or_else(rand::random);
+        info!("Game seed: {}", seed);
+        Self::new(seed)
+    }
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Start the stream over from `seed`
+    pub fn reseed(&mut self, seed: u64) {
+        info!("Game seed: {}", seed);
+        *self = Self::new(seed);
+    }
+
+    /// Generator for one part of the layout that depends only on the seed, so the same seed
+    /// lays that part out the same way however much gameplay has drawn from the stream
+    pub fn layout(&self, part: u64) -> StdRng {
+        StdRng::seed_from_u64(self.seed ^ part.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
@@ -0,0 +1,105 @@
+use bevy::prelude::*;
+use crate::pause::GameState;
+
+/// Seconds of "3..2..1" before the round goes live
+const COUNTDOWN_SECONDS: f32 = 3.0;
+/// How long "GO!" stays up after the countdown
+const GO_DISPLAY_SECONDS: f32 = 0.7;
+
+/// Countdown at the start of a round; gameplay input is frozen until "GO"
+#[derive(Resource)]
+pub struct RoundStart {
+    pub timer: Timer,
+}
+
+impl Default for RoundStart {
+    // The very first round counts down too
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(COUNTDOWN_SECONDS + GO_DISPLAY_SECONDS, TimerMode::Once),
+        }
+    }
+}
+
+impl RoundStart {
+    pub fn begin(&mut self) {
+        self.timer.reset();
+    }
+
+    /// True until "GO"
+    pub fn is_counting(&self) -> bool {
+        self.timer.elapsed_secs() < COUNTDOWN_SECONDS
+    }
+
+    /// Establishing pan amount, 1 at the start of the countdown easing to 0 at "GO"
+    pub fn pan(&self) -> f32 {
+        let t = (self.timer.elapsed_secs() / COUNTDOWN_SECONDS).min(1.0);
+        (1.0 - t) * (1.0 - t)
+    }
+}
+
+#[derive(Component)]
+pub struct CountdownText;
+
+pub struct RoundStartPlugin;
+
+impl Plugin for RoundStartPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RoundStart>()
+            .add_systems(Startup, setup_countdown_text)
+            .add_systems(Update, update_countdown.run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn setup_countdown_text(mut commands: Commands) {
+    commands.spawn(Node {
+        position_type: PositionType::Absolute,
+        width: Val::Percent(100.0),
+        top: Val::Percent(30.0),
+        justify_content: JustifyContent::Center,
+        ..default()
+    }).with_children(|parent| {
+        parent.spawn((
+            CountdownText,
+            Text::new(""),
+            TextFont {
+                font_size: 96.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn update_countdown(
+    time: Res<Time>,
+    mut round_start: ResMut<RoundStart>,
+    mut text_q: Query<(&mut Text, &mut TextFont, &mut TextColor), With<CountdownText>>,
+) {
+    if round_start.timer.finished() {
+        for (mut text, _, _) in text_q.iter_mut() {
+            if !text.0.is_empty() {
+                text.0.clear();
+            }
+        }
+        return;
+    }
+
+    round_start.timer.tick(time.delta());
+    let elapsed = round_start.timer.elapsed_secs();
+
+    let (label, color, beat) = if round_start.is_counting() {
+        let remaining = COUNTDOWN_SECONDS - elapsed;
+        (format!("{}", remaining.ceil() as u32), Color::WHITE, remaining.fract())
+    } else {
+        let go = (elapsed - COUNTDOWN_SECONDS) / GO_DISPLAY_SECONDS;
+        ("GO!".to_string(), Color::srgba(0.4, 1.0, 0.4, 1.0 - go), 1.0 - go)
+    };
+
+    for (mut text, mut font, mut text_color) in text_q.iter_mut() {
+        text.0 = label.clone();
+        // Each number pops in big and settles
+        font.font_size = 96.0 + beat * 40.0;
+        text_color.0 = color;
+    }
+}
@@ -0,0 +1,300 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::combo::ComboSystem;
+use crate::dino::{
+    spawn_baby, spawn_dinosaur, AIState, Alpha, Attacking, Baby, CoinSystem, DinoAI, DinoDeath, DinoHealth,
+    DinoSpecies, Dinosaur, FollowParent, Grazing,
+};
+use crate::game_mode::TimeAttackMode;
+use crate::input::TargetLock;
+use crate::kill_feed::KillFeedEvent;
+use crate::pause::GameState;
+use crate::round_start::RoundStart;
+use crate::save::SaveFile;
+use crate::vehicle::{Knockback, PlayerVehicle, VehicleHealth, VehicleVelocity};
+use crate::GameScore;
+
+const RUN_SAVE_PATH: &str = "runsave.json";
+
+/// Everything needed to pick an unfinished hunt back up
+#[derive(Serialize, Deserialize)]
+pub struct RunSnapshot {
+    pub vehicle: VehicleSnapshot,
+    pub dinos: Vec<DinoSnapshot>,
+    pub coins: u32,
+    pub score: u32,
+    pub combo: u32,
+    pub max_combo: u32,
+    pub time_attack: Option<TimeAttackSnapshot>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct VehicleSnapshot {
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub health: f32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct DinoSnapshot {
+    pub species: DinoSpecies,
+    pub position: Vec3,
+    pub rotation: Quat,
+    pub health: f32,
+    pub state: AIState,
+    /// Where a fleeing dino is running, so it keeps going after a load
+    #[serde(default)]
+    pub flee_direction: Vec3,
+    #[serde(default)]
+    pub flee_target: Option<Vec3>,
+    /// Seconds of grazing left, for an idle herbivore
+    #[serde(default)]
+    pub graze_seconds: Option<f32>,
+    pub alpha: bool,
+    pub baby: bool,
+    /// Index of the parent in `RunSnapshot::dinos`, for babies still following one
+    pub parent: Option<usize>,
+}
+
+/// Only present while a Time Attack is running
+#[derive(Serialize, Deserialize)]
+pub struct TimeAttackSnapshot {
+    pub remaining_secs: f32,
+    pub kills: u32,
+    pub max_combo: u32,
+}
+
+impl RunSnapshot {
+    pub fn load() -> Option<Self> {
+        let contents = std::fs::read_to_string(RUN_SAVE_PATH).ok()?;
+        match serde_json::from_str(&contents) {
+            Ok(snapshot) => Some(snapshot),
+            Err(err) => {
+                warn!("Ignoring unreadable run save {}: {}", RUN_SAVE_PATH, err);
+                None
+            }
+        }
+    }
+
+    pub fn write(&self) {
+        let result = serde_json::to_string_pretty(self)
+            .map_err(|err| err.to_string())
+            .and_then(|json| std::fs::write(RUN_SAVE_PATH, json).map_err(|err| err.to_string()));
+        if let Err(err) = result {
+            warn!("Failed to write run save {}: {}", RUN_SAVE_PATH, err);
+        }
+    }
+}
+
+/// Snapshot the current hunt to disk
+#[derive(Event)]
+pub struct SaveRunEvent;
+
+/// Replace the current hunt with the one on disk
+#[derive(Event)]
+pub struct LoadRunEvent;
+
+/// Whether a run save exists, used by the pause menu to offer "Load Game"
+#[derive(Resource, Default)]
+pub struct RunSaveFile {
+    pub exists: bool,
+}
+
+pub struct RunSavePlugin;
+
+impl Plugin for RunSavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunSaveFile>()
+            .add_event::<SaveRunEvent>()
+            .add_event::<LoadRunEvent>()
+            .add_systems(Startup, detect_run_save)
+            // The menu sends these while paused
+            .add_systems(Update, (save_run, load_run).chain());
+    }
+}
+
+fn detect_run_save(save_file: Res<SaveFile>, mut run_save: ResMut<RunSaveFile>) {
+    run_save.exists = save_file.enabled && std::path::Path::new(RUN_SAVE_PATH).exists();
+}
+
+fn save_run(
+    mut events: EventReader<SaveRunEvent>,
+    save_file: Res<SaveFile>,
+    mut run_save: ResMut<RunSaveFile>,
+    vehicle_q: Query<(&Transform, &VehicleHealth), With<PlayerVehicle>>,
+    dino_q: Query<
+        (
+            Entity, &DinoSpecies, &Transform, &DinoHealth, &DinoAI, Option<&Grazing>, Has<Alpha>, Has<Baby>,
+            Option<&FollowParent>,
+        ),
+        (With<Dinosaur>, Without<DinoDeath>)
+    >,
+    coins: Res<CoinSystem>,
+    score: Res<GameScore>,
+    combo: Res<ComboSystem>,
+    time_attack: Res<TimeAttackMode>,
+    mut kill_feed: EventWriter<KillFeedEvent>,
+) {
+    if events.read().count() == 0 || !save_file.enabled {
+        return;
+    }
+    let Ok((vehicle_transform, vehicle_health)) = vehicle_q.get_single() else {
+        return;
+    };
+
+    // Carcasses are left behind; babies point at their parent by position in the list
+    let living: Vec<_> = dino_q.iter().filter(|(_, _, _, health, ..)| health.current > 0.0).collect();
+    let dinos = living
+        .iter()
+        .map(|(_, species, transform, health, ai, grazing, alpha, baby, follow)| DinoSnapshot {
+            species: **species,
+            position: transform.translation,
+            rotation: transform.rotation,
+            health: health.current,
+            state: ai.state,
+            flee_direction: ai.flee_direction,
+            flee_target: ai.flee_target,
+            graze_seconds: grazing.map(|grazing| grazing.timer.remaining_secs()),
+            alpha: *alpha,
+            baby: *baby,
+            parent: follow.and_then(|follow| living.iter().position(|(entity, ..)| *entity == follow.target)),
+        })
+        .collect();
+
+    RunSnapshot {
+        vehicle: VehicleSnapshot {
+            position: vehicle_transform.translation,
+            rotation: vehicle_transform.rotation,
+            health: vehicle_health.current,
+        },
+        dinos,
+        coins: coins.total_coins,
+        score: score.score,
+        combo: combo.current_combo,
+        max_combo: combo.max_combo,
+        time_attack: time_attack.is_active.then(|| TimeAttackSnapshot {
+            remaining_secs: time_attack.time_remaining.remaining_secs(),
+            kills: time_attack.kills,
+            max_combo: time_attack.max_combo,
+        }),
+    }
+    .write();
+
+    run_save.exists = true;
+    kill_feed.send(KillFeedEvent::new("GAME SAVED", Color::srgb(0.6, 0.8, 1.0)));
+}
+
+/// Despawn the current hunt and rebuild it from the snapshot
+fn load_run(
+    mut commands: Commands,
+    mut events: EventReader<LoadRunEvent>,
+    save_file: Res<SaveFile>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    dino_q: Query<Entity, With<Dinosaur>>,
+    bullet_q: Query<Entity, With<crate::weapon::Bullet>>,
+    coin_q: Query<Entity, With<crate::coins::Coin>>,
+    mut vehicle_q: Query<
+        (&mut Transform, &mut VehicleHealth, &mut VehicleVelocity, &mut Knockback),
+        With<PlayerVehicle>
+    >,
+    mut coins: ResMut<CoinSystem>,
+    mut score: ResMut<GameScore>,
+    mut combo: ResMut<ComboSystem>,
+    mut time_attack: ResMut<TimeAttackMode>,
+    mut target_lock: ResMut<TargetLock>,
+    mut round_start: ResMut<RoundStart>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if events.read().count() == 0 || !save_file.enabled {
+        return;
+    }
+    let Some(snapshot) = RunSnapshot::load() else {
+        return;
+    };
+
+    for entity in dino_q.iter().chain(bullet_q.iter()).chain(coin_q.iter()) {
+        commands.entity(entity).despawn_recursive();
+    }
+    // The locked entity is gone with the old herd
+    target_lock.locked_entity = None;
+    target_lock.lock_position = None;
+
+    for (mut transform, mut health, mut velocity, mut knockback) in vehicle_q.iter_mut() {
+        transform.translation = snapshot.vehicle.position;
+        transform.rotation = snapshot.vehicle.rotation;
+        health.current = snapshot.vehicle.health.min(health.max);
+        velocity.current = 0.0;
+        velocity.turn_rate = 0.0;
+        velocity.lateral = 0.0;
+        velocity.drift = 0.0;
+        knockback.velocity = Vec3::ZERO;
+    }
+
+    // Adults first, so every following baby's parent already has an entity
+    let mut spawned = vec![None; snapshot.dinos.len()];
+    for (index, dino) in snapshot.dinos.iter().enumerate().filter(|(_, dino)| !dino.baby) {
+        let entity = spawn_dinosaur(&mut commands, &mut meshes, &mut materials, dino.species, dino.position, dino.alpha);
+        restore_dino(&mut commands, entity, dino);
+        spawned[index] = Some(entity);
+    }
+    for dino in snapshot.dinos.iter().filter(|dino| dino.baby) {
+        let parent = dino.parent.and_then(|index| spawned.get(index).copied().flatten());
+        let entity = spawn_baby(&mut commands, &mut meshes, &mut materials, dino.species, parent, dino.position);
+        restore_dino(&mut commands, entity, dino);
+    }
+
+    coins.total_coins = snapshot.coins;
+    score.score = snapshot.score;
+    combo.reset();
+    combo.current_combo = snapshot.combo;
+    combo.max_combo = snapshot.max_combo;
+
+    match snapshot.time_attack {
+        Some(saved) => {
+            time_attack.start();
+            let elapsed = time_attack.time_remaining.duration().as_secs_f32() - saved.remaining_secs;
+            time_attack.time_remaining.set_elapsed(std::time::Duration::from_secs_f32(elapsed.max(0.0)));
+            time_attack.kills = saved.kills;
+            time_attack.max_combo = saved.max_combo;
+        }
+        None => time_attack.stop(),
+    }
+
+    // Give the player a moment before the restored herd gets moving
+    round_start.begin();
+    next_state.set(GameState::Playing);
+}
+
+fn restore_dino(commands: &mut Commands, entity: Entity, dino: &DinoSnapshot) {
+    let health = dino.health;
+    let rotation = dino.rotation;
+    let (flee_direction, flee_target) = (dino.flee_direction, dino.flee_target);
+    // Idle only lasts while grazing, and fleeing needs somewhere to run; otherwise
+    // (stunned when saved, or an older save) the dino would stand still until startled
+    let state = match dino.state {
+        AIState::Idle if dino.graze_seconds.is_none() => AIState::Roam,
+        AIState::Flee if flee_direction == Vec3::ZERO && flee_target.is_none() => AIState::Roam,
+        state => state,
+    };
+    let mut entity_commands = commands.entity(entity);
+    entity_commands
+        .entry::<DinoHealth>()
+        .and_modify(move |mut current| current.current = health.min(current.max));
+    entity_commands
+        .entry::<DinoAI>()
+        .and_modify(move |mut ai| {
+            ai.state = state;
+            ai.flee_direction = flee_direction;
+            ai.flee_target = flee_target;
+        });
+    entity_commands
+        .entry::<Transform>()
+        .and_modify(move |mut transform| transform.rotation = rotation);
+    if state == AIState::Attack {
+        entity_commands.insert(Attacking::new());
+    }
+    if let (AIState::Idle, Some(seconds)) = (state, dino.graze_seconds) {
+        entity_commands.insert(Grazing { timer: Timer::from_seconds(seconds, TimerMode::Once) });
+    }
+}
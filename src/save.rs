@@ -0,0 +1,228 @@
+use bevy::prelude::*;
+use bevy::app::AppExit;
+use serde::{Deserialize, Serialize};
+use crate::pause::{GameState, RestartGameEvent, RestartProgress};
+use crate::dino::CoinSystem;
+use crate::shop::{WeaponUpgrades, VehicleUpgrades};
+use crate::weapon_system::{AmmoType, WeaponInventory, WeaponType};
+use crate::vehicle::{PlayerVehicle, VehicleHealth, VehicleType};
+
+const SAVE_PATH: &str = "savegame.json";
+/// Bump when the layout changes; older saves still load with missing fields defaulted
+const SAVE_VERSION: u32 = 2;
+
+/// Persistent progression written to disk
+#[derive(Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SaveData {
+    pub version: u32,
+    pub coins: u32,
+    pub weapon_upgrades: WeaponUpgrades,
+    pub vehicle_upgrades: VehicleUpgrades,
+    pub unlocked_weapons: Vec<WeaponType>,
+    pub unlocked_ammo: Vec<AmmoType>,
+}
+
+impl Default for SaveData {
+    fn default() -> Self {
+        Self {
+            version: SAVE_VERSION,
+            coins: 0,
+            weapon_upgrades: WeaponUpgrades::default(),
+            vehicle_upgrades: VehicleUpgrades::default(),
+            unlocked_weapons: vec![WeaponType::MachineGun],
+            unlocked_ammo: vec![AmmoType::Standard],
+        }
+    }
+}
+
+impl SaveData {
+    pub fn capture(
+        coins: &CoinSystem,
+        weapon_upgrades: &WeaponUpgrades,
+        vehicle_upgrades: &VehicleUpgrades,
+        inventory: &WeaponInventory,
+    ) -> Self {
+        Self {
+            version: SAVE_VERSION,
+            coins: coins.total_coins,
+            weapon_upgrades: *weapon_upgrades,
+            vehicle_upgrades: *vehicle_upgrades,
+            unlocked_weapons: inventory.unlocked_weapons.clone(),
+            unlocked_ammo: inventory.unlocked_ammo.clone(),
+        }
+    }
+
+    /// Read the save file, returning None if there is none or it can't be parsed
+    pub fn load() -> Option<Self> {
+        let contents = std::fs::read_to_string(SAVE_PATH).ok()?;
+        match serde_json::from_str(&contents) {
+            Ok(data) => Some(data),
+            Err(err) => {
+                warn!("Ignoring unreadable save file {}: {}", SAVE_PATH, err);
+                None
+            }
+        }
+    }
+
+    /// Anything earned beyond a fresh start
+    pub fn has_progress(&self) -> bool {
+        *self != Self::default()
+    }
+
+    pub fn write(&self) {
+        let result = serde_json::to_string_pretty(self)
+            .map_err(|err| err.to_string())
+            .and_then(|json| std::fs::write(SAVE_PATH, json).map_err(|err| err.to_string()));
+
+        if let Err(err) = result {
+            warn!("Failed to write save file {}: {}", SAVE_PATH, err);
+        }
+    }
+
+    pub fn apply(
+        &self,
+        coins: &mut CoinSystem,
+        weapon_upgrades: &mut WeaponUpgrades,
+        vehicle_upgrades: &mut VehicleUpgrades,
+        inventory: &mut WeaponInventory,
+    ) {
+        coins.total_coins = self.coins;
+        *weapon_upgrades = self.weapon_upgrades;
+        *vehicle_upgrades = self.vehicle_upgrades;
+
+        // Rebuild through unlock() so the machine gun is always present and slots stay ordered
+        *inventory = WeaponInventory::new();
+        for weapon in &self.unlocked_weapons {
+            inventory.unlock(*weapon);
+        }
+        for ammo in &self.unlocked_ammo {
+            inventory.unlock_ammo(*ammo);
+        }
+    }
+}
+
+/// Whether a save file exists, used by the main menu to offer "Continue"
+#[derive(Resource)]
+pub struct SaveFile {
+    pub exists: bool,
+    /// Off for headless runs, so tests never read or overwrite the player's save
+    pub enabled: bool,
+}
+
+impl Default for SaveFile {
+    fn default() -> Self {
+        Self {
+            exists: false,
+            enabled: true,
+        }
+    }
+}
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SaveFile>()
+            .add_systems(PreStartup, load_progress)
+            .add_systems(PostStartup, apply_vehicle_health_upgrades)
+            .add_systems(OnEnter(GameState::Paused), save_progress)
+            .add_systems(Update, (
+                handle_restart_progress,
+                save_progress.run_if(
+                    resource_changed::<WeaponUpgrades>
+                        .or(resource_changed::<VehicleUpgrades>)
+                        .or(resource_changed::<WeaponInventory>)
+                ),
+            ).chain())
+            .add_systems(Last, save_on_exit);
+    }
+}
+
+fn load_progress(
+    mut save_file: ResMut<SaveFile>,
+    mut coins: ResMut<CoinSystem>,
+    mut weapon_upgrades: ResMut<WeaponUpgrades>,
+    mut vehicle_upgrades: ResMut<VehicleUpgrades>,
+    mut inventory: ResMut<WeaponInventory>,
+) {
+    if !save_file.enabled {
+        return;
+    }
+    if let Some(data) = SaveData::load() {
+        data.apply(&mut coins, &mut weapon_upgrades, &mut vehicle_upgrades, &mut inventory);
+        save_file.exists = true;
+    }
+}
+
+/// Health upgrades live on the vehicle component, so re-derive its max from the level
+fn apply_vehicle_health_upgrades(
+    vehicle_upgrades: Res<VehicleUpgrades>,
+    mut health_q: Query<(&mut VehicleHealth, &VehicleType), With<PlayerVehicle>>,
+) {
+    for (mut health, chassis) in health_q.iter_mut() {
+        health.max = vehicle_upgrades.max_health(*chassis);
+        health.current = health.max;
+    }
+}
+
+fn save_progress(
+    mut save_file: ResMut<SaveFile>,
+    coins: Res<CoinSystem>,
+    weapon_upgrades: Res<WeaponUpgrades>,
+    vehicle_upgrades: Res<VehicleUpgrades>,
+    inventory: Res<WeaponInventory>,
+) {
+    if !save_file.enabled {
+        return;
+    }
+    // A first launch has nothing worth continuing, so don't offer "Continue" for it
+    let data = SaveData::capture(&coins, &weapon_upgrades, &vehicle_upgrades, &inventory);
+    if !save_file.exists && !data.has_progress() {
+        return;
+    }
+    data.write();
+    save_file.exists = true;
+}
+
+/// Continue restores the saved progression, New Game wipes it
+pub fn handle_restart_progress(
+    mut events: EventReader<RestartGameEvent>,
+    save_file: Res<SaveFile>,
+    mut coins: ResMut<CoinSystem>,
+    mut weapon_upgrades: ResMut<WeaponUpgrades>,
+    mut vehicle_upgrades: ResMut<VehicleUpgrades>,
+    mut inventory: ResMut<WeaponInventory>,
+    mut health_q: Query<(&mut VehicleHealth, &VehicleType), With<PlayerVehicle>>,
+) {
+    for event in events.read() {
+        let data = match event.progress {
+            RestartProgress::Continue if save_file.enabled => SaveData::load().unwrap_or_default(),
+            // New Game, or a headless run with no save to continue from
+            _ => SaveData::default(),
+        };
+        data.apply(&mut coins, &mut weapon_upgrades, &mut vehicle_upgrades, &mut inventory);
+
+        for (mut health, chassis) in health_q.iter_mut() {
+            health.max = vehicle_upgrades.max_health(*chassis);
+            health.current = health.max;
+        }
+    }
+}
+
+fn save_on_exit(
+    mut exit_events: EventReader<AppExit>,
+    save_file: Res<SaveFile>,
+    coins: Res<CoinSystem>,
+    weapon_upgrades: Res<WeaponUpgrades>,
+    vehicle_upgrades: Res<VehicleUpgrades>,
+    inventory: Res<WeaponInventory>,
+) {
+    if exit_events.read().next().is_none() || !save_file.enabled {
+        return;
+    }
+    let data = SaveData::capture(&coins, &weapon_upgrades, &vehicle_upgrades, &inventory);
+    if save_file.exists || data.has_progress() {
+        data.write();
+    }
+}
@@ -0,0 +1,233 @@
+use bevy::prelude::*;
+use crate::pause::GameState;
+use crate::input::MouseSettings;
+use crate::audio::AudioSettings;
+use crate::effects::GoreSettings;
+use crate::minimap::MinimapSettings;
+use crate::camera::CameraConfig;
+
+#[derive(Component)]
+pub struct SettingsMenu;
+
+#[derive(Component, Clone, Copy)]
+pub enum SettingsButton {
+    SensitivityDown,
+    SensitivityUp,
+    FovDown,
+    FovUp,
+    ToggleCameraSnap,
+    ToggleInvertY,
+    ToggleAimAssist,
+    ToggleStickyLock,
+    ToggleGore,
+    ToggleRadarSweep,
+    VolumeDown,
+    VolumeUp,
+    ToggleMute,
+}
+
+/// Text showing the current value of a setting
+#[derive(Component, Clone, Copy)]
+pub enum SettingsLabel {
+    Sensitivity,
+    Fov,
+    CameraSnap,
+    InvertY,
+    AimAssist,
+    StickyLock,
+    Gore,
+    RadarSweep,
+    Volume,
+    Mute,
+}
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Paused), setup_settings_menu)
+            .add_systems(Update, (
+                handle_settings_buttons,
+                update_settings_text,
+            ).chain().run_if(in_state(GameState::Paused)));
+    }
+}
+
+fn setup_settings_menu(mut commands: Commands) {
+    // Settings panel (right side, over the pause screen)
+    commands.spawn((
+        SettingsMenu,
+        StateScoped(GameState::Paused),
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(40.0),
+            top: Val::Px(40.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(10.0),
+            padding: UiRect::all(Val::Px(15.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        GlobalZIndex(1),
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("SETTINGS"),
+            TextFont {
+                font_size: 24.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.8, 0.2)),
+        ));
+
+        spawn_stepper_row(parent, SettingsButton::SensitivityDown, SettingsLabel::Sensitivity, SettingsButton::SensitivityUp);
+        spawn_stepper_row(parent, SettingsButton::FovDown, SettingsLabel::Fov, SettingsButton::FovUp);
+        spawn_toggle_button(parent, SettingsButton::ToggleCameraSnap, SettingsLabel::CameraSnap);
+        spawn_toggle_button(parent, SettingsButton::ToggleInvertY, SettingsLabel::InvertY);
+        spawn_toggle_button(parent, SettingsButton::ToggleAimAssist, SettingsLabel::AimAssist);
+        spawn_toggle_button(parent, SettingsButton::ToggleStickyLock, SettingsLabel::StickyLock);
+        spawn_toggle_button(parent, SettingsButton::ToggleGore, SettingsLabel::Gore);
+        spawn_toggle_button(parent, SettingsButton::ToggleRadarSweep, SettingsLabel::RadarSweep);
+        spawn_stepper_row(parent, SettingsButton::VolumeDown, SettingsLabel::Volume, SettingsButton::VolumeUp);
+        spawn_toggle_button(parent, SettingsButton::ToggleMute, SettingsLabel::Mute);
+    });
+}
+
+/// A row of [-] value [+]
+fn spawn_stepper_row(parent: &mut ChildBuilder, down: SettingsButton, label: SettingsLabel, up: SettingsButton) {
+    parent.spawn(Node {
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(10.0),
+        ..default()
+    }).with_children(|row| {
+        spawn_settings_button(row, down, "-", 40.0);
+        row.spawn((
+            label,
+            Text::new(""),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+        spawn_settings_button(row, up, "+", 40.0);
+    });
+}
+
+fn spawn_toggle_button(parent: &mut ChildBuilder, kind: SettingsButton, label: SettingsLabel) {
+    parent.spawn((
+        kind,
+        Button,
+        Node {
+            width: Val::Px(200.0),
+            height: Val::Px(36.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.2, 0.2, 0.3)),
+    )).with_children(|parent| {
+        parent.spawn((
+            label,
+            Text::new(""),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn spawn_settings_button(parent: &mut ChildBuilder, kind: SettingsButton, label: &str, width: f32) {
+    parent.spawn((
+        kind,
+        Button,
+        Node {
+            width: Val::Px(width),
+            height: Val::Px(36.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.2, 0.2, 0.3)),
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn handle_settings_buttons(
+    mut interaction_q: Query<
+        (&Interaction, &SettingsButton, &mut BackgroundColor),
+        Changed<Interaction>
+    >,
+    mut mouse_settings: ResMut<MouseSettings>,
+    mut audio_settings: ResMut<AudioSettings>,
+    mut gore_settings: ResMut<GoreSettings>,
+    mut minimap_settings: ResMut<MinimapSettings>,
+    mut camera_config: ResMut<CameraConfig>,
+) {
+    for (interaction, button, mut bg_color) in interaction_q.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                match button {
+                    SettingsButton::SensitivityDown => mouse_settings.adjust_sensitivity(-0.1),
+                    SettingsButton::SensitivityUp => mouse_settings.adjust_sensitivity(0.1),
+                    SettingsButton::FovDown => camera_config.adjust_fov(-5.0),
+                    SettingsButton::FovUp => camera_config.adjust_fov(5.0),
+                    SettingsButton::ToggleCameraSnap => camera_config.snap = !camera_config.snap,
+                    SettingsButton::ToggleInvertY => mouse_settings.invert_y = !mouse_settings.invert_y,
+                    SettingsButton::ToggleAimAssist => mouse_settings.aim_assist = !mouse_settings.aim_assist,
+                    SettingsButton::ToggleStickyLock => mouse_settings.sticky_lock = !mouse_settings.sticky_lock,
+                    SettingsButton::ToggleGore => gore_settings.enabled = !gore_settings.enabled,
+                    SettingsButton::ToggleRadarSweep => minimap_settings.radar_sweep = !minimap_settings.radar_sweep,
+                    SettingsButton::VolumeDown => audio_settings.adjust_volume(-0.1),
+                    SettingsButton::VolumeUp => audio_settings.adjust_volume(0.1),
+                    SettingsButton::ToggleMute => audio_settings.muted = !audio_settings.muted,
+                }
+            }
+            Interaction::Hovered => {
+                bg_color.0 = Color::srgb(0.3, 0.3, 0.45);
+            }
+            Interaction::None => {
+                bg_color.0 = Color::srgb(0.2, 0.2, 0.3);
+            }
+        }
+    }
+}
+
+fn update_settings_text(
+    mouse_settings: Res<MouseSettings>,
+    audio_settings: Res<AudioSettings>,
+    gore_settings: Res<GoreSettings>,
+    minimap_settings: Res<MinimapSettings>,
+    camera_config: Res<CameraConfig>,
+    mut label_q: Query<(&SettingsLabel, &mut Text)>,
+) {
+    for (label, mut text) in label_q.iter_mut() {
+        text.0 = match label {
+            SettingsLabel::Sensitivity => format!("Mouse Sensitivity: {:.1}", mouse_settings.sensitivity),
+            SettingsLabel::Fov => format!("Field of View: {:.0}", camera_config.fov),
+            SettingsLabel::CameraSnap => format!("Rigid Camera: {}", on_off(camera_config.snap)),
+            SettingsLabel::InvertY => format!("Invert Y: {}", on_off(mouse_settings.invert_y)),
+            SettingsLabel::AimAssist => format!("Aim Assist: {}", on_off(mouse_settings.aim_assist)),
+            SettingsLabel::StickyLock => format!("Sticky Lock: {}", on_off(mouse_settings.sticky_lock)),
+            SettingsLabel::Gore => format!("Blood Splatter: {}", on_off(gore_settings.enabled)),
+            SettingsLabel::RadarSweep => format!("Radar Sweep: {}", on_off(minimap_settings.radar_sweep)),
+            SettingsLabel::Volume => format!("Volume: {:.0}%", audio_settings.master_volume * 100.0),
+            SettingsLabel::Mute => format!("Mute: {}", on_off(audio_settings.muted)),
+        };
+    }
+}
+
+fn on_off(value: bool) -> &'static str {
+    if value { "On" } else { "Off" }
+}
@@ -1,16 +1,36 @@
 use bevy::prelude::*;
+use bevy::input::mouse::{MouseScrollUnit, MouseWheel};
+use serde::{Deserialize, Serialize};
 use crate::pause::GameState;
 use crate::dino::CoinSystem;
-use crate::weapon_system::WeaponType;
-use crate::vehicle::VehicleHealth;
+use crate::weapon_system::{AmmoType, WeaponType, WeaponInventory};
+use crate::vehicle::{VehicleHealth, VehicleType};
 use crate::input::PlayerInput;
+use crate::coins::COIN_MAGNET_RADIUS;
 
 #[derive(Resource, Default)]
 pub struct ShopState {
     pub is_open: bool,
+    pub selected_index: usize,
 }
 
-#[derive(Resource, Default, Clone, Copy)]
+/// Request to buy the next level of an upgrade (from a click or keyboard/gamepad confirm)
+#[derive(Event)]
+pub struct PurchaseUpgradeEvent {
+    pub upgrade_type: UpgradeType,
+}
+
+/// Request to sell back the most recent level of an upgrade (right click, Backspace or gamepad X)
+#[derive(Event)]
+pub struct SellUpgradeEvent {
+    pub upgrade_type: UpgradeType,
+}
+
+/// Fraction of a level's price returned when it is sold
+const SELL_REFUND_FRACTION: f32 = 0.7;
+
+#[derive(Resource, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct WeaponUpgrades {
     pub machinegun_damage_level: u32,
     pub machinegun_fire_rate_level: u32,
@@ -20,11 +40,71 @@ pub struct WeaponUpgrades {
     pub rocket_radius_level: u32,
 }
 
-#[derive(Resource, Default, Clone, Copy)]
+#[derive(Resource, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct VehicleUpgrades {
     pub max_health_level: u32,
     pub speed_level: u32,
     pub acceleration_level: u32,
+    pub coin_magnet_level: u32,
+}
+
+// Per-level stat math, shared by the systems applying upgrades and the shop tooltip
+impl WeaponUpgrades {
+    pub fn damage(&self, weapon: WeaponType) -> f32 {
+        let level = match weapon {
+            WeaponType::MachineGun => self.machinegun_damage_level,
+            WeaponType::Shotgun => self.shotgun_damage_level,
+            WeaponType::RocketLauncher => self.rocket_damage_level,
+        };
+        weapon.damage() * (1.0 + DAMAGE_PER_LEVEL * level as f32)
+    }
+
+    /// Seconds between shots
+    pub fn fire_interval(&self, weapon: WeaponType) -> f32 {
+        match weapon {
+            WeaponType::MachineGun => weapon.fire_rate() * FIRE_INTERVAL_PER_LEVEL.powi(self.machinegun_fire_rate_level as i32),
+            _ => weapon.fire_rate(),
+        }
+    }
+
+    pub fn pellet_count(&self, weapon: WeaponType) -> u32 {
+        match weapon {
+            WeaponType::Shotgun => weapon.pellet_count() + self.shotgun_pellet_level,
+            _ => weapon.pellet_count(),
+        }
+    }
+
+    pub fn explosion_radius(&self, weapon: WeaponType) -> f32 {
+        match weapon {
+            WeaponType::RocketLauncher => weapon.explosion_radius() + ROCKET_RADIUS_PER_LEVEL * self.rocket_radius_level as f32,
+            _ => weapon.explosion_radius(),
+        }
+    }
+}
+
+impl VehicleUpgrades {
+    /// Health added on top of the chassis' base health
+    pub fn bonus_health(&self) -> f32 {
+        VEHICLE_HEALTH_PER_LEVEL * self.max_health_level as f32
+    }
+
+    pub fn max_health(&self, chassis: VehicleType) -> f32 {
+        chassis.base_health() + self.bonus_health()
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        1.0 + SPEED_PER_LEVEL * self.speed_level as f32
+    }
+
+    pub fn acceleration_multiplier(&self) -> f32 {
+        1.0 + ACCELERATION_PER_LEVEL * self.acceleration_level as f32
+    }
+
+    /// Distance at which dropped coins start flying to the vehicle
+    pub fn coin_magnet_radius(&self) -> f32 {
+        COIN_MAGNET_RADIUS + COIN_MAGNET_RADIUS_PER_LEVEL * self.coin_magnet_level as f32
+    }
 }
 
 #[derive(Component)]
@@ -35,6 +115,7 @@ pub struct ShopButton;
 
 #[derive(Component)]
 pub struct UpgradeButton {
+    pub index: usize, // Position in the list, for keyboard/gamepad navigation
     pub upgrade_type: UpgradeType,
     pub cost: u32,
     pub level: u32,
@@ -52,8 +133,186 @@ pub enum UpgradeType {
     VehicleMaxHealth,
     VehicleSpeed,
     VehicleAcceleration,
+    CoinMagnet,
+    UnlockShotgun,
+    UnlockRocketLauncher,
+    UnlockIncendiaryAmmo,
+    UnlockCryoAmmo,
 }
 
+impl UpgradeType {
+    pub const UNLOCK: [UpgradeType; 4] = [
+        UpgradeType::UnlockShotgun,
+        UpgradeType::UnlockRocketLauncher,
+        UpgradeType::UnlockIncendiaryAmmo,
+        UpgradeType::UnlockCryoAmmo,
+    ];
+
+    pub const WEAPON: [UpgradeType; 6] = [
+        UpgradeType::MachineGunDamage,
+        UpgradeType::MachineGunFireRate,
+        UpgradeType::ShotgunDamage,
+        UpgradeType::ShotgunPellets,
+        UpgradeType::RocketDamage,
+        UpgradeType::RocketRadius,
+    ];
+
+    pub const VEHICLE: [UpgradeType; 4] = [
+        UpgradeType::VehicleMaxHealth,
+        UpgradeType::VehicleSpeed,
+        UpgradeType::VehicleAcceleration,
+        UpgradeType::CoinMagnet,
+    ];
+
+    pub fn label(&self) -> &str {
+        match self {
+            UpgradeType::MachineGunDamage => "MG Damage",
+            UpgradeType::MachineGunFireRate => "MG Fire Rate",
+            UpgradeType::ShotgunDamage => "Shotgun Damage",
+            UpgradeType::ShotgunPellets => "Shotgun Pellets",
+            UpgradeType::RocketDamage => "Rocket Damage",
+            UpgradeType::RocketRadius => "Rocket Blast Radius",
+            UpgradeType::VehicleMaxHealth => "Vehicle Health",
+            UpgradeType::VehicleSpeed => "Vehicle Speed",
+            UpgradeType::VehicleAcceleration => "Vehicle Acceleration",
+            UpgradeType::CoinMagnet => "Coin Magnet",
+            UpgradeType::UnlockShotgun => "Unlock Shotgun",
+            UpgradeType::UnlockRocketLauncher => "Unlock Rocket Launcher",
+            UpgradeType::UnlockIncendiaryAmmo => "Unlock Incendiary Ammo",
+            UpgradeType::UnlockCryoAmmo => "Unlock Cryo Ammo",
+        }
+    }
+
+    /// Coins returned for selling back the current level, None at level 0
+    pub fn refund(&self, level: u32) -> Option<u32> {
+        let bought_at = level.checked_sub(1)?;
+        Some((self.cost(bought_at) as f32 * SELL_REFUND_FRACTION) as u32)
+    }
+
+    /// Coin cost of buying the next level
+    pub fn cost(&self, level: u32) -> u32 {
+        match self {
+            UpgradeType::MachineGunDamage => level * 100 + 100,
+            UpgradeType::MachineGunFireRate => level * 120 + 150,
+            UpgradeType::ShotgunDamage => level * 100 + 120,
+            UpgradeType::ShotgunPellets => level * 150 + 200,
+            UpgradeType::RocketDamage => level * 150 + 250,
+            UpgradeType::RocketRadius => level * 120 + 200,
+            UpgradeType::VehicleMaxHealth => level * 200 + 200,
+            UpgradeType::VehicleSpeed => level * 150 + 150,
+            UpgradeType::VehicleAcceleration => level * 120 + 120,
+            UpgradeType::CoinMagnet => level * 100 + 150,
+            UpgradeType::UnlockShotgun => 300,
+            UpgradeType::UnlockRocketLauncher => 600,
+            UpgradeType::UnlockIncendiaryAmmo => 400,
+            UpgradeType::UnlockCryoAmmo => 350,
+        }
+    }
+
+    pub fn max_level(&self) -> u32 {
+        match self {
+            UpgradeType::UnlockShotgun
+            | UpgradeType::UnlockRocketLauncher
+            | UpgradeType::UnlockIncendiaryAmmo
+            | UpgradeType::UnlockCryoAmmo => 1,
+            UpgradeType::CoinMagnet => COIN_MAGNET_MAX_LEVEL,
+            _ => UPGRADE_MAX_LEVEL,
+        }
+    }
+
+    pub fn level(&self, weapon: &WeaponUpgrades, vehicle: &VehicleUpgrades, inventory: &WeaponInventory) -> u32 {
+        match self {
+            UpgradeType::MachineGunDamage => weapon.machinegun_damage_level,
+            UpgradeType::MachineGunFireRate => weapon.machinegun_fire_rate_level,
+            UpgradeType::ShotgunDamage => weapon.shotgun_damage_level,
+            UpgradeType::ShotgunPellets => weapon.shotgun_pellet_level,
+            UpgradeType::RocketDamage => weapon.rocket_damage_level,
+            UpgradeType::RocketRadius => weapon.rocket_radius_level,
+            UpgradeType::VehicleMaxHealth => vehicle.max_health_level,
+            UpgradeType::VehicleSpeed => vehicle.speed_level,
+            UpgradeType::VehicleAcceleration => vehicle.acceleration_level,
+            UpgradeType::CoinMagnet => vehicle.coin_magnet_level,
+            UpgradeType::UnlockShotgun => inventory.is_unlocked(WeaponType::Shotgun) as u32,
+            UpgradeType::UnlockRocketLauncher => inventory.is_unlocked(WeaponType::RocketLauncher) as u32,
+            UpgradeType::UnlockIncendiaryAmmo => inventory.is_ammo_unlocked(AmmoType::Incendiary) as u32,
+            UpgradeType::UnlockCryoAmmo => inventory.is_ammo_unlocked(AmmoType::Cryo) as u32,
+        }
+    }
+
+    /// Name and value of the stat this upgrade improves, at a given level; None for weapon and ammo unlocks
+    pub fn stat_at(&self, level: u32) -> Option<(&'static str, f32)> {
+        let stat = match self {
+            UpgradeType::MachineGunDamage => (
+                "Damage",
+                WeaponUpgrades { machinegun_damage_level: level, ..default() }.damage(WeaponType::MachineGun),
+            ),
+            UpgradeType::MachineGunFireRate => (
+                "Shots/sec",
+                1.0 / WeaponUpgrades { machinegun_fire_rate_level: level, ..default() }.fire_interval(WeaponType::MachineGun),
+            ),
+            UpgradeType::ShotgunDamage => (
+                "Damage per pellet",
+                WeaponUpgrades { shotgun_damage_level: level, ..default() }.damage(WeaponType::Shotgun),
+            ),
+            UpgradeType::ShotgunPellets => (
+                "Pellets",
+                WeaponUpgrades { shotgun_pellet_level: level, ..default() }.pellet_count(WeaponType::Shotgun) as f32,
+            ),
+            UpgradeType::RocketDamage => (
+                "Damage",
+                WeaponUpgrades { rocket_damage_level: level, ..default() }.damage(WeaponType::RocketLauncher),
+            ),
+            UpgradeType::RocketRadius => (
+                "Blast radius",
+                WeaponUpgrades { rocket_radius_level: level, ..default() }.explosion_radius(WeaponType::RocketLauncher),
+            ),
+            UpgradeType::VehicleMaxHealth => (
+                "Bonus health",
+                VehicleUpgrades { max_health_level: level, ..default() }.bonus_health(),
+            ),
+            UpgradeType::VehicleSpeed => (
+                "Top speed %",
+                VehicleUpgrades { speed_level: level, ..default() }.speed_multiplier() * 100.0,
+            ),
+            UpgradeType::VehicleAcceleration => (
+                "Acceleration %",
+                VehicleUpgrades { acceleration_level: level, ..default() }.acceleration_multiplier() * 100.0,
+            ),
+            UpgradeType::CoinMagnet => (
+                "Magnet radius",
+                VehicleUpgrades { coin_magnet_level: level, ..default() }.coin_magnet_radius(),
+            ),
+            UpgradeType::UnlockShotgun
+            | UpgradeType::UnlockRocketLauncher
+            | UpgradeType::UnlockIncendiaryAmmo
+            | UpgradeType::UnlockCryoAmmo => return None,
+        };
+        Some(stat)
+    }
+}
+
+const UPGRADE_MAX_LEVEL: u32 = 5;
+/// Max vehicle health gained per health upgrade level
+const VEHICLE_HEALTH_PER_LEVEL: f32 = 20.0;
+/// Fraction of base damage added per damage level
+const DAMAGE_PER_LEVEL: f32 = 0.15;
+/// Machine gun shot interval is multiplied by this per fire rate level
+const FIRE_INTERVAL_PER_LEVEL: f32 = 0.9;
+const ROCKET_RADIUS_PER_LEVEL: f32 = 1.0;
+/// Fraction of base top speed and acceleration added per level
+const SPEED_PER_LEVEL: f32 = 0.08;
+const ACCELERATION_PER_LEVEL: f32 = 0.1;
+/// Coin magnet reach gained per level; capped so coins never come from across the map
+const COIN_MAGNET_RADIUS_PER_LEVEL: f32 = 2.5;
+const COIN_MAGNET_MAX_LEVEL: u32 = 4;
+
+#[derive(Component)]
+pub struct ShopScrollArea;
+
+/// Panel beside the list describing the selected upgrade's next level
+#[derive(Component)]
+pub struct ShopTooltipText;
+
 pub struct ShopPlugin;
 
 impl Plugin for ShopPlugin {
@@ -61,10 +320,17 @@ impl Plugin for ShopPlugin {
         app.init_resource::<ShopState>()
             .init_resource::<WeaponUpgrades>()
             .init_resource::<VehicleUpgrades>()
+            .add_event::<PurchaseUpgradeEvent>()
+            .add_event::<SellUpgradeEvent>()
+            .add_systems(OnEnter(GameState::Paused), close_shop)
             .add_systems(Update, (
                 handle_shop_toggle,
+                handle_shop_navigation,
                 update_shop_ui,
-            ).run_if(in_state(GameState::Playing)));
+                highlight_selected_upgrade,
+                update_shop_tooltip,
+                scroll_shop_menu,
+            ).chain().run_if(in_state(GameState::Playing)));
     }
 }
 
@@ -72,17 +338,39 @@ fn handle_shop_toggle(
     input: Res<PlayerInput>,
     mut shop_state: ResMut<ShopState>,
     mut commands: Commands,
+    shop_menu_q: Query<Entity, With<ShopMenu>>,
     weapon_upgrades: Res<WeaponUpgrades>,
     vehicle_upgrades: Res<VehicleUpgrades>,
+    inventory: Res<WeaponInventory>,
     coins: Res<CoinSystem>,
 ) {
     // Toggle shop with TAB key
-    if input.pause {
-        shop_state.is_open = !shop_state.is_open;
+    if !input.toggle_shop {
+        return;
+    }
 
-        if shop_state.is_open {
-            spawn_shop_menu(&mut commands, &weapon_upgrades, &vehicle_upgrades, &coins);
-        }
+    shop_state.is_open = !shop_state.is_open;
+
+    // Clear any existing overlay first so there is never more than one
+    for entity in shop_menu_q.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    if shop_state.is_open {
+        spawn_shop_menu(&mut commands, &weapon_upgrades, &vehicle_upgrades, &inventory, &coins);
+    }
+}
+
+/// Close the shop when the game is paused so it doesn't sit on top of the menu
+fn close_shop(
+    mut commands: Commands,
+    mut shop_state: ResMut<ShopState>,
+    shop_menu_q: Query<Entity, With<ShopMenu>>,
+) {
+    shop_state.is_open = false;
+
+    for entity in shop_menu_q.iter() {
+        commands.entity(entity).despawn_recursive();
     }
 }
 
@@ -90,6 +378,7 @@ fn spawn_shop_menu(
     commands: &mut Commands,
     weapon_upgrades: &WeaponUpgrades,
     vehicle_upgrades: &VehicleUpgrades,
+    inventory: &WeaponInventory,
     coins: &CoinSystem,
 ) {
     commands.spawn((
@@ -103,141 +392,92 @@ fn spawn_shop_menu(
         },
         BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
     )).with_children(|parent| {
-        // Title
-        parent.spawn((
-            Text::new("SHOP"),
-            TextFont {
-                font_size: 48.0,
-                ..default()
-            },
-            TextColor(Color::srgb(1.0, 0.8, 0.2)),
-            Node {
-                margin: UiRect::bottom(Val::Px(30.0)),
-                ..default()
-            },
-        ));
-
-        // Coins display
-        parent.spawn((
-            Text::new(format!("Coins: {}", coins.total_coins)),
-            TextFont {
-                font_size: 24.0,
-                ..default()
-            },
-            TextColor(Color::srgb(1.0, 0.84, 0.0)),
-            Node {
-                margin: UiRect::bottom(Val::Px(20.0)),
-                ..default()
-            },
-        ));
-
-        // Weapon Upgrades Section
+        // Scrollable column so the list still fits on small windows
         parent.spawn((
-            Text::new("WEAPON UPGRADES"),
-            TextFont {
-                font_size: 20.0,
-                ..default()
-            },
-            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ShopScrollArea,
             Node {
-                margin: UiRect::bottom(Val::Px(10.0)),
-                ..default()
-            },
-        ));
-
-        // Machine Gun Damage
-        let cost = weapon_upgrades.machinegun_damage_level * 100 + 100;
-        parent.spawn((
-            ShopButton,
-            UpgradeButton {
-                upgrade_type: UpgradeType::MachineGunDamage,
-                cost,
-                level: weapon_upgrades.machinegun_damage_level,
-                max_level: 5,
-            },
-            Node {
-                width: Val::Px(400.0),
-                height: Val::Px(40.0),
-                justify_content: JustifyContent::SpaceBetween,
+                flex_direction: FlexDirection::Column,
                 align_items: AlignItems::Center,
+                row_gap: Val::Px(6.0),
+                max_height: Val::Percent(90.0),
+                overflow: Overflow::scroll_y(),
                 ..default()
             },
-            BackgroundColor(Color::srgb(0.2, 0.2, 0.3)),
         )).with_children(|parent| {
+            // Title
             parent.spawn((
-                Text::new(format!("MG Damage [Level {}] - Cost: {}", weapon_upgrades.machinegun_damage_level, cost)),
+                Text::new("SHOP"),
                 TextFont {
-                    font_size: 16.0,
+                    font_size: 48.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.8, 0.2)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(30.0)),
                     ..default()
                 },
-                TextColor(Color::WHITE),
             ));
-        });
 
-        // Machine Gun Fire Rate
-        let cost = weapon_upgrades.machinegun_fire_rate_level * 120 + 150;
-        parent.spawn((
-            ShopButton,
-            UpgradeButton {
-                upgrade_type: UpgradeType::MachineGunFireRate,
-                cost,
-                level: weapon_upgrades.machinegun_fire_rate_level,
-                max_level: 5,
-            },
-            Node {
-                width: Val::Px(400.0),
-                height: Val::Px(40.0),
-                justify_content: JustifyContent::SpaceBetween,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            BackgroundColor(Color::srgb(0.2, 0.2, 0.3)),
-        )).with_children(|parent| {
+            // Coins display
+            parent.spawn((
+                Text::new(format!("Coins: {}", coins.total_coins)),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.84, 0.0)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            // One header per section, rows numbered across all sections
+            let sections: [(&str, &[UpgradeType]); 3] = [
+                ("WEAPONS", &UpgradeType::UNLOCK),
+                ("WEAPON UPGRADES", &UpgradeType::WEAPON),
+                ("VEHICLE UPGRADES", &UpgradeType::VEHICLE),
+            ];
+
+            let mut index = 0;
+            for (title, upgrades) in sections {
+                spawn_section_header(parent, title);
+                for upgrade_type in upgrades {
+                    let level = upgrade_type.level(weapon_upgrades, vehicle_upgrades, inventory);
+                    spawn_upgrade_button(parent, index, *upgrade_type, level);
+                    index += 1;
+                }
+            }
+
+            // Instructions
             parent.spawn((
-                Text::new(format!("MG Fire Rate [Level {}] - Cost: {}", weapon_upgrades.machinegun_fire_rate_level, cost)),
+                Text::new("Arrows/D-Pad: Select | Enter/A: Buy | Right-click/Backspace/X: Sell (70%) | TAB: Close shop"),
                 TextFont {
                     font_size: 16.0,
                     ..default()
                 },
-                TextColor(Color::WHITE),
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                Node {
+                    margin: UiRect::top(Val::Px(20.0)),
+                    ..default()
+                },
             ));
         });
 
-        // Vehicle Upgrades Section
+        // Tooltip for the hovered/selected row
         parent.spawn((
-            Text::new("VEHICLE UPGRADES"),
-            TextFont {
-                font_size: 20.0,
-                ..default()
-            },
-            TextColor(Color::srgb(0.8, 0.8, 0.8)),
             Node {
-                margin: UiRect::top(Val::Px(20.0)).with_bottom(Val::Px(10.0)),
+                width: Val::Px(280.0),
+                margin: UiRect::left(Val::Px(20.0)),
+                padding: UiRect::all(Val::Px(14.0)),
                 ..default()
             },
-        ));
-
-        // Vehicle Max Health
-        let cost = vehicle_upgrades.max_health_level * 200 + 200;
-        parent.spawn((
-            ShopButton,
-            UpgradeButton {
-                upgrade_type: UpgradeType::VehicleMaxHealth,
-                cost,
-                level: vehicle_upgrades.max_health_level,
-                max_level: 5,
-            },
-            Node {
-                width: Val::Px(400.0),
-                height: Val::Px(40.0),
-                justify_content: JustifyContent::SpaceBetween,
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            BackgroundColor(Color::srgb(0.2, 0.2, 0.3)),
+            BackgroundColor(Color::srgba(0.12, 0.12, 0.2, 0.95)),
+            BorderRadius::all(Val::Px(6.0)),
         )).with_children(|parent| {
             parent.spawn((
-                Text::new(format!("Vehicle Health [Level {}] - Cost: {}", vehicle_upgrades.max_health_level, cost)),
+                ShopTooltipText,
+                Text::new(""),
                 TextFont {
                     font_size: 16.0,
                     ..default()
@@ -245,93 +485,425 @@ fn spawn_shop_menu(
                 TextColor(Color::WHITE),
             ));
         });
+    });
+}
+
+fn spawn_section_header(parent: &mut ChildBuilder, title: &str) {
+    parent.spawn((
+        Text::new(title),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+        Node {
+            margin: UiRect::top(Val::Px(20.0)).with_bottom(Val::Px(10.0)),
+            ..default()
+        },
+    ));
+}
+
+fn spawn_upgrade_button(parent: &mut ChildBuilder, index: usize, upgrade_type: UpgradeType, level: u32) {
+    let cost = upgrade_type.cost(level);
+    let label = if upgrade_type.max_level() == 1 {
+        if level >= 1 {
+            format!("{} - Owned", upgrade_type.label())
+        } else {
+            format!("{} - Cost: {}", upgrade_type.label(), cost)
+        }
+    } else if level >= upgrade_type.max_level() {
+        format!("{} [Level {}] - MAX", upgrade_type.label(), level)
+    } else {
+        format!("{} [Level {}] - Cost: {}", upgrade_type.label(), level, cost)
+    };
 
-        // Instructions
+    parent.spawn((
+        ShopButton,
+        Button,
+        UpgradeButton {
+            index,
+            upgrade_type,
+            cost,
+            level,
+            max_level: upgrade_type.max_level(),
+        },
+        Node {
+            width: Val::Px(400.0),
+            height: Val::Px(40.0),
+            justify_content: JustifyContent::SpaceBetween,
+            align_items: AlignItems::Center,
+            padding: UiRect::horizontal(Val::Px(10.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.2, 0.2, 0.3)),
+    )).with_children(|parent| {
         parent.spawn((
-            Text::new("Press TAB to close shop"),
+            Text::new(label),
             TextFont {
                 font_size: 16.0,
                 ..default()
             },
-            TextColor(Color::srgb(0.6, 0.6, 0.6)),
-            Node {
-                margin: UiRect::top(Val::Px(20.0)),
-                ..default()
-            },
+            TextColor(Color::WHITE),
         ));
     });
 }
 
+fn scroll_shop_menu(
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut scroll_q: Query<&mut ScrollPosition, With<ShopScrollArea>>,
+) {
+    let scroll: f32 = mouse_wheel.read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * 30.0,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum();
+
+    if scroll == 0.0 {
+        return;
+    }
+
+    for mut position in scroll_q.iter_mut() {
+        position.offset_y = (position.offset_y - scroll).max(0.0);
+    }
+}
+
+fn handle_shop_navigation(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    gamepads: Query<&Gamepad>,
+    mut shop_state: ResMut<ShopState>,
+    hover_q: Query<(&Interaction, &UpgradeButton), Changed<Interaction>>,
+    button_q: Query<(&UpgradeButton, &Interaction)>,
+    mut purchase_events: EventWriter<PurchaseUpgradeEvent>,
+    mut sell_events: EventWriter<SellUpgradeEvent>,
+    mut stick_held: Local<bool>,
+) {
+    if !shop_state.is_open {
+        return;
+    }
+
+    let button_count = button_q.iter().count();
+    if button_count == 0 {
+        return;
+    }
+
+    // Mouse hover selects the row so mouse and keyboard stay in sync
+    for (interaction, button) in hover_q.iter() {
+        if *interaction == Interaction::Hovered {
+            shop_state.selected_index = button.index;
+        }
+    }
+
+    let mut up = keyboard.just_pressed(KeyCode::ArrowUp);
+    let mut down = keyboard.just_pressed(KeyCode::ArrowDown);
+    let mut confirm = keyboard.just_pressed(KeyCode::Enter);
+    let mut sell = keyboard.just_pressed(KeyCode::Backspace);
+
+    for gamepad in gamepads.iter() {
+        up |= gamepad.just_pressed(GamepadButton::DPadUp);
+        down |= gamepad.just_pressed(GamepadButton::DPadDown);
+        confirm |= gamepad.just_pressed(GamepadButton::South);
+        sell |= gamepad.just_pressed(GamepadButton::West);
+    }
+
+    // Right click sells the row under the cursor
+    if mouse_button.just_pressed(MouseButton::Right) {
+        if let Some((button, _)) = button_q.iter().find(|(_, interaction)| **interaction == Interaction::Hovered) {
+            sell_events.send(SellUpgradeEvent { upgrade_type: button.upgrade_type });
+        }
+    }
+
+    // Left stick moves one row per push
+    let stick_y = gamepads.iter()
+        .filter_map(|gamepad| gamepad.get(GamepadAxis::LeftStickY))
+        .find(|value| value.abs() > 0.5);
+    match stick_y {
+        Some(value) if !*stick_held => {
+            *stick_held = true;
+            if value > 0.0 { up = true; } else { down = true; }
+        }
+        Some(_) => {}
+        None => *stick_held = false,
+    }
+
+    // Wrap around at both ends
+    if up {
+        shop_state.selected_index = (shop_state.selected_index + button_count - 1) % button_count;
+    }
+    if down {
+        shop_state.selected_index = (shop_state.selected_index + 1) % button_count;
+    }
+    shop_state.selected_index = shop_state.selected_index.min(button_count - 1);
+
+    let selected = button_q.iter().find(|(b, _)| b.index == shop_state.selected_index);
+    if let Some((button, _)) = selected {
+        if confirm {
+            purchase_events.send(PurchaseUpgradeEvent { upgrade_type: button.upgrade_type });
+        }
+        if sell {
+            sell_events.send(SellUpgradeEvent { upgrade_type: button.upgrade_type });
+        }
+    }
+}
+
 fn update_shop_ui(
     mut commands: Commands,
     shop_state: Res<ShopState>,
     shop_menu_q: Query<Entity, With<ShopMenu>>,
-    mut interaction_q: Query<
+    interaction_q: Query<
         (&Interaction, &UpgradeButton),
         (With<ShopButton>, Changed<Interaction>)
     >,
+    mut purchase_events: EventReader<PurchaseUpgradeEvent>,
+    mut sell_events: EventReader<SellUpgradeEvent>,
     mut weapon_upgrades: ResMut<WeaponUpgrades>,
     mut vehicle_upgrades: ResMut<VehicleUpgrades>,
+    mut inventory: ResMut<WeaponInventory>,
     mut coins: ResMut<CoinSystem>,
-    mut vehicle_health: Query<&mut VehicleHealth, With<crate::vehicle::PlayerVehicle>>,
+    mut vehicle_health: Query<(&mut VehicleHealth, &VehicleType), With<crate::vehicle::PlayerVehicle>>,
 ) {
-    // Remove shop menu if closed
     if !shop_state.is_open {
+        purchase_events.clear();
+        sell_events.clear();
+        return;
+    }
+
+    // Button clicks and keyboard/gamepad confirms
+    let clicked = interaction_q.iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, upgrade)| upgrade.upgrade_type);
+    let requested: Vec<UpgradeType> = clicked
+        .chain(purchase_events.read().map(|event| event.upgrade_type))
+        .collect();
+
+    let mut changed = false;
+
+    for upgrade_type in requested {
+        let level = upgrade_type.level(&weapon_upgrades, &vehicle_upgrades, &inventory);
+        let cost = upgrade_type.cost(level);
+
+        if coins.total_coins < cost || level >= upgrade_type.max_level() {
+            continue;
+        }
+
+        // Deduct coins
+        coins.total_coins -= cost;
+        changed = true;
+
+        // Apply upgrade
+        match upgrade_type {
+            UpgradeType::MachineGunDamage => {
+                weapon_upgrades.machinegun_damage_level += 1;
+            }
+            UpgradeType::MachineGunFireRate => {
+                weapon_upgrades.machinegun_fire_rate_level += 1;
+            }
+            UpgradeType::ShotgunDamage => {
+                weapon_upgrades.shotgun_damage_level += 1;
+            }
+            UpgradeType::ShotgunPellets => {
+                weapon_upgrades.shotgun_pellet_level += 1;
+            }
+            UpgradeType::RocketDamage => {
+                weapon_upgrades.rocket_damage_level += 1;
+            }
+            UpgradeType::RocketRadius => {
+                weapon_upgrades.rocket_radius_level += 1;
+            }
+            UpgradeType::VehicleMaxHealth => {
+                vehicle_upgrades.max_health_level += 1;
+                // Also restore some health when upgrading
+                if let Ok((mut health, _)) = vehicle_health.get_single_mut() {
+                    health.max += VEHICLE_HEALTH_PER_LEVEL;
+                    health.current = (health.current + VEHICLE_HEALTH_PER_LEVEL).min(health.max);
+                }
+            }
+            UpgradeType::VehicleSpeed => {
+                vehicle_upgrades.speed_level += 1;
+            }
+            UpgradeType::VehicleAcceleration => {
+                vehicle_upgrades.acceleration_level += 1;
+            }
+            UpgradeType::CoinMagnet => {
+                vehicle_upgrades.coin_magnet_level += 1;
+            }
+            UpgradeType::UnlockShotgun => {
+                inventory.unlock(WeaponType::Shotgun);
+            }
+            UpgradeType::UnlockRocketLauncher => {
+                inventory.unlock(WeaponType::RocketLauncher);
+            }
+            UpgradeType::UnlockIncendiaryAmmo => {
+                inventory.unlock_ammo(AmmoType::Incendiary);
+            }
+            UpgradeType::UnlockCryoAmmo => {
+                inventory.unlock_ammo(AmmoType::Cryo);
+            }
+        }
+    }
+
+    // Sell back the latest level; stats follow the lower level automatically
+    for event in sell_events.read() {
+        let upgrade_type = event.upgrade_type;
+        let level = upgrade_type.level(&weapon_upgrades, &vehicle_upgrades, &inventory);
+
+        // Nothing bought, nothing to refund
+        let Some(refund) = upgrade_type.refund(level) else {
+            continue;
+        };
+
+        coins.total_coins += refund;
+        changed = true;
+
+        match upgrade_type {
+            UpgradeType::MachineGunDamage => {
+                weapon_upgrades.machinegun_damage_level -= 1;
+            }
+            UpgradeType::MachineGunFireRate => {
+                weapon_upgrades.machinegun_fire_rate_level -= 1;
+            }
+            UpgradeType::ShotgunDamage => {
+                weapon_upgrades.shotgun_damage_level -= 1;
+            }
+            UpgradeType::ShotgunPellets => {
+                weapon_upgrades.shotgun_pellet_level -= 1;
+            }
+            UpgradeType::RocketDamage => {
+                weapon_upgrades.rocket_damage_level -= 1;
+            }
+            UpgradeType::RocketRadius => {
+                weapon_upgrades.rocket_radius_level -= 1;
+            }
+            UpgradeType::VehicleMaxHealth => {
+                vehicle_upgrades.max_health_level -= 1;
+                if let Ok((mut health, chassis)) = vehicle_health.get_single_mut() {
+                    health.max = vehicle_upgrades.max_health(*chassis);
+                    health.current = health.current.min(health.max);
+                }
+            }
+            UpgradeType::VehicleSpeed => {
+                vehicle_upgrades.speed_level -= 1;
+            }
+            UpgradeType::VehicleAcceleration => {
+                vehicle_upgrades.acceleration_level -= 1;
+            }
+            UpgradeType::CoinMagnet => {
+                vehicle_upgrades.coin_magnet_level -= 1;
+            }
+            UpgradeType::UnlockShotgun => {
+                inventory.lock(WeaponType::Shotgun);
+            }
+            UpgradeType::UnlockRocketLauncher => {
+                inventory.lock(WeaponType::RocketLauncher);
+            }
+            UpgradeType::UnlockIncendiaryAmmo => {
+                inventory.lock_ammo(AmmoType::Incendiary);
+            }
+            UpgradeType::UnlockCryoAmmo => {
+                inventory.lock_ammo(AmmoType::Cryo);
+            }
+        }
+    }
+
+    // Respawn shop menu to show updated costs
+    if changed {
         for entity in shop_menu_q.iter() {
             commands.entity(entity).despawn_recursive();
         }
+        spawn_shop_menu(&mut commands, &weapon_upgrades, &vehicle_upgrades, &inventory, &coins);
+    }
+}
+
+/// Color rows by whether they can be bought, brighter for the selected one
+fn highlight_selected_upgrade(
+    shop_state: Res<ShopState>,
+    coins: Res<CoinSystem>,
+    mut button_q: Query<(&UpgradeButton, &mut BackgroundColor, &Children)>,
+    mut text_q: Query<&mut TextColor>,
+) {
+    for (button, mut bg_color, children) in button_q.iter_mut() {
+        let selected = button.index == shop_state.selected_index;
+        let maxed = button.level >= button.max_level;
+        let affordable = coins.total_coins >= button.cost;
+
+        let (background, text) = if maxed {
+            // Nothing left to buy
+            (if selected { Color::srgb(0.3, 0.3, 0.3) } else { Color::srgb(0.18, 0.18, 0.18) }, Color::srgb(0.55, 0.55, 0.55))
+        } else if affordable {
+            (if selected { Color::srgb(0.3, 0.55, 0.35) } else { Color::srgb(0.18, 0.35, 0.22) }, Color::WHITE)
+        } else {
+            (if selected { Color::srgb(0.45, 0.2, 0.2) } else { Color::srgb(0.3, 0.12, 0.12) }, Color::srgb(0.85, 0.6, 0.6))
+        };
+
+        bg_color.0 = background;
+        for child in children.iter() {
+            if let Ok(mut color) = text_q.get_mut(*child) {
+                color.0 = text;
+            }
+        }
+    }
+}
+
+fn update_shop_tooltip(
+    shop_state: Res<ShopState>,
+    coins: Res<CoinSystem>,
+    weapon_upgrades: Res<WeaponUpgrades>,
+    vehicle_upgrades: Res<VehicleUpgrades>,
+    inventory: Res<WeaponInventory>,
+    button_q: Query<&UpgradeButton>,
+    mut tooltip_q: Query<&mut Text, With<ShopTooltipText>>,
+) {
+    if !shop_state.is_open {
         return;
     }
+    let Some(button) = button_q.iter().find(|b| b.index == shop_state.selected_index) else {
+        return;
+    };
 
-    // Handle button clicks
-    for (interaction, upgrade) in interaction_q.iter_mut() {
-        if *interaction == Interaction::Pressed {
-            if coins.total_coins >= upgrade.cost && upgrade.level < upgrade.max_level {
-                // Deduct coins
-                coins.total_coins -= upgrade.cost;
-
-                // Apply upgrade
-                match upgrade.upgrade_type {
-                    UpgradeType::MachineGunDamage => {
-                        weapon_upgrades.machinegun_damage_level += 1;
-                    }
-                    UpgradeType::MachineGunFireRate => {
-                        weapon_upgrades.machinegun_fire_rate_level += 1;
-                    }
-                    UpgradeType::ShotgunDamage => {
-                        weapon_upgrades.shotgun_damage_level += 1;
-                    }
-                    UpgradeType::ShotgunPellets => {
-                        weapon_upgrades.shotgun_pellet_level += 1;
-                    }
-                    UpgradeType::RocketDamage => {
-                        weapon_upgrades.rocket_damage_level += 1;
-                    }
-                    UpgradeType::RocketRadius => {
-                        weapon_upgrades.rocket_radius_level += 1;
-                    }
-                    UpgradeType::VehicleMaxHealth => {
-                        vehicle_upgrades.max_health_level += 1;
-                        // Also restore some health when upgrading
-                        if let Ok(mut health) = vehicle_health.get_single_mut() {
-                            health.max += 20.0;
-                            health.current = (health.current + 20.0).min(health.max);
-                        }
-                    }
-                    UpgradeType::VehicleSpeed => {
-                        vehicle_upgrades.speed_level += 1;
-                    }
-                    UpgradeType::VehicleAcceleration => {
-                        vehicle_upgrades.acceleration_level += 1;
-                    }
-                }
+    let upgrade_type = button.upgrade_type;
+    let level = upgrade_type.level(&weapon_upgrades, &vehicle_upgrades, &inventory);
+    let max_level = upgrade_type.max_level();
+    let mut lines = vec![upgrade_type.label().to_string()];
 
-                // Respawn shop menu to show updated costs
-                for entity in shop_menu_q.iter() {
-                    commands.entity(entity).despawn_recursive();
-                }
-                spawn_shop_menu(&mut commands, &weapon_upgrades, &vehicle_upgrades, &coins);
+    if level >= max_level {
+        lines.push(if max_level == 1 { "Owned".to_string() } else { format!("Level {} (MAX)", level) });
+        if let Some((name, value)) = upgrade_type.stat_at(level) {
+            lines.push(format!("{} {}", name, format_stat(value)));
+        }
+    } else {
+        if max_level > 1 {
+            lines.push(format!("Level {} \u{2192} {}", level, level + 1));
+        }
+        match (upgrade_type.stat_at(level), upgrade_type.stat_at(level + 1)) {
+            (Some((name, now)), Some((_, next))) => {
+                lines.push(format!("{} {} \u{2192} {}", name, format_stat(now), format_stat(next)));
             }
+            _ => lines.push("Adds it to your arsenal".to_string()),
         }
+
+        let cost = upgrade_type.cost(level);
+        lines.push(if coins.total_coins >= cost {
+            format!("Cost {} - can afford", cost)
+        } else {
+            format!("Cost {} - need {} more", cost, cost - coins.total_coins)
+        });
+    }
+
+    if let Some(refund) = upgrade_type.refund(level) {
+        lines.push(format!("Sells back for {}", refund));
+    }
+
+    for mut text in tooltip_q.iter_mut() {
+        text.0 = lines.join("\n");
+    }
+}
+
+/// Whole numbers without decimals, everything else to one place
+fn format_stat(value: f32) -> String {
+    if (value - value.round()).abs() < 0.05 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1}", value)
     }
 }
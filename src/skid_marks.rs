@@ -0,0 +1,162 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use crate::pause::GameState;
+use crate::vehicle::{PlayerVehicle, VehicleType, VehicleVelocity};
+use crate::terrain::TerrainSeed;
+use crate::effects::FadeMaterials;
+
+/// Oldest marks are removed once this many exist
+const MAX_SKID_MARKS: usize = 600;
+const SKID_MARK_LIFETIME: f32 = 8.0;
+/// Distance driven between two mark segments
+const SKID_SEGMENT_LENGTH: f32 = 0.8;
+const SKID_MARK_WIDTH: f32 = 0.3;
+/// Faint tracks left by plain driving
+const TRACK_BASE_ALPHA: f32 = 0.12;
+/// Darkest a mark gets, in a full slide
+const SKID_MAX_ALPHA: f32 = 0.8;
+/// Below this speed the wheels leave no tracks
+const TRACK_MIN_SPEED: f32 = 1.0;
+/// Just above the ground, between scorch marks and footprints
+const SKID_MARK_LIFT: f32 = 0.015;
+
+/// Where the last track segment ended, added to the vehicle the first time it moves
+#[derive(Component)]
+pub struct SkidTrail {
+    pub last_position: Vec3,
+}
+
+#[derive(Component)]
+pub struct SkidMark {
+    pub lifetime: Timer,
+    pub alpha: f32,
+}
+
+/// Spawn order of live skid marks, used to despawn the oldest first
+#[derive(Resource, Default)]
+pub struct SkidMarkRegistry {
+    pub marks: VecDeque<Entity>,
+}
+
+#[derive(Resource)]
+struct SkidMarkAssets {
+    mesh: Handle<Mesh>,
+    materials: FadeMaterials,
+}
+
+pub struct SkidMarkPlugin;
+
+impl Plugin for SkidMarkPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SkidMarkRegistry>()
+            .add_systems(Startup, setup_skid_mark_assets)
+            .add_systems(Update, (
+                spawn_skid_marks,
+                update_skid_marks,
+            ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn setup_skid_mark_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(SkidMarkAssets {
+        mesh: meshes.add(Rectangle::new(1.0, 1.0)),
+        materials: FadeMaterials::new(&mut materials, Color::srgb(0.05, 0.05, 0.05), SKID_MAX_ALPHA),
+    });
+}
+
+fn spawn_skid_marks(
+    mut commands: Commands,
+    skid_assets: Res<SkidMarkAssets>,
+    mut registry: ResMut<SkidMarkRegistry>,
+    terrain: Res<TerrainSeed>,
+    mut vehicle_q: Query<(Entity, &Transform, &VehicleVelocity, &VehicleType, Option<&mut SkidTrail>), With<PlayerVehicle>>,
+) {
+    let Ok((entity, transform, velocity, chassis, trail)) = vehicle_q.get_single_mut() else {
+        return;
+    };
+
+    let Some(mut trail) = trail else {
+        commands.entity(entity).insert(SkidTrail { last_position: transform.translation });
+        return;
+    };
+
+    let moved = transform.translation.xz().distance(trail.last_position.xz());
+    if moved < SKID_SEGMENT_LENGTH {
+        return;
+    }
+    let travel = (transform.translation - trail.last_position).with_y(0.0);
+    trail.last_position = transform.translation;
+
+    if velocity.current.abs() < TRACK_MIN_SPEED {
+        return;
+    }
+
+    // Faint tracks normally, darker through hard turns and much darker in a slide
+    let slide = (velocity.lateral.abs() / 10.0).min(1.0);
+    let turn = (velocity.turn_rate.abs() / velocity.turn_speed.max(f32::EPSILON)).min(1.0)
+        * (velocity.current.abs() / velocity.max_speed.max(f32::EPSILON)).min(1.0);
+    let alpha = (TRACK_BASE_ALPHA + turn * 0.2 + slide * 0.6).min(SKID_MAX_ALPHA);
+    let (yaw, _, _) = transform.rotation.to_euler(EulerRot::YXZ);
+
+    // Rear wheels only
+    for wheel in chassis.wheel_positions().into_iter().filter(|wheel| wheel.z > 0.0) {
+        // Segment spans from the previous wheel position up to the current one
+        let wheel_pos = transform.transform_point(wheel) - travel * 0.5;
+        let ground = terrain.ground_height(wheel_pos.x, wheel_pos.z);
+
+        let mark = commands.spawn((
+            SkidMark {
+                lifetime: Timer::from_seconds(SKID_MARK_LIFETIME, TimerMode::Once),
+                alpha,
+            },
+            Mesh3d(skid_assets.mesh.clone()),
+            MeshMaterial3d(skid_assets.materials.at(alpha)),
+            // Rectangle faces +Z: lay it flat, then point its length along the heading
+            Transform::from_xyz(wheel_pos.x, ground + SKID_MARK_LIFT, wheel_pos.z)
+                .with_rotation(Quat::from_rotation_y(yaw) * Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+                .with_scale(Vec3::new(SKID_MARK_WIDTH, moved, 1.0)),
+        )).id();
+
+        registry.marks.push_back(mark);
+    }
+
+    // Keep the total bounded, oldest first
+    while registry.marks.len() > MAX_SKID_MARKS {
+        if let Some(oldest) = registry.marks.pop_front() {
+            if let Some(mut entity_commands) = commands.get_entity(oldest) {
+                entity_commands.despawn();
+            }
+        }
+    }
+}
+
+fn update_skid_marks(
+    time: Res<Time>,
+    mut commands: Commands,
+    skid_assets: Res<SkidMarkAssets>,
+    mut registry: ResMut<SkidMarkRegistry>,
+    mut mark_q: Query<(Entity, &mut SkidMark, &mut MeshMaterial3d<StandardMaterial>)>,
+) {
+    for (entity, mut mark, mut material) in mark_q.iter_mut() {
+        mark.lifetime.tick(time.delta());
+
+        if mark.lifetime.finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // Fade out over the lifetime
+        let alpha = mark.alpha * (1.0 - mark.lifetime.fraction());
+        let faded = skid_assets.materials.at(alpha);
+        if material.0 != faded {
+            material.0 = faded;
+        }
+    }
+
+    // Drop registry entries for marks that expired on their own
+    registry.marks.retain(|entity| mark_q.contains(*entity));
+}
@@ -0,0 +1,161 @@
+use bevy::prelude::*;
+use crate::dino::{update_dino_ai, update_dino_movement, AIState, BodyPart, DinoAI};
+use crate::pause::GameState;
+use crate::weapon::BulletHitEvent;
+
+/// Seconds between burn damage hits, so popups and hit sounds don't fire every frame
+const BURN_TICK: f32 = 0.5;
+
+/// Effect a hit leaves on a dino, carried by `BulletHitEvent::status`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StatusEffect {
+    /// Damage per second, dealt in `BURN_TICK` hits
+    Burn { dps: f32, seconds: f32 },
+    /// Movement speed multiplier, 0..1
+    Slow { factor: f32, seconds: f32 },
+    /// Stands the dino still in `AIState::Idle`
+    Stun { seconds: f32 },
+}
+
+#[derive(Debug)]
+pub struct Burn {
+    pub dps: f32,
+    pub remaining: Timer,
+    pub tick: Timer,
+}
+
+#[derive(Debug)]
+pub struct Slow {
+    pub factor: f32,
+    pub remaining: Timer,
+}
+
+/// Active effects on a dino, at most one of each kind.
+/// Reapplying refreshes the duration to the longer of the two and keeps the stronger
+/// strength (hottest burn, slowest slow); nothing stacks.
+#[derive(Component, Default, Debug)]
+pub struct StatusEffects {
+    pub burn: Option<Burn>,
+    pub slow: Option<Slow>,
+    pub stun: Option<Timer>,
+}
+
+impl StatusEffects {
+    pub fn apply(&mut self, effect: StatusEffect) {
+        match effect {
+            StatusEffect::Burn { dps, seconds } => {
+                // Keep the running tick, so re-igniting doesn't delay the next burn hit
+                let tick = self.burn.as_ref().map_or_else(
+                    || Timer::from_seconds(BURN_TICK, TimerMode::Repeating),
+                    |burn| burn.tick.clone(),
+                );
+                let remaining = refreshed(self.burn.as_ref().map(|burn| &burn.remaining), seconds);
+                let dps = self.burn.as_ref().map_or(dps, |burn| burn.dps.max(dps));
+                self.burn = Some(Burn { dps, remaining, tick });
+            }
+            StatusEffect::Slow { factor, seconds } => {
+                let remaining = refreshed(self.slow.as_ref().map(|slow| &slow.remaining), seconds);
+                let factor = self.slow.as_ref().map_or(factor, |slow| slow.factor.min(factor));
+                self.slow = Some(Slow { factor: factor.clamp(0.0, 1.0), remaining });
+            }
+            StatusEffect::Stun { seconds } => {
+                self.stun = Some(refreshed(self.stun.as_ref(), seconds));
+            }
+        }
+    }
+
+    pub fn is_stunned(&self) -> bool {
+        self.stun.is_some()
+    }
+
+    /// Multiplier for the dino's move speed
+    pub fn speed_factor(&self) -> f32 {
+        if self.is_stunned() {
+            0.0
+        } else {
+            self.slow.as_ref().map_or(1.0, |slow| slow.factor)
+        }
+    }
+}
+
+/// Timer for whichever lasts longer: what's left of `current` or a fresh `seconds`
+fn refreshed(current: Option<&Timer>, seconds: f32) -> Timer {
+    let left = current.map_or(0.0, Timer::remaining_secs);
+    Timer::from_seconds(seconds.max(left), TimerMode::Once)
+}
+
+pub struct StatusEffectsPlugin;
+
+impl Plugin for StatusEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+            apply_hit_effects,
+            // Stuns override whatever the AI decided this frame, before anything moves
+            tick_status_effects.after(update_dino_ai).before(update_dino_movement),
+        ).run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn apply_hit_effects(
+    mut events: EventReader<BulletHitEvent>,
+    mut dino_q: Query<&mut StatusEffects>,
+) {
+    for event in events.read() {
+        let Some(effect) = event.status else {
+            continue;
+        };
+        if let Ok(mut effects) = dino_q.get_mut(event.target) {
+            effects.apply(effect);
+        }
+    }
+}
+
+fn tick_status_effects(
+    time: Res<Time>,
+    mut dino_q: Query<(Entity, &mut StatusEffects, &mut DinoAI, &Transform)>,
+    mut hit_events: EventWriter<BulletHitEvent>,
+) {
+    for (entity, mut effects, mut ai, transform) in dino_q.iter_mut() {
+        if ai.state == AIState::Dead {
+            *effects = StatusEffects::default();
+            continue;
+        }
+
+        // Burn damage goes through the regular hit pipeline, so kills score as usual
+        if let Some(burn) = effects.burn.as_mut() {
+            burn.remaining.tick(time.delta());
+            burn.tick.tick(time.delta());
+            if burn.tick.just_finished() {
+                hit_events.send(BulletHitEvent {
+                    target: entity,
+                    damage: burn.dps * BURN_TICK,
+                    position: transform.translation,
+                    hit_part: BodyPart::Body,
+                    status: None,
+                });
+            }
+            if burn.remaining.finished() {
+                effects.burn = None;
+            }
+        }
+
+        if let Some(slow) = effects.slow.as_mut() {
+            slow.remaining.tick(time.delta());
+            if slow.remaining.finished() {
+                effects.slow = None;
+            }
+        }
+
+        if let Some(stun) = effects.stun.as_mut() {
+            stun.tick(time.delta());
+            if stun.finished() {
+                effects.stun = None;
+                if ai.state == AIState::Idle {
+                    ai.state = AIState::Roam;
+                }
+            } else {
+                ai.state = AIState::Idle;
+            }
+        }
+    }
+}
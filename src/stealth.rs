@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+use crate::pause::GameState;
+use crate::vehicle::{PlayerVehicle, VehicleVelocity};
+use crate::weapon::WeaponFiredEvent;
+use crate::weapon_system::WeaponType;
+
+/// Share of the noise level that comes from driving at full speed
+const SPEED_NOISE_WEIGHT: f32 = 0.6;
+/// Shot noise lost per second
+const SHOT_NOISE_DECAY: f32 = 0.4;
+/// Detection ranges are scaled between these as noise goes from 0 to 1
+const MIN_DETECTION_SCALE: f32 = 0.4;
+const MAX_DETECTION_SCALE: f32 = 1.5;
+
+/// How much attention the player is drawing, 0.0 = silent, 1.0 = as loud as it gets
+#[derive(Resource, Default)]
+pub struct PlayerNoise {
+    pub noise_level: f32,
+    pub shot_noise: f32, // Decaying noise from recent gunfire
+}
+
+impl PlayerNoise {
+    /// Add a burst of noise from firing a weapon
+    pub fn add_burst(&mut self, amount: f32) {
+        self.shot_noise = (self.shot_noise + amount).min(1.0);
+    }
+
+    /// Multiplier for dino flee/attack trigger distances
+    pub fn detection_scale(&self) -> f32 {
+        MIN_DETECTION_SCALE + (MAX_DETECTION_SCALE - MIN_DETECTION_SCALE) * self.noise_level
+    }
+}
+
+pub struct StealthPlugin;
+
+impl Plugin for StealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerNoise>()
+            .add_systems(Update, update_player_noise.run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn update_player_noise(
+    time: Res<Time>,
+    mut noise: ResMut<PlayerNoise>,
+    mut fired_events: EventReader<WeaponFiredEvent>,
+    vehicle_q: Query<&VehicleVelocity, With<PlayerVehicle>>,
+) {
+    noise.shot_noise = (noise.shot_noise - SHOT_NOISE_DECAY * time.delta_secs()).max(0.0);
+
+    for event in fired_events.read() {
+        let burst = match event.weapon {
+            WeaponType::MachineGun => 0.15,
+            WeaponType::Shotgun => 0.4,
+            WeaponType::RocketLauncher => 0.6,
+        };
+        noise.add_burst(burst);
+    }
+
+    let speed_noise = vehicle_q.get_single()
+        .map(|velocity| (velocity.current.abs() / velocity.max_speed).min(1.0) * SPEED_NOISE_WEIGHT)
+        .unwrap_or(0.0);
+
+    noise.noise_level = (speed_noise + noise.shot_noise).min(1.0);
+}
@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy_rapier3d::prelude::*;
+
+/// Height of the ground where the terrain is flat (the old ground plane)
+pub const GROUND_LEVEL: f32 = -0.5;
+/// Half size of the terrain square, matching the old ground plane
+pub const TERRAIN_HALF_SIZE: f32 = 500.0;
+/// Grid cells per side of the terrain mesh
+const TERRAIN_RESOLUTION: usize = 160;
+/// Peak height of the hills above or below ground level
+const HILL_AMPLITUDE: f32 = 6.0;
+/// World units per noise cell of the lowest octave
+const HILL_WAVELENGTH: f32 = 70.0;
+/// The spawn area stays flat out to this radius, then blends into hills
+const FLAT_RADIUS: f32 = 25.0;
+const BLEND_RADIUS: f32 = 60.0;
+
+/// Seed for the procedural heightmap; the same seed always builds the same hills
+#[derive(Resource, Clone, Copy)]
+pub struct TerrainSeed(pub u32);
+
+impl Default for TerrainSeed {
+    fn default() -> Self {
+        Self(1337)
+    }
+}
+
+impl TerrainSeed {
+    /// World height of the ground surface at an XZ position, for placing anything on the terrain
+    pub fn ground_height(&self, x: f32, z: f32) -> f32 {
+        let mut height = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0 / HILL_WAVELENGTH;
+        for octave in 0..3 {
+            let noise = value_noise(self.0.wrapping_add(octave * 7919), x * frequency, z * frequency);
+            height += (noise * 2.0 - 1.0) * amplitude;
+            amplitude *= 0.45;
+            frequency *= 2.1;
+        }
+
+        // Flatten the middle of the map so spawning stays on level ground
+        let distance = Vec2::new(x, z).length();
+        let blend = ((distance - FLAT_RADIUS) / (BLEND_RADIUS - FLAT_RADIUS)).clamp(0.0, 1.0);
+        let blend = blend * blend * (3.0 - 2.0 * blend);
+
+        GROUND_LEVEL + height * HILL_AMPLITUDE * blend
+    }
+
+    /// Build the render mesh and a matching trimesh collider for the whole terrain
+    pub fn build(&self) -> (Mesh, Collider) {
+        let row = TERRAIN_RESOLUTION + 1;
+        let step = TERRAIN_HALF_SIZE * 2.0 / TERRAIN_RESOLUTION as f32;
+
+        let mut positions = Vec::with_capacity(row * row);
+        let mut normals = Vec::with_capacity(row * row);
+        let mut uvs = Vec::with_capacity(row * row);
+        for iz in 0..row {
+            for ix in 0..row {
+                let x = -TERRAIN_HALF_SIZE + ix as f32 * step;
+                let z = -TERRAIN_HALF_SIZE + iz as f32 * step;
+                positions.push([x, self.ground_height(x, z), z]);
+
+                // Normal from central differences of the height function
+                let dx = self.ground_height(x + step, z) - self.ground_height(x - step, z);
+                let dz = self.ground_height(x, z + step) - self.ground_height(x, z - step);
+                normals.push(Vec3::new(-dx, 2.0 * step, -dz).normalize().to_array());
+
+                uvs.push([ix as f32 / TERRAIN_RESOLUTION as f32, iz as f32 / TERRAIN_RESOLUTION as f32]);
+            }
+        }
+
+        let mut triangles = Vec::with_capacity(TERRAIN_RESOLUTION * TERRAIN_RESOLUTION * 2);
+        for iz in 0..TERRAIN_RESOLUTION {
+            for ix in 0..TERRAIN_RESOLUTION {
+                let i = (iz * row + ix) as u32;
+                let below = i + row as u32;
+                // Counter-clockwise seen from above
+                triangles.push([i, below, i + 1]);
+                triangles.push([i + 1, below, below + 1]);
+            }
+        }
+
+        let vertices = positions.iter().map(|&p| Vec3::from_array(p)).collect();
+        let collider = Collider::trimesh(vertices, triangles.clone()).unwrap();
+
+        let mesh = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::RENDER_WORLD)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+            .with_inserted_indices(Indices::U32(triangles.into_iter().flatten().collect()));
+
+        (mesh, collider)
+    }
+}
+
+/// Hash a lattice point to a pseudo-random value in [0, 1)
+fn lattice_value(seed: u32, ix: i32, iz: i32) -> f32 {
+    let mut h = seed
+        ^ (ix as u32).wrapping_mul(0x27d4_eb2d)
+        ^ (iz as u32).wrapping_mul(0x1656_67b1);
+    h = (h ^ (h >> 15)).wrapping_mul(0x85eb_ca6b);
+    h = (h ^ (h >> 13)).wrapping_mul(0xc2b2_ae35);
+    h ^= h >> 16;
+    (h & 0x00ff_ffff) as f32 / 0x0100_0000 as f32
+}
+
+/// Smoothly interpolated value noise in [0, 1)
+fn value_noise(seed: u32, x: f32, z: f32) -> f32 {
+    let (x0, z0) = (x.floor(), z.floor());
+    let (fx, fz) = (x - x0, z - z0);
+    let (ix, iz) = (x0 as i32, z0 as i32);
+
+    // Smoothstep weights avoid creases along cell edges
+    let sx = fx * fx * (3.0 - 2.0 * fx);
+    let sz = fz * fz * (3.0 - 2.0 * fz);
+
+    let top = lattice_value(seed, ix, iz) * (1.0 - sx) + lattice_value(seed, ix + 1, iz) * sx;
+    let bottom = lattice_value(seed, ix, iz + 1) * (1.0 - sx) + lattice_value(seed, ix + 1, iz + 1) * sx;
+    top * (1.0 - sz) + bottom * sz
+}
@@ -0,0 +1,210 @@
+use bevy::prelude::*;
+use crate::pause::GameState;
+use crate::dino::{CoinSystem, DinoDeath, DinoSpecies, Dinosaur};
+use crate::input::PlayerInput;
+use crate::kill_feed::KillFeedEvent;
+use crate::terrain::TerrainSeed;
+use crate::vehicle::{PlayerVehicle, WeaponTurret};
+
+/// Furthest carcass the tether can reach
+const TETHER_RANGE: f32 = 25.0;
+/// Carcasses must be roughly where the turret points, within this angle of its facing
+const TETHER_AIM_ANGLE: f32 = 0.7;
+/// Rope never gets shorter than this, so the carcass trails behind instead of under the vehicle
+const MIN_ROPE_LENGTH: f32 = 6.0;
+/// Where carcasses are turned in for coins
+pub const DROP_OFF_POSITION: Vec2 = Vec2::new(70.0, -60.0);
+pub const DROP_OFF_RADIUS: f32 = 8.0;
+/// Delivery pays the species' kill coins again, times this
+const DELIVERY_COIN_MULTIPLIER: u32 = 2;
+
+/// The one active tether, if any
+#[derive(Resource, Default)]
+pub struct TowState {
+    pub carcass: Option<Entity>,
+    pub rope_length: f32,
+}
+
+/// On a dead dino being dragged; its death animation holds until it is delivered or released
+#[derive(Component)]
+pub struct Towed;
+
+#[derive(Component)]
+pub struct TetherRope;
+
+#[derive(Component)]
+pub struct DropOffZone;
+
+pub struct TowPlugin;
+
+impl Plugin for TowPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TowState>()
+            .add_systems(Startup, setup_tow)
+            .add_systems(Update, (
+                handle_tether_input,
+                drag_carcass,
+                deliver_carcass,
+                update_tether_rope,
+            ).chain().run_if(in_state(GameState::Playing)));
+    }
+}
+
+fn setup_tow(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    terrain: Res<TerrainSeed>,
+) {
+    // Glowing pad marking the drop-off
+    let ground = terrain.ground_height(DROP_OFF_POSITION.x, DROP_OFF_POSITION.y);
+    commands.spawn((
+        DropOffZone,
+        Mesh3d(meshes.add(Cylinder::new(DROP_OFF_RADIUS, 0.2))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgba(0.2, 0.9, 0.4, 0.35),
+            emissive: LinearRgba::rgb(0.1, 0.6, 0.2),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        })),
+        Transform::from_xyz(DROP_OFF_POSITION.x, ground + 0.1, DROP_OFF_POSITION.y),
+    ));
+
+    // Unit-length cable, stretched between turret and carcass each frame
+    commands.spawn((
+        TetherRope,
+        Mesh3d(meshes.add(Cylinder::new(0.06, 1.0))),
+        MeshMaterial3d(materials.add(Color::srgb(0.15, 0.15, 0.15))),
+        Transform::default(),
+        Visibility::Hidden,
+    ));
+}
+
+fn handle_tether_input(
+    mut commands: Commands,
+    input: Res<PlayerInput>,
+    mut tow: ResMut<TowState>,
+    turret_q: Query<&GlobalTransform, With<WeaponTurret>>,
+    carcass_q: Query<(Entity, &Transform), (With<Dinosaur>, With<DinoDeath>)>,
+) {
+    if !input.tether {
+        return;
+    }
+
+    // Second press lets go
+    if let Some(carcass) = tow.carcass.take() {
+        if let Some(mut entity) = commands.get_entity(carcass) {
+            entity.remove::<Towed>();
+        }
+        return;
+    }
+
+    let Ok(turret) = turret_q.get_single() else {
+        return;
+    };
+    let turret_pos = turret.translation();
+    let aim = turret.forward().with_y(0.0).normalize_or_zero();
+
+    // Closest carcass in front of the turret
+    let target = carcass_q
+        .iter()
+        .filter_map(|(entity, transform)| {
+            let offset = (transform.translation - turret_pos).with_y(0.0);
+            let distance = offset.length();
+            let in_cone = offset.normalize_or_zero().dot(aim) > TETHER_AIM_ANGLE.cos();
+            (distance < TETHER_RANGE && in_cone).then_some((entity, distance))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+
+    if let Some((entity, distance)) = target {
+        commands.entity(entity).insert(Towed);
+        tow.carcass = Some(entity);
+        tow.rope_length = distance.max(MIN_ROPE_LENGTH);
+    }
+}
+
+/// Keep the carcass at rope length behind the vehicle.
+/// Dinos and the vehicle are kinematic bodies, so a physics joint between them would do nothing;
+/// the rope is a distance constraint applied to the carcass transform instead.
+fn drag_carcass(
+    mut tow: ResMut<TowState>,
+    vehicle_q: Query<&Transform, With<PlayerVehicle>>,
+    mut carcass_q: Query<&mut Transform, (With<Towed>, Without<PlayerVehicle>)>,
+) {
+    let Some(carcass) = tow.carcass else {
+        return;
+    };
+    let Ok(mut transform) = carcass_q.get_mut(carcass) else {
+        // Carcass is gone (restart); drop the tether
+        tow.carcass = None;
+        return;
+    };
+    let Ok(vehicle) = vehicle_q.get_single() else {
+        return;
+    };
+
+    // Only the horizontal position is pulled; the death animation keeps it on the ground
+    let offset = (transform.translation - vehicle.translation).with_y(0.0);
+    let distance = offset.length();
+    if distance > tow.rope_length {
+        let pulled = vehicle.translation + offset / distance * tow.rope_length;
+        transform.translation.x = pulled.x;
+        transform.translation.z = pulled.z;
+    }
+}
+
+fn deliver_carcass(
+    mut commands: Commands,
+    mut tow: ResMut<TowState>,
+    mut coins: ResMut<CoinSystem>,
+    carcass_q: Query<(&Transform, &DinoSpecies), With<Towed>>,
+    mut kill_feed: EventWriter<KillFeedEvent>,
+) {
+    let Some(carcass) = tow.carcass else {
+        return;
+    };
+    let Ok((transform, species)) = carcass_q.get(carcass) else {
+        return;
+    };
+
+    let position = Vec2::new(transform.translation.x, transform.translation.z);
+    if position.distance(DROP_OFF_POSITION) > DROP_OFF_RADIUS {
+        return;
+    }
+
+    let bonus = species.kill_reward().1 * DELIVERY_COIN_MULTIPLIER;
+    coins.total_coins += bonus;
+    kill_feed.send(KillFeedEvent::new(
+        format!("DELIVERED {} +{} coins", species.name().to_uppercase(), bonus),
+        Color::srgb(0.3, 1.0, 0.5),
+    ));
+
+    commands.entity(carcass).despawn_recursive();
+    tow.carcass = None;
+}
+
+fn update_tether_rope(
+    tow: Res<TowState>,
+    turret_q: Query<&GlobalTransform, With<WeaponTurret>>,
+    carcass_q: Query<&Transform, (With<Towed>, Without<TetherRope>)>,
+    mut rope_q: Query<(&mut Transform, &mut Visibility), With<TetherRope>>,
+) {
+    let ends = tow.carcass
+        .and_then(|carcass| carcass_q.get(carcass).ok())
+        .zip(turret_q.get_single().ok())
+        .map(|(carcass, turret)| (turret.translation(), carcass.translation));
+
+    for (mut transform, mut visibility) in rope_q.iter_mut() {
+        let Some((start, end)) = ends else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        let span = end - start;
+        *visibility = Visibility::Inherited;
+        transform.translation = start + span * 0.5;
+        transform.rotation = Quat::from_rotation_arc(Vec3::Y, span.normalize_or(Vec3::Y));
+        transform.scale = Vec3::new(1.0, span.length(), 1.0);
+    }
+}
@@ -1,9 +1,16 @@
 use bevy::prelude::*;
-use crate::dino::{Dinosaur, DinoHealth, CoinSystem};
+use bevy::window::PrimaryWindow;
+use bevy_rapier3d::prelude::{QueryFilter, ReadDefaultRapierContext};
+use crate::dino::{Alpha, Dinosaur, DinoDeath, DinoHealth, DinoLod, DinoSpecies, CoinSystem};
 use crate::pause::GameState;
-use crate::weapon_system::WeaponInventory;
+use crate::weapon_system::{WeaponInventory, WeaponType};
 use crate::combo::ComboSystem;
-use crate::vehicle::VehicleHealth;
+use crate::vehicle::{Dodge, PlayerVehicle, TurretAim, VehicleHealth, VehicleVelocity, WeaponTurret};
+use crate::input::TargetLock;
+use crate::contract::HuntContract;
+use crate::fuel::Fuel;
+use crate::weapon::AccuracyStats;
+use crate::camera::MainCamera;
 
 pub struct UIPlugin;
 
@@ -16,17 +23,70 @@ pub struct CoinText;
 #[derive(Component)]
 pub struct WeaponText;
 
+#[derive(Component)]
+pub struct AccuracyText;
+
 #[derive(Component)]
 pub struct ComboText;
 
+/// Part of the weapon hint text naming one weapon
+#[derive(Component)]
+pub struct WeaponHintSpan(pub WeaponType);
+
 #[derive(Component)]
 pub struct HealthBar;
 
 #[derive(Component)]
 pub struct HealthBarBackground;
 
+/// Crosshair line, tagged with the axis it stretches along
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum Crosshair {
+    Horizontal,
+    Vertical,
+}
+
+/// Crosshair tint with nothing targeted, over a live dino, and over the boss
+const RETICLE_IDLE_COLOR: Color = Color::WHITE;
+const RETICLE_TARGET_COLOR: Color = Color::srgb(1.0, 0.25, 0.2);
+const RETICLE_BOSS_COLOR: Color = Color::srgb(0.3, 1.0, 0.4);
+/// Dinos further than this down the barrel don't tint the crosshair
+const RETICLE_RANGE: f32 = 120.0;
+
+/// Ring around the crosshair marking where pellets can land, for weapons with spread
 #[derive(Component)]
-pub struct Crosshair;
+pub struct SpreadCircle;
+
+/// One diagonal tick of the hit marker, pointing away from the crosshair center
+#[derive(Component)]
+pub struct HitMarkerTick {
+    pub direction: Vec2, // Screen space, y down
+}
+
+/// Crosshair line node of the given size, centered on its parent anchor
+pub fn crosshair_line_node(size: Vec2) -> Node {
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(-size.x * 0.5),
+        top: Val::Px(-size.y * 0.5),
+        width: Val::Px(size.x),
+        height: Val::Px(size.y),
+        ..default()
+    }
+}
+
+/// Hit marker tick node centered `gap` pixels from the anchor along `direction`
+pub fn hit_marker_node(direction: Vec2, length: f32, gap: f32) -> Node {
+    let center = direction * gap;
+    Node {
+        position_type: PositionType::Absolute,
+        left: Val::Px(center.x - length * 0.5),
+        top: Val::Px(center.y - 1.0),
+        width: Val::Px(length),
+        height: Val::Px(2.0),
+        ..default()
+    }
+}
 
 #[derive(Component)]
 pub struct VehicleHPBar;
@@ -34,6 +94,48 @@ pub struct VehicleHPBar;
 #[derive(Component)]
 pub struct VehicleHPBarBackground;
 
+/// Dodge cooldown bar, full when a dodge is ready
+#[derive(Component)]
+pub struct DodgeMeterFill;
+
+#[derive(Component)]
+pub struct BossHealthBar;
+
+#[derive(Component)]
+pub struct BossHealthFill;
+
+#[derive(Component)]
+pub struct BossHealthText;
+
+const BOSS_BAR_WIDTH: f32 = 500.0;
+
+#[derive(Component)]
+pub struct ComboMeter;
+
+#[derive(Component)]
+pub struct ComboMeterFill;
+
+#[derive(Component)]
+pub struct ContractText;
+
+#[derive(Component)]
+pub struct SpeedText;
+
+#[derive(Component)]
+pub struct CompassText;
+
+/// Tick on the compass strip marking the turret's yaw relative to the vehicle nose
+#[derive(Component)]
+pub struct CompassMarker;
+
+const COMPASS_WIDTH: f32 = 160.0;
+
+#[derive(Component)]
+pub struct FuelGauge;
+
+#[derive(Component)]
+pub struct FuelGaugeFill;
+
 impl Plugin for UIPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, setup_ui)
@@ -42,7 +144,16 @@ impl Plugin for UIPlugin {
                 update_weapon_display,
                 update_combo_display,
                 update_coin_display,
+                update_accuracy_display,
                 update_vehicle_hp_bar,
+                update_dodge_meter,
+                update_boss_health_bar,
+                update_combo_meter,
+                update_contract_display,
+                update_fuel_gauge,
+                update_vehicle_readouts,
+                update_reticle_color,
+                update_spread_circle,
             ).run_if(in_state(GameState::Playing)));
     }
 }
@@ -51,108 +162,454 @@ fn setup_ui(mut commands: Commands) {
     // Score text (top left)
     commands.spawn((
         ScoreText,
-        Text2d::new("Score: 0"),
+        Text::new("Score: 0"),
         TextFont {
             font_size: 28.0,
             ..default()
         },
         TextColor(Color::WHITE),
-        Transform::from_xyz(-420.0, 320.0, 0.0),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            left: Val::Px(20.0),
+            ..default()
+        },
     ));
 
     // Coin text (below score, top left)
     commands.spawn((
         CoinText,
-        Text2d::new("Coins: 0"),
+        Text::new("Coins: 0"),
         TextFont {
             font_size: 24.0,
             ..default()
         },
         TextColor(Color::srgb(1.0, 0.84, 0.0)), // Gold color
-        Transform::from_xyz(-420.0, 285.0, 0.0),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(55.0),
+            left: Val::Px(20.0),
+            ..default()
+        },
     ));
 
-    // Weapon text (top center)
+    // Accuracy text (below coins, top left)
     commands.spawn((
-        WeaponText,
-        Text2d::new("Weapon: Machine Gun"),
+        AccuracyText,
+        Text::new("Accuracy: --"),
         TextFont {
-            font_size: 24.0,
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.75, 0.85, 0.9)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(85.0),
+            left: Val::Px(20.0),
             ..default()
         },
-        TextColor(Color::srgb(0.8, 0.9, 1.0)),
-        Transform::from_xyz(0.0, 320.0, 0.0),
-        TextLayout::new_with_justify(JustifyText::Center),
     ));
 
+    // Weapon text (top center)
+    commands.spawn(Node {
+        position_type: PositionType::Absolute,
+        top: Val::Px(20.0),
+        width: Val::Percent(100.0),
+        justify_content: JustifyContent::Center,
+        ..default()
+    }).with_children(|parent| {
+        parent.spawn((
+            WeaponText,
+            Text::new("Weapon: Machine Gun"),
+            TextFont {
+                font_size: 24.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.8, 0.9, 1.0)),
+        ));
+    });
+
     // Combo text (top right)
     commands.spawn((
         ComboText,
-        Text2d::new(""),
+        Text::new(""),
         TextFont {
             font_size: 36.0,
             ..default()
         },
         TextColor(Color::srgb(1.0, 0.84, 0.0)),
-        Transform::from_xyz(420.0, 320.0, 0.0),
         TextLayout::new_with_justify(JustifyText::Right),
-    ));
-
-    // Weapon switching hint (bottom center)
-    commands.spawn((
-        Text2d::new("[1] Machine Gun   [2] Shotgun   [3] Rocket Launcher   [Scroll] Switch"),
-        TextFont {
-            font_size: 16.0,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            right: Val::Px(20.0),
             ..default()
         },
-        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
-        Transform::from_xyz(0.0, -340.0, 0.0),
-        TextLayout::new_with_justify(JustifyText::Center),
-    ));
-
-    // Crosshair (horizontal line)
-    commands.spawn((
-        Crosshair,
-        Sprite::from_color(Color::WHITE, Vec2::new(20.0, 2.0)),
-        Transform::from_xyz(0.0, 0.0, 0.0),
     ));
 
-    // Crosshair (vertical line)
+    // Weapon switching hint (bottom center), one span per weapon so locked ones can be greyed out
+    commands.spawn(Node {
+        position_type: PositionType::Absolute,
+        bottom: Val::Px(20.0),
+        width: Val::Percent(100.0),
+        justify_content: JustifyContent::Center,
+        ..default()
+    }).with_children(|parent| {
+        parent.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
+        )).with_children(|parent| {
+            for (i, weapon) in WeaponType::ALL.into_iter().enumerate() {
+                parent.spawn((
+                    WeaponHintSpan(weapon),
+                    TextSpan::new(format!("[{}] {}   ", i + 1, weapon.name())),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
+                ));
+            }
+
+            parent.spawn((
+                TextSpan::new("[Scroll] Switch   [T] Ammo"),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
+            ));
+        });
+    });
+
+    // Zero-size anchor at the screen center; crosshair lines and hit marker ticks
+    // are positioned around it by the effects systems
+    commands.spawn(Node {
+        position_type: PositionType::Absolute,
+        left: Val::Percent(50.0),
+        top: Val::Percent(50.0),
+        ..default()
+    }).with_children(|parent| {
+        // Crosshair (horizontal line)
+        parent.spawn((
+            Crosshair::Horizontal,
+            crosshair_line_node(Vec2::new(20.0, 2.0)),
+            BackgroundColor(Color::WHITE),
+        ));
+
+        // Crosshair (vertical line)
+        parent.spawn((
+            Crosshair::Vertical,
+            crosshair_line_node(Vec2::new(2.0, 20.0)),
+            BackgroundColor(Color::WHITE),
+        ));
+
+        // Shotgun spread ring, sized every frame to the current weapon's cone
+        parent.spawn((
+            SpreadCircle,
+            Node {
+                position_type: PositionType::Absolute,
+                border: UiRect::all(Val::Px(1.5)),
+                display: Display::None,
+                ..default()
+            },
+            BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.5)),
+            BorderRadius::MAX,
+        ));
+
+        // Hit marker ticks, hidden until a hit lands
+        for direction in [Vec2::new(1.0, -1.0), Vec2::new(-1.0, -1.0), Vec2::new(-1.0, 1.0), Vec2::new(1.0, 1.0)] {
+            let direction = direction.normalize();
+            parent.spawn((
+                HitMarkerTick { direction },
+                hit_marker_node(direction, 8.0, 14.0),
+                BackgroundColor(Color::NONE),
+                Transform::from_rotation(Quat::from_rotation_z(direction.to_angle())),
+            ));
+        }
+    });
+
+    // Vehicle HP bar (bottom left, above the fuel gauge)
+    commands.spawn(Node {
+        position_type: PositionType::Absolute,
+        bottom: Val::Px(45.0),
+        left: Val::Px(20.0),
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(8.0),
+        ..default()
+    }).with_children(|parent| {
+        parent.spawn((
+            Text::new("HP"),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+        parent.spawn((
+            VehicleHPBarBackground,
+            Node {
+                width: Val::Px(150.0),
+                height: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::BLACK),
+        )).with_children(|bar| {
+            bar.spawn((
+                VehicleHPBar,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.2, 0.8, 0.2)),
+            ));
+        });
+    });
+
+    // Dodge cooldown (bottom left, above the HP bar)
+    commands.spawn(Node {
+        position_type: PositionType::Absolute,
+        bottom: Val::Px(68.0),
+        left: Val::Px(20.0),
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(8.0),
+        ..default()
+    }).with_children(|parent| {
+        parent.spawn((
+            Text::new("DODGE"),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+        parent.spawn((
+            Node {
+                width: Val::Px(150.0),
+                height: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        )).with_children(|bar| {
+            bar.spawn((
+                DodgeMeterFill,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.3, 0.9, 1.0)),
+            ));
+        });
+    });
+
+    // Speedometer and turret compass (bottom right, left of the minimap)
+    commands.spawn(Node {
+        position_type: PositionType::Absolute,
+        bottom: Val::Px(20.0),
+        right: Val::Px(190.0),
+        flex_direction: FlexDirection::Column,
+        align_items: AlignItems::Center,
+        row_gap: Val::Px(4.0),
+        ..default()
+    }).with_children(|parent| {
+        parent.spawn((
+            SpeedText,
+            Text::new("0 km/h"),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgba(1.0, 1.0, 1.0, 0.8)),
+        ));
+
+        // Strip centered on the vehicle's nose, edges are directly behind
+        parent.spawn((
+            Node {
+                width: Val::Px(COMPASS_WIDTH),
+                height: Val::Px(6.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+        )).with_children(|strip| {
+            strip.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(COMPASS_WIDTH * 0.5 - 1.0),
+                    width: Val::Px(2.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.4)),
+            ));
+            strip.spawn((
+                CompassMarker,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(COMPASS_WIDTH * 0.5 - 2.0),
+                    top: Val::Px(-4.0),
+                    width: Val::Px(4.0),
+                    height: Val::Px(14.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(1.0, 0.8, 0.2)),
+            ));
+        });
+
+        parent.spawn((
+            CompassText,
+            Text::new(""),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
+        ));
+    });
+
+    // Fuel gauge (bottom left), only shown when fuel is in play
     commands.spawn((
-        Crosshair,
-        Sprite::from_color(Color::WHITE, Vec2::new(2.0, 20.0)),
-        Transform::from_xyz(0.0, 0.0, 0.0),
-    ));
-
-    // Vehicle HP bar background (bottom left)
+        FuelGauge,
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(20.0),
+            left: Val::Px(20.0),
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.0),
+            display: Display::None,
+            ..default()
+        },
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("FUEL"),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+        parent.spawn((
+            Node {
+                width: Val::Px(150.0),
+                height: Val::Px(10.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+        )).with_children(|bar| {
+            bar.spawn((
+                FuelGaugeFill,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.9, 0.6, 0.1)),
+            ));
+        });
+    });
+
+    // Combo meter (top right, under the combo counter), drains until the combo expires
     commands.spawn((
-        VehicleHPBarBackground,
-        Sprite::from_color(Color::BLACK, Vec2::new(150.0, 12.0)),
-        Transform::from_xyz(-320.0, -300.0, 0.0),
-    ));
-
-    // Vehicle HP bar (bottom left)
+        ComboMeter,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(70.0),
+            right: Val::Px(20.0),
+            width: Val::Px(150.0),
+            height: Val::Px(8.0),
+            display: Display::None,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.6)),
+    )).with_children(|parent| {
+        parent.spawn((
+            ComboMeterFill,
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            BackgroundColor(Color::WHITE),
+        ));
+    });
+
+    // Active hunt contract (top left, under the coin counter)
     commands.spawn((
-        VehicleHPBar,
-        Sprite::from_color(Color::srgb(0.2, 0.8, 0.2), Vec2::new(150.0, 10.0)),
-        Transform::from_xyz(-320.0, -300.0, 0.01),
+        ContractText,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(110.0),
+            left: Val::Px(20.0),
+            ..default()
+        },
+        Text::new(""),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.4, 1.0, 0.5)),
     ));
 
-    // Vehicle HP text
+    // Boss health bar (top center, hidden until a T-Rex is alive)
     commands.spawn((
-        Text2d::new("HP"),
-        TextFont {
-            font_size: 16.0,
+        BossHealthBar,
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(60.0),
+            left: Val::Percent(50.0),
+            margin: UiRect::left(Val::Px(-BOSS_BAR_WIDTH / 2.0)),
+            width: Val::Px(BOSS_BAR_WIDTH),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.0),
+            display: Display::None,
             ..default()
         },
-        TextColor(Color::WHITE),
-        Transform::from_xyz(-405.0, -300.0, 0.0),
-    ));
+    )).with_children(|parent| {
+        parent.spawn((
+            BossHealthText,
+            Text::new(""),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.4, 0.3)),
+        ));
+
+        // Bar background
+        parent.spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Px(16.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            BorderColor(Color::srgb(0.6, 0.1, 0.1)),
+        )).with_children(|parent| {
+            // Bar fill
+            parent.spawn((
+                BossHealthFill,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.8, 0.1, 0.1)),
+            ));
+        });
+    });
 }
 
 fn update_health_bars(
     mut commands: Commands,
-    dino_q: Query<(Entity, &DinoHealth, &GlobalTransform), With<Dinosaur>>,
+    dino_q: Query<(Entity, &DinoHealth, &GlobalTransform, &DinoLod), With<Dinosaur>>,
     health_bar_bg_q: Query<(Entity, &Parent), With<HealthBarBackground>>,
     health_bar_q: Query<(Entity, &Parent), (With<HealthBar>, Without<HealthBarBackground>)>,
 ) {
@@ -163,8 +620,9 @@ fn update_health_bars(
         .collect();
 
     // Spawn health bars for dinosaurs that don't have them yet
-    for (entity, health, global_transform) in dino_q.iter() {
-        if dinos_with_bars.contains(&entity) {
+    for (entity, health, global_transform, lod) in dino_q.iter() {
+        // Distant dinos get their bar once they come into detail range
+        if dinos_with_bars.contains(&entity) || !lod.detailed {
             continue;
         }
 
@@ -198,10 +656,24 @@ fn update_health_bars(
 fn update_weapon_display(
     weapon_inv: Res<WeaponInventory>,
     mut weapon_text: Query<&mut Text, With<WeaponText>>,
+    mut hint_spans: Query<(&WeaponHintSpan, &mut TextColor)>,
 ) {
     for mut text in weapon_text.iter_mut() {
         let stats = weapon_inv.get_current_stats();
-        text.0 = format!("Weapon: {}", stats.name);
+        text.0 = if stats.weapon_type == WeaponType::MachineGun {
+            format!("Weapon: {} ({} ammo)", stats.name, weapon_inv.current_ammo.name())
+        } else {
+            format!("Weapon: {}", stats.name)
+        };
+    }
+
+    // Grey out weapons that haven't been bought yet
+    for (span, mut color) in hint_spans.iter_mut() {
+        color.0 = if weapon_inv.is_unlocked(span.0) {
+            Color::srgba(1.0, 1.0, 1.0, 0.7)
+        } else {
+            Color::srgba(0.5, 0.5, 0.5, 0.4)
+        };
     }
 }
 
@@ -228,19 +700,36 @@ fn update_coin_display(
     }
 }
 
+fn update_accuracy_display(
+    accuracy: Res<AccuracyStats>,
+    mut accuracy_text: Query<&mut Text, With<AccuracyText>>,
+) {
+    if !accuracy.is_changed() {
+        return;
+    }
+
+    for mut text in accuracy_text.iter_mut() {
+        text.0 = if accuracy.shots_fired == 0 {
+            "Accuracy: --".to_string()
+        } else {
+            format!("Accuracy: {:.0}% ({}/{})", accuracy.percent(), accuracy.shots_hit, accuracy.shots_fired)
+        };
+    }
+}
+
 fn update_vehicle_hp_bar(
     vehicle_health: Query<&VehicleHealth, With<crate::vehicle::PlayerVehicle>>,
-    mut hp_bar: Query<&mut Sprite, With<VehicleHPBar>>,
+    mut hp_bar: Query<(&mut Node, &mut BackgroundColor), With<VehicleHPBar>>,
 ) {
     if let Ok(health) = vehicle_health.get_single() {
         let health_percent = health.current / health.max;
 
         // Update bar width and color
-        for mut sprite in hp_bar.iter_mut() {
-            sprite.custom_size = Some(Vec2::new(150.0 * health_percent, 10.0));
+        for (mut node, mut color) in hp_bar.iter_mut() {
+            node.width = Val::Percent(health_percent * 100.0);
 
             // Change color based on health
-            sprite.color = if health_percent < 0.3 {
+            color.0 = if health_percent < 0.3 {
                 Color::srgb(0.8, 0.2, 0.2) // Red
             } else if health_percent < 0.6 {
                 Color::srgb(0.8, 0.8, 0.2) // Yellow
@@ -250,3 +739,248 @@ fn update_vehicle_hp_bar(
         }
     }
 }
+
+/// Shows the nearest living T-Rex, or alpha if no T-Rex is around
+fn update_boss_health_bar(
+    boss_q: Query<(&DinoHealth, &DinoSpecies, &GlobalTransform, Has<Alpha>), With<Dinosaur>>,
+    vehicle_q: Query<&GlobalTransform, With<crate::vehicle::PlayerVehicle>>,
+    mut bar_q: Query<&mut Node, (With<BossHealthBar>, Without<BossHealthFill>)>,
+    mut fill_q: Query<&mut Node, (With<BossHealthFill>, Without<BossHealthBar>)>,
+    mut text_q: Query<&mut Text, With<BossHealthText>>,
+) {
+    let vehicle_pos = vehicle_q.get_single().map(|t| t.translation()).unwrap_or(Vec3::ZERO);
+
+    // Track the nearest living T-Rex, falling back to the nearest alpha
+    let nearest = |want_trex: bool| boss_q.iter()
+        .filter(|(health, species, _, alpha)| {
+            health.current > 0.0 && if want_trex { **species == DinoSpecies::TRex } else { *alpha }
+        })
+        .min_by(|a, b| {
+            let dist_a = a.2.translation().distance_squared(vehicle_pos);
+            let dist_b = b.2.translation().distance_squared(vehicle_pos);
+            dist_a.total_cmp(&dist_b)
+        })
+        .map(|(health, species, _, alpha)| (health, species, alpha));
+    let nearest_boss = nearest(true).or_else(|| nearest(false));
+
+    let Ok(mut bar_node) = bar_q.get_single_mut() else {
+        return;
+    };
+
+    let Some((health, species, alpha)) = nearest_boss else {
+        bar_node.display = Display::None;
+        return;
+    };
+
+    bar_node.display = Display::Flex;
+
+    let health_percent = (health.current / health.max).clamp(0.0, 1.0);
+    for mut fill in fill_q.iter_mut() {
+        fill.width = Val::Percent(health_percent * 100.0);
+    }
+
+    for mut text in text_q.iter_mut() {
+        let prefix = if alpha { "ALPHA " } else { "" };
+        text.0 = format!(
+            "{}{}  {:.0} / {:.0}",
+            prefix,
+            species.name().to_uppercase(),
+            health.current.max(0.0),
+            health.max,
+        );
+    }
+}
+
+fn update_combo_meter(
+    combo: Res<ComboSystem>,
+    mut meter_q: Query<&mut Node, (With<ComboMeter>, Without<ComboMeterFill>)>,
+    mut fill_q: Query<(&mut Node, &mut BackgroundColor), (With<ComboMeterFill>, Without<ComboMeter>)>,
+) {
+    // Hidden together with the combo text
+    let visible = combo.current_combo >= 2;
+    for mut node in meter_q.iter_mut() {
+        node.display = if visible { Display::Flex } else { Display::None };
+    }
+
+    if !visible {
+        return;
+    }
+
+    let remaining = 1.0 - combo.combo_timer.fraction();
+    for (mut node, mut color) in fill_q.iter_mut() {
+        node.width = Val::Percent(remaining * 100.0);
+        color.0 = combo.current_tier().color();
+    }
+}
+
+fn update_contract_display(
+    contract: Res<HuntContract>,
+    mut contract_text: Query<&mut Text, With<ContractText>>,
+) {
+    for mut text in contract_text.iter_mut() {
+        text.0 = format!(
+            "Contract: {} ({}/{})  Reward: {}",
+            contract.description(),
+            contract.progress,
+            contract.template.count,
+            contract.template.reward,
+        );
+    }
+}
+
+fn update_fuel_gauge(
+    fuel: Res<Fuel>,
+    mut gauge_q: Query<&mut Node, (With<FuelGauge>, Without<FuelGaugeFill>)>,
+    mut fill_q: Query<(&mut Node, &mut BackgroundColor), (With<FuelGaugeFill>, Without<FuelGauge>)>,
+) {
+    for mut node in gauge_q.iter_mut() {
+        node.display = if fuel.active { Display::Flex } else { Display::None };
+    }
+
+    if !fuel.active {
+        return;
+    }
+
+    let fraction = fuel.fraction();
+    for (mut node, mut color) in fill_q.iter_mut() {
+        node.width = Val::Percent(fraction * 100.0);
+        color.0 = if fraction < 0.2 {
+            Color::srgb(0.9, 0.2, 0.1)
+        } else {
+            Color::srgb(0.9, 0.6, 0.1)
+        };
+    }
+}
+
+fn update_dodge_meter(
+    dodge_q: Query<&Dodge, With<PlayerVehicle>>,
+    mut fill_q: Query<(&mut Node, &mut BackgroundColor), With<DodgeMeterFill>>,
+) {
+    let Ok(dodge) = dodge_q.get_single() else {
+        return;
+    };
+
+    let fraction = dodge.cooldown.fraction();
+    let ready = dodge.cooldown.finished();
+    for (mut node, mut color) in fill_q.iter_mut() {
+        node.width = Val::Percent(fraction * 100.0);
+        color.0 = if ready {
+            Color::srgb(0.3, 0.9, 1.0)
+        } else {
+            Color::srgb(0.3, 0.4, 0.5)
+        };
+    }
+}
+
+fn update_vehicle_readouts(
+    vehicle_q: Query<(&Transform, &VehicleVelocity), With<PlayerVehicle>>,
+    turret_q: Query<&TurretAim>,
+    mut speed_text: Query<&mut Text, (With<SpeedText>, Without<CompassText>)>,
+    mut compass_text: Query<&mut Text, (With<CompassText>, Without<SpeedText>)>,
+    mut marker_q: Query<&mut Node, With<CompassMarker>>,
+) {
+    let Ok((vehicle_transform, velocity)) = vehicle_q.get_single() else {
+        return;
+    };
+
+    // World units are roughly meters
+    for mut text in speed_text.iter_mut() {
+        text.0 = format!("{:.0} km/h", velocity.current.abs() * 3.6);
+    }
+
+    let Ok(aim) = turret_q.get_single() else {
+        return;
+    };
+
+    // Relative yaw wrapped to -PI..PI, left is positive
+    let relative = (aim.yaw + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI;
+    let offset = -relative / std::f32::consts::PI * COMPASS_WIDTH * 0.5;
+    for mut node in marker_q.iter_mut() {
+        node.left = Val::Px(COMPASS_WIDTH * 0.5 - 2.0 + offset);
+    }
+
+    // World heading, clockwise from north (-Z)
+    let direction = vehicle_transform.rotation * aim.rotation() * Vec3::NEG_Z;
+    let heading = direction.x.atan2(-direction.z).to_degrees().rem_euclid(360.0);
+    for mut text in compass_text.iter_mut() {
+        text.0 = format!("Aim {} {:03.0}\u{b0}", compass_point(heading), heading);
+    }
+}
+
+fn compass_point(heading: f32) -> &'static str {
+    const POINTS: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+    POINTS[((heading + 22.5) / 45.0) as usize % 8]
+}
+
+/// Hover state for the crosshair: tinted while the locked target or the barrel is on a live dino
+fn update_reticle_color(
+    rapier_context: ReadDefaultRapierContext,
+    target_lock: Res<TargetLock>,
+    turret_q: Query<&GlobalTransform, With<WeaponTurret>>,
+    vehicle_q: Query<Entity, With<PlayerVehicle>>,
+    dino_q: Query<(&GlobalTransform, &DinoSpecies), (With<Dinosaur>, Without<DinoDeath>)>,
+    mut crosshair_q: Query<&mut BackgroundColor, With<Crosshair>>,
+) {
+    let Ok(turret) = turret_q.get_single() else {
+        return;
+    };
+    let origin = turret.translation();
+
+    // A live locked dino in range wins, otherwise whatever the barrel points at
+    let locked = target_lock.locked_entity
+        .and_then(|entity| dino_q.get(entity).ok())
+        .filter(|(transform, _)| transform.translation().distance(origin) < RETICLE_RANGE)
+        .map(|(_, species)| *species);
+
+    let hovered = locked.or_else(|| {
+        // Rocks and logs are in the filter too, so a dino behind cover doesn't count
+        let mut filter = QueryFilter::new().exclude_sensors();
+        if let Ok(vehicle) = vehicle_q.get_single() {
+            filter = filter.exclude_rigid_body(vehicle);
+        }
+        rapier_context
+            .cast_ray(origin, *turret.forward(), RETICLE_RANGE, true, filter)
+            .and_then(|(entity, _)| dino_q.get(entity).ok())
+            .map(|(_, species)| *species)
+    });
+
+    let color = match hovered {
+        Some(DinoSpecies::TRex) => RETICLE_BOSS_COLOR,
+        Some(_) => RETICLE_TARGET_COLOR,
+        None => RETICLE_IDLE_COLOR,
+    };
+
+    for mut background in crosshair_q.iter_mut() {
+        if background.0 != color {
+            background.0 = color;
+        }
+    }
+}
+
+/// Size the spread ring to the pellet cone's max angle as the camera sees it
+fn update_spread_circle(
+    weapon_inv: Res<WeaponInventory>,
+    window_q: Query<&Window, With<PrimaryWindow>>,
+    camera_q: Query<&Projection, With<MainCamera>>,
+    mut circle_q: Query<&mut Node, With<SpreadCircle>>,
+) {
+    let (Ok(window), Ok(Projection::Perspective(perspective))) = (window_q.get_single(), camera_q.get_single()) else {
+        return;
+    };
+
+    let spread = weapon_inv.current_weapon.spread();
+    // Half the cone against half the vertical field of view, in logical pixels
+    let radius = (spread * 0.5).tan() / (perspective.fov * 0.5).tan() * window.height() * 0.5;
+
+    for mut node in circle_q.iter_mut() {
+        if spread <= 0.0 {
+            node.display = Display::None;
+            continue;
+        }
+        node.display = Display::Flex;
+        node.left = Val::Px(-radius);
+        node.top = Val::Px(-radius);
+        node.width = Val::Px(radius * 2.0);
+        node.height = Val::Px(radius * 2.0);
+    }
+}
@@ -1,15 +1,23 @@
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
-use bevy::math::Mat3;
-use crate::input::{PlayerInput, TargetLock};
-use crate::dino::Dinosaur;
+use crate::input::{MouseSettings, PlayerInput, TargetLock};
+use crate::dino::{AIState, DinoAI, DinoAttackEvent, DinoDeath, DinoHealth, Dinosaur};
 use crate::camera::MainCamera;
+use crate::pause::{GameState, RestartGameEvent};
+use crate::game_mode::TimeAttackMode;
+use crate::fuel::Fuel;
+use crate::environment::Obstacle;
+use crate::terrain::{TerrainSeed, GROUND_LEVEL, TERRAIN_HALF_SIZE};
 
 #[derive(Event)]
 pub struct SpeedModifierEvent {
     pub multiplier: f32,
 }
 
+/// Put the vehicle on the selected chassis without restarting the hunt
+#[derive(Event)]
+pub struct SwapChassisEvent;
+
 #[derive(Resource, Default)]
 pub struct SpeedModifier {
     pub current_multiplier: f32,
@@ -17,6 +25,9 @@ pub struct SpeedModifier {
 
 pub struct VehiclePlugin;
 
+/// Where the player vehicle starts, and returns to on restart
+pub const VEHICLE_SPAWN_POSITION: Vec3 = Vec3::new(0.0, 1.0, 0.0);
+
 #[derive(Component)]
 pub struct PlayerVehicle;
 
@@ -27,32 +38,257 @@ pub struct VehicleVelocity {
     pub acceleration: f32,
     pub deceleration: f32,
     pub turn_speed: f32,
+    pub turn_rate: f32, // Current yaw speed, eases toward the steering input
+    pub lateral: f32, // Sideways speed (positive = right), only sizable while drifting
+    pub drift: f32, // 0.0 = full grip, 1.0 = handbrake fully on
+}
+
+/// Sideways speed lost per second with full grip and with the handbrake held
+const GRIP_NORMAL: f32 = 12.0;
+const GRIP_HANDBRAKE: f32 = 1.2;
+/// How fast grip is lost and regained when the handbrake changes
+const DRIFT_BLEND_RATE: f32 = 4.0;
+/// Extra turn speed while sliding
+const DRIFT_TURN_BOOST: f32 = 0.8;
+/// Below this speed the handbrake just brakes
+const DRIFT_MIN_SPEED: f32 = 8.0;
+
+/// Shape of the throttle response, tunable at runtime
+#[derive(Resource)]
+pub struct AccelerationCurve {
+    pub launch_factor: f32, // Share of full acceleration from a standstill
+    pub peak_fraction: f32, // Speed fraction where acceleration is strongest
+    pub taper_exponent: f32, // Higher keeps pulling hard until closer to max speed
+    pub min_factor: f32, // Acceleration left just below max speed, so max is still reached
+    pub steering_response: f32, // How quickly the yaw rate follows the steering input
+}
+
+impl Default for AccelerationCurve {
+    fn default() -> Self {
+        Self {
+            launch_factor: 0.4,
+            peak_fraction: 0.3,
+            taper_exponent: 2.0,
+            min_factor: 0.15,
+            steering_response: 6.0,
+        }
+    }
+}
+
+impl AccelerationCurve {
+    /// Acceleration multiplier at a given fraction of max speed
+    pub fn factor(&self, speed_fraction: f32) -> f32 {
+        let fraction = speed_fraction.clamp(0.0, 1.0);
+        if fraction < self.peak_fraction {
+            // Ease in from the launch factor up to full pull
+            let t = fraction / self.peak_fraction;
+            self.launch_factor + (1.0 - self.launch_factor) * t * (2.0 - t)
+        } else {
+            // Taper off toward max speed
+            let t = (fraction - self.peak_fraction) / (1.0 - self.peak_fraction).max(f32::EPSILON);
+            (1.0 - t.powf(self.taper_exponent)).max(self.min_factor)
+        }
+    }
 }
 
 #[derive(Component)]
 pub struct VehicleHealth {
     pub current: f32,
     pub max: f32,
+    pub last_damaged: f32, // Elapsed seconds when the vehicle last took damage
 }
 
 impl Default for VehicleHealth {
     fn default() -> Self {
-        Self { current: 100.0, max: 100.0 }
+        Self { current: 100.0, max: 100.0, last_damaged: 0.0 }
+    }
+}
+
+/// Passive vehicle repair once out of combat
+#[derive(Resource)]
+pub struct HealthRegenConfig {
+    pub rate: f32, // HP per second
+    pub delay: f32, // Seconds without damage before regen starts
+    pub enabled_in_time_attack: bool,
+}
+
+impl Default for HealthRegenConfig {
+    fn default() -> Self {
+        Self {
+            rate: 2.0,
+            delay: 5.0,
+            enabled_in_time_attack: false,
+        }
     }
 }
 
 impl Plugin for VehiclePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<SpeedModifier>()
+            .init_resource::<HealthRegenConfig>()
+            .init_resource::<AccelerationCurve>()
+            .init_resource::<SelectedVehicle>()
             .add_event::<SpeedModifierEvent>()
+            .add_event::<SwapChassisEvent>()
             .add_systems(Startup, spawn_vehicle)
+            // Restart and start events come from the paused menu; restarts re-derive health from the chassis after
+            .add_systems(Update, (
+                apply_selected_vehicle,
+                rescale_health_to_chassis,
+            ).chain().before(crate::save::handle_restart_progress))
             .add_systems(Update, (
                 handle_speed_modifiers,
+                handle_dodge.before(handle_vehicle_movement),
+                receive_knockback.before(handle_vehicle_movement),
                 handle_vehicle_movement,
+                resolve_obstacle_collisions.after(handle_vehicle_movement),
                 rotate_weapon_turret,
                 update_target_lock,
-                update_indicator_position,
-            ));
+                release_lost_target.after(update_target_lock),
+                update_indicator_position.after(release_lost_target),
+                animate_wheels,
+                regenerate_vehicle_health,
+            ).run_if(in_state(GameState::Playing)));
+    }
+}
+
+/// Selectable chassis, picked on the main menu
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum VehicleType {
+    Buggy,
+    #[default]
+    Jeep,
+    Truck,
+}
+
+/// Chassis the next hunt starts with; a restart swaps it onto the vehicle
+#[derive(Resource, Default)]
+pub struct SelectedVehicle(pub VehicleType);
+
+/// Mesh proportions of a chassis, in vehicle space
+struct ChassisLayout {
+    body: Vec3,
+    body_height: f32,
+    body_color: Color,
+    cabin: Vec3,
+    cabin_offset: Vec3,
+    wheel_radius: f32,
+    /// Turret pivot, on the cabin roof
+    mount: Vec3,
+}
+
+impl VehicleType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            VehicleType::Buggy => "Buggy",
+            VehicleType::Jeep => "Jeep",
+            VehicleType::Truck => "Truck",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            VehicleType::Buggy => VehicleType::Jeep,
+            VehicleType::Jeep => VehicleType::Truck,
+            VehicleType::Truck => VehicleType::Buggy,
+        }
+    }
+
+    /// Driving stats before shop upgrades, at a standstill
+    pub fn velocity(&self) -> VehicleVelocity {
+        let (max_speed, acceleration, deceleration, turn_speed) = match self {
+            VehicleType::Buggy => (32.0, 20.0, 12.0, 3.0),
+            VehicleType::Jeep => (25.0, 15.0, 10.0, 2.5),
+            VehicleType::Truck => (19.0, 10.0, 8.0, 1.9),
+        };
+        VehicleVelocity {
+            current: 0.0,
+            max_speed,
+            acceleration,
+            deceleration,
+            turn_speed,
+            turn_rate: 0.0,
+            lateral: 0.0,
+            drift: 0.0,
+        }
+    }
+
+    /// Max health before health upgrades
+    pub fn base_health(&self) -> f32 {
+        match self {
+            VehicleType::Buggy => 70.0,
+            VehicleType::Jeep => 100.0,
+            VehicleType::Truck => 160.0,
+        }
+    }
+
+    fn mass(&self) -> f32 {
+        match self {
+            VehicleType::Buggy => 900.0,
+            VehicleType::Jeep => 1500.0,
+            VehicleType::Truck => 2600.0,
+        }
+    }
+
+    /// Radius of the circle standing in for the vehicle when it's pushed out of obstacles
+    fn footprint_radius(&self) -> f32 {
+        match self {
+            VehicleType::Buggy => 1.3,
+            VehicleType::Jeep => 1.5,
+            VehicleType::Truck => 1.9,
+        }
+    }
+
+    fn collider(&self) -> Collider {
+        match self {
+            VehicleType::Buggy => Collider::cuboid(1.6, 0.8, 3.2),
+            VehicleType::Jeep => Collider::cuboid(2.0, 1.0, 4.0),
+            VehicleType::Truck => Collider::cuboid(2.4, 1.3, 5.2),
+        }
+    }
+
+    /// Wheel centers relative to the vehicle; rear wheels have positive Z
+    pub fn wheel_positions(&self) -> [Vec3; 4] {
+        let (x, z) = match self {
+            VehicleType::Buggy => (1.0, 1.2),
+            VehicleType::Jeep => (1.1, 1.3),
+            VehicleType::Truck => (1.3, 1.8),
+        };
+        [Vec3::new(-x, 0.0, z), Vec3::new(x, 0.0, z), Vec3::new(-x, 0.0, -z), Vec3::new(x, 0.0, -z)]
+    }
+
+    fn layout(&self) -> ChassisLayout {
+        match self {
+            // Low open frame with a roll cage and big wheels
+            VehicleType::Buggy => ChassisLayout {
+                body: Vec3::new(1.6, 0.5, 3.2),
+                body_height: 0.45,
+                body_color: Color::srgb(0.95, 0.6, 0.1),
+                cabin: Vec3::new(1.2, 0.5, 1.2),
+                cabin_offset: Vec3::new(0.0, 0.95, 0.2),
+                wheel_radius: 0.5,
+                mount: Vec3::new(0.0, 1.45, 0.2),
+            },
+            VehicleType::Jeep => ChassisLayout {
+                body: Vec3::new(2.0, 0.8, 4.0),
+                body_height: 0.5,
+                body_color: Color::srgb(0.7, 0.2, 0.15),
+                cabin: Vec3::new(1.8, 0.7, 2.0),
+                cabin_offset: Vec3::new(0.0, 1.2, -0.5),
+                wheel_radius: 0.4,
+                mount: Vec3::new(0.0, 1.8, 0.0),
+            },
+            // Tall cab up front, long flatbed behind
+            VehicleType::Truck => ChassisLayout {
+                body: Vec3::new(2.4, 1.1, 5.2),
+                body_height: 0.75,
+                body_color: Color::srgb(0.25, 0.35, 0.2),
+                cabin: Vec3::new(2.2, 0.9, 1.6),
+                cabin_offset: Vec3::new(0.0, 1.75, -1.6),
+                wheel_radius: 0.5,
+                mount: Vec3::new(0.0, 2.45, -1.6),
+            },
+        }
     }
 }
 
@@ -60,81 +296,211 @@ fn spawn_vehicle(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    selected: Res<SelectedVehicle>,
 ) {
-    let body_color = Color::srgb(0.7, 0.2, 0.15);
-    let cabin_color = Color::srgb(0.9, 0.85, 0.7);
-    let wheel_color = Color::srgb(0.1, 0.1, 0.1);
-    let gun_color = Color::srgb(0.3, 0.3, 0.35);
+    let chassis = selected.0;
+    let health = chassis.base_health();
 
     // Vehicle root entity
-    let vehicle_entity = commands.spawn((
+    commands.spawn((
         PlayerVehicle,
-        Transform::from_xyz(0.0, 1.0, 0.0),
-        VehicleVelocity {
-            current: 0.0,
-            max_speed: 25.0,
-            acceleration: 15.0,
-            deceleration: 10.0,
-            turn_speed: 2.5,
-        },
-        VehicleHealth::default(),
+        chassis,
+        Transform::from_translation(VEHICLE_SPAWN_POSITION),
+        chassis.velocity(),
+        VehicleHealth { current: health, max: health, ..default() },
+        Dodge::default(),
+        Knockback::default(),
         RigidBody::KinematicPositionBased,
-        Collider::cuboid(2.0, 1.0, 4.0),
+        chassis.collider(),
         Friction::new(0.8),
-        AdditionalMassProperties::Mass(1500.0),
-    )).id();
+        AdditionalMassProperties::Mass(chassis.mass()),
+    )).with_children(|parent| spawn_chassis_parts(parent, &mut meshes, &mut materials, chassis));
+}
+
+/// Body, cabin, wheels and the weapon turret of a chassis, as children of the vehicle
+fn spawn_chassis_parts(
+    parent: &mut ChildBuilder,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    chassis: VehicleType,
+) {
+    let layout = chassis.layout();
+    let cabin_color = Color::srgb(0.9, 0.85, 0.7);
+    let wheel_color = Color::srgb(0.1, 0.1, 0.1);
+    let gun_color = Color::srgb(0.3, 0.3, 0.35);
 
     // Vehicle body
-    commands.spawn((
-        Mesh3d(meshes.add(Cuboid::new(2.0, 0.8, 4.0))),
-        MeshMaterial3d(materials.add(body_color)),
-        Transform::from_xyz(0.0, 0.5, 0.0),
-    )).set_parent(vehicle_entity);
+    parent.spawn((
+        Mesh3d(meshes.add(Cuboid::from_size(layout.body))),
+        MeshMaterial3d(materials.add(layout.body_color)),
+        Transform::from_xyz(0.0, layout.body_height, 0.0),
+    ));
 
     // Cabin
-    commands.spawn((
-        Mesh3d(meshes.add(Cuboid::new(1.8, 0.7, 2.0))),
+    parent.spawn((
+        Mesh3d(meshes.add(Cuboid::from_size(layout.cabin))),
         MeshMaterial3d(materials.add(cabin_color)),
-        Transform::from_xyz(0.0, 1.2, -0.5),
-    )).set_parent(vehicle_entity);
+        Transform::from_translation(layout.cabin_offset),
+    ));
 
     // Wheels
-    let wheel_positions = [
-        (-1.1, 0.0, 1.3),
-        (1.1, 0.0, 1.3),
-        (-1.1, 0.0, -1.3),
-        (1.1, 0.0, -1.3),
-    ];
-
-    for pos in wheel_positions {
-        commands.spawn((
-            Mesh3d(meshes.add(Cylinder::new(0.4, 0.3))),
+    for pos in chassis.wheel_positions() {
+        parent.spawn((
+            Wheel { front: pos.z < 0.0, radius: layout.wheel_radius, spin: 0.0, steer: 0.0 },
+            Mesh3d(meshes.add(Cylinder::new(layout.wheel_radius, 0.3))),
             MeshMaterial3d(materials.add(wheel_color)),
-            Transform::from_xyz(pos.0, pos.1, pos.2)
+            Transform::from_translation(pos)
                 .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
-        )).set_parent(vehicle_entity);
+        ));
     }
 
     // Weapon mount base
-    commands.spawn((
+    parent.spawn((
         Mesh3d(meshes.add(Cylinder::new(0.2, 0.3))),
         MeshMaterial3d(materials.add(gun_color)),
-        Transform::from_xyz(0.0, 1.8, 0.0),
-    )).set_parent(vehicle_entity);
+        Transform::from_translation(layout.mount),
+    ));
 
-    // Machine gun barrel (will rotate to face mouse direction)
-    commands.spawn((
+    // Turret pivot (will rotate to face mouse direction, forward is the fire direction)
+    parent.spawn((
         WeaponTurret,
-        Mesh3d(meshes.add(Cylinder::new(0.08, 1.5))),
-        MeshMaterial3d(materials.add(gun_color)),
-        Transform::from_xyz(0.0, 1.9, 0.0)
-            .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
-    )).set_parent(vehicle_entity);
+        TurretAim::default(),
+        Transform::from_translation(layout.mount + Vec3::Y * 0.1),
+        Visibility::default(),
+    )).with_children(|turret| {
+        // Machine gun barrel, laid along the pivot's forward axis
+        turret.spawn((
+            Mesh3d(meshes.add(Cylinder::new(0.08, 1.5))),
+            MeshMaterial3d(materials.add(gun_color)),
+            Transform::from_xyz(0.0, 0.0, -0.75)
+                .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+        ));
+    });
+}
+
+/// Restarts and menu starts put the vehicle on the selected chassis, rebuilding it if the choice changed
+pub fn apply_selected_vehicle(
+    mut commands: Commands,
+    mut restart_events: EventReader<RestartGameEvent>,
+    mut swap_events: EventReader<SwapChassisEvent>,
+    selected: Res<SelectedVehicle>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut vehicle_q: Query<(Entity, &mut VehicleType, &mut VehicleVelocity), With<PlayerVehicle>>,
+) {
+    if restart_events.read().count() + swap_events.read().count() == 0 {
+        return;
+    }
+    let chassis = selected.0;
+    for (entity, mut current, mut velocity) in vehicle_q.iter_mut() {
+        if *current == chassis {
+            continue;
+        }
+        *current = chassis;
+        *velocity = chassis.velocity();
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .insert((chassis.collider(), AdditionalMassProperties::Mass(chassis.mass())))
+            .with_children(|parent| spawn_chassis_parts(parent, &mut meshes, &mut materials, chassis));
+    }
+}
+
+/// A new chassis keeps the same share of health, so swapping is never a free repair
+fn rescale_health_to_chassis(
+    upgrades: Res<crate::shop::VehicleUpgrades>,
+    mut vehicle_q: Query<(&VehicleType, &mut VehicleHealth), Changed<VehicleType>>,
+) {
+    for (chassis, mut health) in vehicle_q.iter_mut() {
+        let fraction = health.current / health.max.max(1.0);
+        health.max = upgrades.max_health(*chassis);
+        health.current = health.max * fraction;
+    }
 }
 
 #[derive(Component)]
 pub struct WeaponTurret;
 
+/// Sideways dodge burst; dino attacks miss while `timer` runs, then `cooldown` gates the next one
+#[derive(Component)]
+pub struct Dodge {
+    pub timer: Timer,
+    pub cooldown: Timer,
+}
+
+impl Default for Dodge {
+    // Both start finished so the first dodge is available right away
+    fn default() -> Self {
+        let mut timer = Timer::from_seconds(DODGE_DURATION, TimerMode::Once);
+        let mut cooldown = Timer::from_seconds(DODGE_COOLDOWN, TimerMode::Once);
+        timer.tick(timer.duration());
+        cooldown.tick(cooldown.duration());
+        Self { timer, cooldown }
+    }
+}
+
+impl Dodge {
+    pub fn is_dodging(&self) -> bool {
+        !self.timer.finished()
+    }
+}
+
+/// Shove from dino bites in world space, bleeding off over a fraction of a second
+#[derive(Component, Default)]
+pub struct Knockback {
+    pub velocity: Vec3,
+}
+
+/// Fraction of knockback speed lost per second
+const KNOCKBACK_DECAY: f32 = 4.0;
+const MAX_KNOCKBACK_SPEED: f32 = 25.0;
+/// Share of forward speed kept through a bite
+const BITE_SPEED_KEPT: f32 = 0.4;
+/// Knockback stops short of this distance from the terrain edge
+const KNOCKBACK_EDGE_MARGIN: f32 = 10.0;
+
+/// Invulnerable window of a dodge
+const DODGE_DURATION: f32 = 0.35;
+const DODGE_COOLDOWN: f32 = 2.5;
+/// Sideways speed kicked into the vehicle; tire grip scrubs it off like any slide
+const DODGE_SPEED: f32 = 22.0;
+
+/// Wheel visual state, front wheels also steer
+#[derive(Component)]
+pub struct Wheel {
+    pub front: bool,
+    pub radius: f32,
+    pub spin: f32,
+    pub steer: f32,
+}
+
+const WHEEL_MAX_STEER: f32 = 0.45;
+/// Steering angle change per second
+const WHEEL_STEER_SPEED: f32 = 4.0;
+
+/// Turret orientation relative to the vehicle, in radians
+#[derive(Component, Default)]
+pub struct TurretAim {
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl TurretAim {
+    pub fn rotation(&self) -> Quat {
+        Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, 0.0)
+    }
+}
+
+/// Radians of turret rotation per (sensitivity-scaled) pixel of mouse motion
+const TURRET_MOUSE_SCALE: f32 = 0.003;
+const TURRET_MIN_PITCH: f32 = -0.35; // About 20 degrees down
+const TURRET_MAX_PITCH: f32 = 1.0;   // About 57 degrees up, enough for a Brachiosaurus head
+/// Aim assist only pulls toward dinos within this angle (radians) of the barrel
+const AIM_ASSIST_CONE: f32 = 0.12;
+const AIM_ASSIST_RANGE: f32 = 120.0;
+/// Fraction of the remaining angle closed per second
+const AIM_ASSIST_STRENGTH: f32 = 3.0;
+
 fn handle_speed_modifiers(
     mut events: EventReader<SpeedModifierEvent>,
     mut modifier: ResMut<SpeedModifier>,
@@ -148,19 +514,48 @@ fn handle_vehicle_movement(
     input: Res<PlayerInput>,
     time: Res<Time>,
     modifier: Res<SpeedModifier>,
-    mut vehicle_q: Query<(&mut Transform, &mut VehicleVelocity), With<PlayerVehicle>>,
+    curve: Res<AccelerationCurve>,
+    mut fuel: ResMut<Fuel>,
+    terrain: Res<TerrainSeed>,
+    upgrades: Res<crate::shop::VehicleUpgrades>,
+    mut vehicle_q: Query<(&mut Transform, &mut VehicleVelocity, &mut Knockback), With<PlayerVehicle>>,
 ) {
-    let Ok((mut transform, mut velocity)) = vehicle_q.get_single_mut() else {
+    let Ok((mut transform, mut velocity, mut knockback)) = vehicle_q.get_single_mut() else {
         return;
     };
 
     let dt = time.delta_secs();
+    let max_speed = velocity.max_speed * upgrades.speed_multiplier() * modifier.current_multiplier;
+    let acceleration = velocity.acceleration * upgrades.acceleration_multiplier();
+    let reverse_max = max_speed * 0.3;
+
+    // Throttle burns fuel, more at speed; an empty tank means coasting
+    let throttle = input.move_forward || input.move_backward;
+    if throttle {
+        let load = 0.3 + 0.7 * (velocity.current.abs() / max_speed.max(f32::EPSILON)).min(1.0);
+        let amount = fuel.drain_rate * load * dt;
+        fuel.drain(amount);
+    }
 
-    // Acceleration
-    if input.move_forward {
-        velocity.current += velocity.acceleration * dt;
+    // Acceleration, shaped by the curve. Braking against the current direction uses full force.
+    if throttle && !fuel.can_accelerate() {
+        // Out of fuel, only rolling resistance applies
+        let drag = velocity.deceleration * dt;
+        velocity.current -= velocity.current.signum() * drag.min(velocity.current.abs());
+    } else if input.move_forward {
+        let factor = if velocity.current >= 0.0 {
+            curve.factor(velocity.current / max_speed.max(f32::EPSILON))
+        } else {
+            1.0
+        };
+        velocity.current += acceleration * factor * dt;
     } else if input.move_backward {
-        velocity.current -= velocity.acceleration * dt;
+        let factor = if velocity.current <= 0.0 {
+            curve.factor(-velocity.current / reverse_max.max(f32::EPSILON))
+        } else {
+            1.0
+        };
+        velocity.current -= acceleration * factor * dt;
     } else {
         // Decelerate when not moving
         if velocity.current > 0.0 {
@@ -172,38 +567,169 @@ fn handle_vehicle_movement(
         }
     }
 
+    // Handbrake: grip fades out while held at speed and comes back gradually on release
+    let target_drift = if input.handbrake && velocity.current.abs() > DRIFT_MIN_SPEED { 1.0 } else { 0.0 };
+    velocity.drift += (target_drift - velocity.drift) * (DRIFT_BLEND_RATE * dt).min(1.0);
+    if input.handbrake {
+        let brake = velocity.deceleration * 0.5 * dt;
+        velocity.current -= velocity.current.signum() * brake.min(velocity.current.abs());
+    }
+
     // Clamp speed (apply speed modifier)
-    let max_speed = velocity.max_speed * modifier.current_multiplier;
-    velocity.current = velocity.current.clamp(-max_speed * 0.3, max_speed);
+    velocity.current = velocity.current.clamp(-reverse_max, max_speed);
 
-    // Turning (only when moving)
+    // Turning (only when moving). The yaw rate eases toward the steering input,
+    // so turns build up and unwind instead of snapping.
+    let mut target_turn_rate = 0.0;
     if velocity.current.abs() > 0.1 {
         let turn_direction = if input.move_backward { -1.0 } else { 1.0 };
+        let turn_speed = velocity.turn_speed * (1.0 + DRIFT_TURN_BOOST * velocity.drift);
         if input.move_left {
-            transform.rotate_y(velocity.turn_speed * dt * turn_direction);
+            target_turn_rate += turn_speed * turn_direction;
         }
         if input.move_right {
-            transform.rotate_y(-velocity.turn_speed * dt * turn_direction);
+            target_turn_rate -= turn_speed * turn_direction;
         }
     }
+    let blend = (curve.steering_response * dt).min(1.0);
+    velocity.turn_rate += (target_turn_rate - velocity.turn_rate) * blend;
+    if velocity.current.abs() > 0.1 {
+        let angle = velocity.turn_rate * dt;
+        transform.rotate_y(angle);
+
+        // Momentum keeps pointing the old way; re-express it in the new heading
+        let (sin, cos) = angle.sin_cos();
+        let (current, lateral) = (velocity.current, velocity.lateral);
+        velocity.current = current * cos - lateral * sin;
+        velocity.lateral = current * sin + lateral * cos;
+    } else {
+        velocity.turn_rate = 0.0;
+    }
+
+    // Tires scrub off sideways speed; less so with the handbrake on
+    let grip = GRIP_NORMAL + (GRIP_HANDBRAKE - GRIP_NORMAL) * velocity.drift;
+    velocity.lateral *= (1.0 - grip * dt).max(0.0);
 
     // Apply velocity
     let forward = transform.forward();
-    transform.translation += forward * velocity.current * dt;
+    let right = transform.right();
+    transform.translation += forward * velocity.current * dt + right * velocity.lateral * dt;
+
+    // Bite knockback rides on top, but never shoves the vehicle off the map
+    let shoved = transform.translation + knockback.velocity * dt;
+    let edge = TERRAIN_HALF_SIZE - KNOCKBACK_EDGE_MARGIN;
+    if shoved.x.abs() < edge && shoved.z.abs() < edge {
+        transform.translation = shoved;
+        knockback.velocity *= (1.0 - KNOCKBACK_DECAY * dt).max(0.0);
+    } else {
+        knockback.velocity = Vec3::ZERO;
+    }
+
+    // Ride on the terrain surface
+    let ground = terrain.ground_height(transform.translation.x, transform.translation.z);
+    transform.translation.y = ground + VEHICLE_SPAWN_POSITION.y - GROUND_LEVEL;
 
     // Reset speed modifier to default after applying
     // This ensures continuous updates from the environment system
 }
 
-fn rotate_weapon_turret(
+/// The vehicle is kinematic and moved by writing its transform, so rapier never stops it.
+/// Push it back out of any obstacle it drove into and drop the speed heading into it.
+/// Footprints come from the obstacle's scale: round for rocks and trunks, a stadium for logs.
+fn resolve_obstacle_collisions(
+    terrain: Res<TerrainSeed>,
+    obstacle_q: Query<&Transform, (With<Obstacle>, Without<PlayerVehicle>)>,
+    mut vehicle_q: Query<(&mut Transform, &mut VehicleVelocity, &VehicleType), With<PlayerVehicle>>,
+) {
+    let Ok((mut transform, mut velocity, chassis)) = vehicle_q.get_single_mut() else {
+        return;
+    };
+
+    for obstacle in obstacle_q.iter() {
+        let half = obstacle.scale.xz() * 0.5;
+        let radius = half.min_element();
+        // Long axis of the footprint, in world XZ
+        let axis = if half.x > half.y { obstacle.right() } else { obstacle.back() };
+        let axis = axis.xz().normalize_or_zero() * (half.max_element() - radius);
+
+        let center = obstacle.translation.xz();
+        let offset = transform.translation.xz() - center;
+        let along = if axis == Vec2::ZERO { 0.0 } else { (offset.dot(axis) / axis.length_squared()).clamp(-1.0, 1.0) };
+        let away = offset - axis * along;
+
+        let reach = radius + chassis.footprint_radius();
+        let distance = away.length();
+        if distance >= reach {
+            continue;
+        }
+        let normal = if distance > f32::EPSILON { away / distance } else { transform.back().xz().normalize_or_zero() };
+        let push = normal * (reach - distance);
+        transform.translation.x += push.x;
+        transform.translation.z += push.y;
+
+        // Keep the sliding part of the motion, lose the part into the obstacle
+        let forward = transform.forward().xz().normalize_or_zero();
+        let right = transform.right().xz().normalize_or_zero();
+        let mut motion = forward * velocity.current + right * velocity.lateral;
+        let into = motion.dot(normal);
+        if into < 0.0 {
+            motion -= normal * into;
+            velocity.current = motion.dot(forward);
+            velocity.lateral = motion.dot(right);
+        }
+    }
+
+    let ground = terrain.ground_height(transform.translation.x, transform.translation.z);
+    transform.translation.y = ground + VEHICLE_SPAWN_POSITION.y - GROUND_LEVEL;
+}
+
+fn receive_knockback(
+    mut events: EventReader<DinoAttackEvent>,
+    mut vehicle_q: Query<(&mut Knockback, &mut VehicleVelocity), With<PlayerVehicle>>,
+) {
+    let Ok((mut knockback, mut velocity)) = vehicle_q.get_single_mut() else {
+        events.clear();
+        return;
+    };
+
+    for event in events.read() {
+        knockback.velocity = (knockback.velocity + event.knockback).clamp_length_max(MAX_KNOCKBACK_SPEED);
+        // A bite kills the run-up
+        velocity.current *= BITE_SPEED_KEPT;
+    }
+}
+
+fn handle_dodge(
     time: Res<Time>,
     input: Res<PlayerInput>,
+    mut vehicle_q: Query<(&mut Dodge, &mut VehicleVelocity), With<PlayerVehicle>>,
+) {
+    for (mut dodge, mut velocity) in vehicle_q.iter_mut() {
+        dodge.timer.tick(time.delta());
+        dodge.cooldown.tick(time.delta());
+
+        if input.dodge == 0.0 || !dodge.cooldown.finished() {
+            continue;
+        }
+
+        velocity.lateral += input.dodge * DODGE_SPEED;
+        dodge.timer.reset();
+        dodge.cooldown.reset();
+    }
+}
+
+/// Runs on real time, so the turret keeps its speed through bullet time
+fn rotate_weapon_turret(
+    time: Res<Time<Real>>,
+    input: Res<PlayerInput>,
     target_lock: Res<TargetLock>,
-    mut turret_q: Query<&mut Transform, (With<WeaponTurret>, Without<PlayerVehicle>)>,
+    mouse_settings: Res<MouseSettings>,
+    mut turret_q: Query<(&mut Transform, &mut TurretAim), (With<WeaponTurret>, Without<PlayerVehicle>)>,
     vehicle_q: Query<&Transform, (With<PlayerVehicle>, Without<WeaponTurret>)>,
     dino_q: Query<&GlobalTransform, With<Dinosaur>>,
+    assist_q: Query<(&GlobalTransform, &DinoHealth), With<Dinosaur>>,
 ) {
-    let Ok(mut turret_transform) = turret_q.get_single_mut() else {
+    let Ok((mut turret_transform, mut aim)) = turret_q.get_single_mut() else {
         return;
     };
 
@@ -214,80 +740,99 @@ fn rotate_weapon_turret(
     let dt = time.delta_secs();
     let turret_rotation_speed = 2.0;
 
-    // Check if we have a locked target
-    if let Some(locked_entity) = target_lock.locked_entity {
-        if let Ok(dino_transform) = dino_q.get(locked_entity) {
-            let turret_pos = vehicle_transform.translation + Vec3::new(0.0, 1.9, 0.0);
-            let target_pos = dino_transform.translation();
-            let direction = (target_pos - turret_pos).normalize();
-
-            if direction.length_squared() > 0.01 {
-                let forward = Vec3::new(direction.x, 0.0, direction.z).normalize();
-                let up = Vec3::Y;
-                let right = forward.cross(up).normalize();
-                let new_up = right.cross(forward).normalize();
-
-                turret_transform.rotation = Quat::from_mat3(&Mat3::from_cols(
-                    right,
-                    new_up,
-                    -forward,
-                ));
-            }
-        }
-    } else if let Some(lock_pos) = target_lock.lock_position {
-        // Use locked position
-        let turret_pos = vehicle_transform.translation + Vec3::new(0.0, 1.9, 0.0);
-        let direction = (lock_pos - turret_pos).normalize();
+    // Aim at the locked dino if it still exists, otherwise at the locked position.
+    // A lock on a dino that is gone falls through to free aim.
+    let lock_target = match target_lock.locked_entity {
+        Some(locked_entity) => dino_q.get(locked_entity).ok().map(|t| t.translation()),
+        None => target_lock.lock_position,
+    };
+
+    if let Some(target_pos) = lock_target {
+        let turret_pos = vehicle_transform.transform_point(turret_transform.translation);
+        let direction = (target_pos - turret_pos).normalize_or_zero();
 
         if direction.length_squared() > 0.01 {
-            let forward = Vec3::new(direction.x, 0.0, direction.z).normalize();
-            let up = Vec3::Y;
-            let right = forward.cross(up).normalize();
-            let new_up = right.cross(forward).normalize();
-
-            turret_transform.rotation = Quat::from_mat3(&Mat3::from_cols(
-                right,
-                new_up,
-                -forward,
-            ));
+            // Turret is parented to the vehicle, so aim in vehicle-local space
+            let local_direction = vehicle_transform.rotation.inverse() * direction;
+            aim.yaw = (-local_direction.x).atan2(-local_direction.z);
+            aim.pitch = local_direction.y.asin().clamp(TURRET_MIN_PITCH, TURRET_MAX_PITCH);
         }
     } else {
-        // Use mouse movement for rotation
+        // Free aim: mouse X drives yaw, mouse Y drives pitch
+        aim.yaw -= input.mouse_delta.x * TURRET_MOUSE_SCALE;
+        aim.pitch -= input.mouse_delta.y * TURRET_MOUSE_SCALE;
+
+        // Q/E as keyboard fallback
         if input.turret_left {
-            turret_transform.rotate_y(turret_rotation_speed * dt);
+            aim.yaw += turret_rotation_speed * dt;
         }
         if input.turret_right {
-            turret_transform.rotate_y(-turret_rotation_speed * dt);
+            aim.yaw -= turret_rotation_speed * dt;
         }
+
+        if mouse_settings.aim_assist {
+            let turret_pos = vehicle_transform.transform_point(turret_transform.translation);
+            let aim_direction = vehicle_transform.rotation * aim.rotation() * Vec3::NEG_Z;
+
+            // Living dino closest to the barrel line, within the assist cone
+            let assist_target = assist_q.iter()
+                .filter(|(_, health)| health.current > 0.0)
+                .map(|(transform, _)| transform.translation() - turret_pos)
+                .filter(|to_dino| to_dino.length() < AIM_ASSIST_RANGE)
+                .map(|to_dino| (to_dino, aim_direction.angle_between(to_dino)))
+                .filter(|(_, angle)| *angle < AIM_ASSIST_CONE)
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+
+            if let Some((to_dino, _)) = assist_target {
+                let local_direction = vehicle_transform.rotation.inverse() * to_dino.normalize();
+                let target_yaw = (-local_direction.x).atan2(-local_direction.z);
+                let target_pitch = local_direction.y.asin();
+
+                // Nudge rather than snap, so the player stays in control
+                let pull = (AIM_ASSIST_STRENGTH * dt).min(1.0);
+                let yaw_error = (target_yaw - aim.yaw + std::f32::consts::PI)
+                    .rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI;
+                aim.yaw += yaw_error * pull;
+                aim.pitch += (target_pitch - aim.pitch) * pull;
+            }
+        }
+
+        aim.pitch = aim.pitch.clamp(TURRET_MIN_PITCH, TURRET_MAX_PITCH);
     }
+
+    turret_transform.rotation = aim.rotation();
 }
 
+/// Dinos further than this from the camera can't be locked; sticky locks let go past it
+const LOCK_RANGE: f32 = 200.0;
+
 fn update_target_lock(
-    mut commands: Commands,
     input: Res<PlayerInput>,
+    mouse_settings: Res<MouseSettings>,
     mut target_lock: ResMut<TargetLock>,
     camera_q: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
-    dino_q: Query<(Entity, &GlobalTransform), With<Dinosaur>>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-    indicator_q: Query<Entity, With<TargetLockIndicator>>,
+    dino_q: Query<(Entity, &GlobalTransform), (With<Dinosaur>, Without<DinoDeath>)>,
+    rapier_context: ReadDefaultRapierContext,
 ) {
-    // Handle target locking when right mouse button is pressed
-    if input.lock_target {
+    // In sticky mode right-click on a held lock lets go of it
+    if input.lock_target && mouse_settings.sticky_lock && target_lock.locked_entity.is_some() {
+        target_lock.locked_entity = None;
+        target_lock.lock_position = None;
+        return;
+    }
+
+    // Handle target locking when right mouse button (or the cycle key) is pressed
+    if input.lock_target || input.cycle_target {
         let Ok((_camera, camera_transform)) = camera_q.get_single() else {
             return;
         };
 
-        // Remove old indicator if exists
-        for indicator_entity in indicator_q.iter() {
-            commands.entity(indicator_entity).despawn_recursive();
-        }
-
         // Camera forward direction and position
         let cam_pos = camera_transform.translation();
         let cam_forward = camera_transform.forward();
 
-        // Get list of visible dinosaurs (in front of camera)
+        // Get list of visible dinosaurs (in front of camera, alive, not behind cover).
+        // Cycling only walks this list, so hidden dinos are skipped too.
         let visible_dinos: Vec<(Entity, Vec3, f32)> = dino_q.iter()
             .filter_map(|(entity, transform)| {
                 let dino_pos = transform.translation();
@@ -298,11 +843,24 @@ fn update_target_lock(
                 let to_dino_norm = to_dino.normalize();
                 let dot = cam_forward.dot(to_dino_norm);
 
-                if dot > 0.3 && distance < 200.0 {
-                    // Dinosaur is in front of camera and within range
-                    return Some((entity, dino_pos, distance));
+                if dot <= 0.3 || distance >= LOCK_RANGE {
+                    return None;
+                }
+
+                // Line of sight: any rock or log between camera and dino blocks the lock
+                let occluded = rapier_context.cast_ray(
+                    cam_pos,
+                    to_dino_norm,
+                    distance - 1.0,
+                    true,
+                    QueryFilter::only_fixed().exclude_sensors(),
+                ).is_some();
+
+                if occluded {
+                    return None;
                 }
-                None
+
+                Some((entity, dino_pos, distance))
             })
             .collect();
 
@@ -317,7 +875,8 @@ fn update_target_lock(
         let mut sorted_dinos = visible_dinos;
         sorted_dinos.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
 
-        // If we already have a lock, cycle to the next visible dinosaur
+        // If we already have a lock, cycle to the next visible dinosaur; sticky right-clicks
+        // only get here without one
         let target_entity = if let Some(current_lock) = target_lock.locked_entity {
             // Find the current lock's index
             if let Some(current_idx) = sorted_dinos.iter().position(|(e, _, _)| *e == current_lock) {
@@ -338,8 +897,54 @@ fn update_target_lock(
         if let Ok((_, transform)) = dino_q.get(target_entity) {
             target_lock.lock_position = Some(transform.translation());
         }
+    }
+}
 
-        // Spawn red circle indicator for the new target
+/// Let go of a lock once its dino dies or despawns, or in sticky mode wanders out of range,
+/// so the turret and indicator don't keep tracking a ghost
+fn release_lost_target(
+    mouse_settings: Res<MouseSettings>,
+    mut target_lock: ResMut<TargetLock>,
+    camera_q: Query<&GlobalTransform, With<MainCamera>>,
+    dino_q: Query<(&GlobalTransform, &DinoAI), With<Dinosaur>>,
+) {
+    let Some(locked_entity) = target_lock.locked_entity else {
+        return;
+    };
+
+    let alive = dino_q.get(locked_entity).is_ok_and(|(_, ai)| ai.state != AIState::Dead);
+    let out_of_range = mouse_settings.sticky_lock
+        && camera_q.get_single().is_ok_and(|camera| {
+            dino_q.get(locked_entity).is_ok_and(|(dino, _)| dino.translation().distance(camera.translation()) > LOCK_RANGE)
+        });
+
+    if !alive || out_of_range {
+        target_lock.locked_entity = None;
+        target_lock.lock_position = None;
+    }
+}
+
+/// The lock indicator is a free-standing entity owned here alone: spawned when a lock appears,
+/// moved onto the locked dino in world space every frame, and despawned the moment the lock
+/// is gone, however it was cleared
+fn update_indicator_position(
+    mut commands: Commands,
+    target_lock: Res<TargetLock>,
+    dino_q: Query<&GlobalTransform, With<Dinosaur>>,
+    mut indicator_q: Query<(Entity, &mut Transform), With<TargetLockIndicator>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let target = target_lock.locked_entity.and_then(|entity| dino_q.get(entity).ok());
+    let Some(dino_transform) = target else {
+        for (entity, _) in indicator_q.iter() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    };
+
+    let position = dino_transform.translation() + Vec3::Y * 0.5;
+    if indicator_q.is_empty() {
         commands.spawn((
             TargetLockIndicator,
             Mesh3d(meshes.add(Torus::new(1.5, 0.1))),
@@ -348,26 +953,70 @@ fn update_target_lock(
                 unlit: true,
                 ..default()
             })),
-            Transform::from_xyz(0.0, 0.5, 0.0),
-        )).set_parent(target_entity);
+            Transform::from_translation(position),
+        ));
+    }
+    for (_, mut transform) in indicator_q.iter_mut() {
+        transform.translation = position;
     }
 }
 
-fn update_indicator_position(
-    target_lock: Res<TargetLock>,
-    dino_q: Query<&GlobalTransform, With<Dinosaur>>,
-    mut indicator_q: Query<&mut Transform, With<TargetLockIndicator>>,
+/// Ring marking the locked dino
+#[derive(Component)]
+pub struct TargetLockIndicator;
+
+fn animate_wheels(
+    time: Res<Time>,
+    input: Res<PlayerInput>,
+    vehicle_q: Query<&VehicleVelocity, With<PlayerVehicle>>,
+    mut wheel_q: Query<(&mut Wheel, &mut Transform)>,
 ) {
-    // Update indicator position
-    if let Some(locked_entity) = target_lock.locked_entity {
-        if let Ok(dino_transform) = dino_q.get(locked_entity) {
-            for mut transform in indicator_q.iter_mut() {
-                let pos = dino_transform.translation();
-                transform.translation = Vec3::new(pos.x, pos.y + 0.5, pos.z);
-            }
+    let Ok(velocity) = vehicle_q.get_single() else {
+        return;
+    };
+
+    let dt = time.delta_secs();
+
+    let mut target_steer = 0.0;
+    if input.move_left {
+        target_steer += WHEEL_MAX_STEER;
+    }
+    if input.move_right {
+        target_steer -= WHEEL_MAX_STEER;
+    }
+
+    for (mut wheel, mut transform) in wheel_q.iter_mut() {
+        // Rolling forward (-Z) turns the wheel backward around its X axle
+        wheel.spin = (wheel.spin - velocity.current / wheel.radius * dt).rem_euclid(std::f32::consts::TAU);
+
+        if wheel.front {
+            let max_step = WHEEL_STEER_SPEED * dt;
+            wheel.steer += (target_steer - wheel.steer).clamp(-max_step, max_step);
         }
+
+        // Cylinder is laid on its side, so spin about X after that, then steer about Y
+        transform.rotation = Quat::from_rotation_y(wheel.steer)
+            * Quat::from_rotation_x(wheel.spin)
+            * Quat::from_rotation_z(std::f32::consts::FRAC_PI_2);
     }
 }
 
-#[derive(Component)]
-struct TargetLockIndicator;
+fn regenerate_vehicle_health(
+    time: Res<Time>,
+    config: Res<HealthRegenConfig>,
+    time_attack: Res<TimeAttackMode>,
+    mut health_q: Query<&mut VehicleHealth, With<PlayerVehicle>>,
+) {
+    if time_attack.is_active && !config.enabled_in_time_attack {
+        return;
+    }
+
+    for mut health in health_q.iter_mut() {
+        // Dead vehicles stay dead, and regen waits until out of combat
+        if health.current <= 0.0 || health.current >= health.max || time.elapsed_secs() - health.last_damaged < config.delay {
+            continue;
+        }
+
+        health.current = (health.current + config.rate * time.delta_secs()).min(health.max);
+    }
+}
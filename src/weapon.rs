@@ -1,10 +1,15 @@
 use bevy::prelude::*;
+use rand::Rng;
 use crate::dino::{BodyPart, HitBox, Dinosaur};
 use crate::vehicle::WeaponTurret;
 use crate::input::TargetLock;
 use crate::pause::GameState;
-use crate::weapon_system::WeaponInventory;
+use crate::rng::GameRng;
+use crate::weapon_system::{AmmoType, WeaponInventory, WeaponType};
 use crate::effects::HitFeedbackEvent;
+use crate::status_effects::StatusEffect;
+use crate::smoothing;
+use crate::environment::{ExplosiveBarrel, BARREL_HIT_RADIUS};
 
 pub struct WeaponPlugin;
 
@@ -14,6 +19,14 @@ pub struct BulletHitEvent {
     pub damage: f32,
     pub position: Vec3,
     pub hit_part: BodyPart,
+    /// Effect the hit leaves behind, see `StatusEffects` for how repeats combine
+    pub status: Option<StatusEffect>,
+}
+
+/// Event fired once per trigger pull (not per pellet)
+#[derive(Event)]
+pub struct WeaponFiredEvent {
+    pub weapon: crate::weapon_system::WeaponType,
 }
 
 #[derive(Resource)]
@@ -34,8 +47,20 @@ pub struct Bullet {
     pub lifetime: Timer,
     pub damage: f32,
     pub weapon_type: crate::weapon_system::WeaponType,
+    /// Dinos this bullet may still pass through before it stops
+    pub penetration: u32,
+    /// Dinos already hit, so a passing bullet doesn't hit the same one every frame
+    pub pierced: Vec<Entity>,
+    /// Decides the status effect each hit leaves
+    pub ammo: AmmoType,
 }
 
+/// Seconds a dino at the center of a blast stands stunned, less further out
+const BLAST_STAGGER_SECONDS: f32 = 1.0;
+
+/// Damage kept for each dino a bullet has already passed through
+const PENETRATION_FALLOFF: f32 = 0.6;
+
 #[derive(Component)]
 struct BulletVelocity {
     vec: Vec3,
@@ -44,8 +69,115 @@ struct BulletVelocity {
 #[derive(Component)]
 pub struct BloodParticle {
     pub lifetime: Timer,
+    pub size: f32, // Radius at spawn, shrinks to zero over the lifetime
 }
 
+/// Shared projectile and particle handles, created once at startup
+#[derive(Resource)]
+pub struct WeaponAssets {
+    pub sphere: Handle<Mesh>, // Unit sphere, sized through Transform scale
+    pub machine_gun_material: Handle<StandardMaterial>,
+    pub shotgun_material: Handle<StandardMaterial>,
+    pub rocket_material: Handle<StandardMaterial>,
+    pub blood_material: Handle<StandardMaterial>,
+    pub explosion_material: Handle<StandardMaterial>,
+}
+
+impl WeaponAssets {
+    pub fn bullet_material(&self, weapon: WeaponType) -> Handle<StandardMaterial> {
+        match weapon {
+            WeaponType::MachineGun => self.machine_gun_material.clone(),
+            WeaponType::Shotgun => self.shotgun_material.clone(),
+            WeaponType::RocketLauncher => self.rocket_material.clone(),
+        }
+    }
+}
+
+/// Recycled bullet and particle entities, hidden while waiting for reuse
+#[derive(Resource, Default)]
+pub struct ProjectilePool {
+    bullets: Vec<Entity>,
+    particles: Vec<Entity>,
+}
+
+impl ProjectilePool {
+    fn spawn_bullet(&mut self, commands: &mut Commands, bundle: impl Bundle) -> Entity {
+        match self.bullets.pop() {
+            Some(entity) => {
+                commands.entity(entity).insert((bundle, Visibility::Inherited));
+                entity
+            }
+            None => commands.spawn(bundle).id(),
+        }
+    }
+
+    fn release_bullet(&mut self, commands: &mut Commands, entity: Entity) {
+        commands.entity(entity)
+            .remove::<(Bullet, Rocket, BulletVelocity)>()
+            .insert(Visibility::Hidden);
+        self.bullets.push(entity);
+    }
+
+    fn spawn_particle(&mut self, commands: &mut Commands, bundle: impl Bundle) -> Entity {
+        match self.particles.pop() {
+            Some(entity) => {
+                commands.entity(entity).insert((bundle, Visibility::Inherited));
+                entity
+            }
+            None => commands.spawn(bundle).id(),
+        }
+    }
+
+    fn release_particle(&mut self, commands: &mut Commands, entity: Entity) {
+        commands.entity(entity)
+            .remove::<(BloodParticle, BulletVelocity)>()
+            .insert(Visibility::Hidden);
+        self.particles.push(entity);
+    }
+}
+
+/// Predicted intercept point for the locked dino.
+/// Every weapon fires travelling projectiles, so all of them lead.
+#[derive(Resource, Default)]
+pub struct LeadTarget {
+    pub entity: Option<Entity>,
+    pub last_position: Vec3,
+    pub velocity: Vec3, // Smoothed, so jittery flee paths average out to a small lead
+    pub predicted: Option<Vec3>,
+}
+
+/// Seconds-based smoothing rate for the lead velocity estimate
+const LEAD_SMOOTHING: f32 = 6.0;
+/// Longest travel time worth leading for
+const MAX_LEAD_TIME: f32 = 1.5;
+
+/// Shots fired vs shots that connected, for the current round
+/// Every shotgun pellet counts as a shot; a rocket counts as a hit if its blast catches any dino
+#[derive(Resource, Default)]
+pub struct AccuracyStats {
+    pub shots_fired: u32,
+    pub shots_hit: u32,
+}
+
+impl AccuracyStats {
+    /// Hit percentage, 0 before the first shot
+    pub fn percent(&self) -> f32 {
+        if self.shots_fired == 0 {
+            0.0
+        } else {
+            self.shots_hit as f32 / self.shots_fired as f32 * 100.0
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Ghost reticle drawn at the predicted intercept point
+#[derive(Component)]
+pub struct LeadIndicator;
+
 /// For rocket delayed explosions
 #[derive(Component)]
 pub struct Rocket {
@@ -57,10 +189,17 @@ pub struct Rocket {
 impl Plugin for WeaponPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<WeaponState>()
+            .init_resource::<ProjectilePool>()
+            .init_resource::<LeadTarget>()
+            .init_resource::<AccuracyStats>()
+            .insert_resource(WeaponInventory::new())
             .add_event::<BulletHitEvent>()
             .add_event::<RocketExplosionEvent>()
+            .add_event::<WeaponFiredEvent>()
             .add_event::<HitFeedbackEvent>()
+            .add_systems(Startup, setup_weapon_assets)
             .add_systems(Update, (
+                update_lead_target,
                 handle_shooting,
                 update_bullets,
                 check_bullet_collisions,
@@ -75,21 +214,117 @@ pub struct RocketExplosionEvent {
     pub position: Vec3,
     pub damage: f32,
     pub radius: f32,
+    /// Set for exploding barrels, which aren't a shot of their own
+    pub environmental: bool,
 }
 
-fn handle_shooting(
+fn setup_weapon_assets(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    commands.insert_resource(WeaponAssets {
+        sphere: meshes.add(Sphere { radius: 1.0 }),
+        machine_gun_material: materials.add(Color::srgb(1.0, 0.8, 0.2)),
+        shotgun_material: materials.add(Color::srgb(0.8, 0.6, 0.3)), // Buckshot color
+        rocket_material: materials.add(Color::srgb(1.0, 0.3, 0.1)),
+        blood_material: materials.add(Color::srgba(0.6, 0.05, 0.05, 0.8)),
+        explosion_material: materials.add(Color::srgba(1.0, 0.5, 0.1, 0.9)),
+    });
+
+    commands.spawn((
+        LeadIndicator,
+        Mesh3d(meshes.add(Sphere { radius: 0.35 })),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgba(1.0, 0.3, 0.3, 0.45),
+            alpha_mode: AlphaMode::Blend,
+            unlit: true,
+            ..default()
+        })),
+        Transform::default(),
+        Visibility::Hidden,
+    ));
+}
+
+/// Track the locked dino's velocity and predict where a shot would meet it
+fn update_lead_target(
     time: Res<Time>,
+    target_lock: Res<TargetLock>,
+    weapon_inv: Res<WeaponInventory>,
+    mut lead: ResMut<LeadTarget>,
+    turret_q: Query<&GlobalTransform, With<WeaponTurret>>,
+    dino_q: Query<&GlobalTransform, With<Dinosaur>>,
+    mut indicator_q: Query<(&mut Transform, &mut Visibility), With<LeadIndicator>>,
+) {
+    let dt = time.delta_secs();
+    let target_pos = target_lock.locked_entity
+        .and_then(|entity| dino_q.get(entity).ok().map(|t| (entity, t.translation())));
+
+    match target_pos {
+        Some((entity, position)) if lead.entity == Some(entity) => {
+            if dt > 0.0 {
+                let frame_velocity = (position - lead.last_position) / dt;
+                let blend = smoothing(LEAD_SMOOTHING, dt);
+                lead.velocity = lead.velocity.lerp(frame_velocity, blend);
+            }
+            lead.last_position = position;
+        }
+        Some((entity, position)) => {
+            // New target, start from rest rather than a bogus jump
+            lead.entity = Some(entity);
+            lead.last_position = position;
+            lead.velocity = Vec3::ZERO;
+        }
+        None => {
+            lead.entity = None;
+        }
+    }
+
+    lead.predicted = match (lead.entity, turret_q.get_single()) {
+        (Some(_), Ok(turret)) => {
+            let turret_pos = turret.translation();
+            let speed = weapon_inv.current_weapon.bullet_speed();
+
+            // Two refinement passes are plenty for dino speeds
+            let mut predicted = lead.last_position;
+            for _ in 0..2 {
+                let travel_time = (turret_pos.distance(predicted) / speed).min(MAX_LEAD_TIME);
+                predicted = lead.last_position + lead.velocity * travel_time;
+            }
+            Some(predicted)
+        }
+        _ => None,
+    };
+
+    for (mut transform, mut visibility) in indicator_q.iter_mut() {
+        match lead.predicted {
+            // Only worth drawing when the lead is noticeable
+            Some(predicted) if predicted.distance(lead.last_position) > 0.5 => {
+                transform.translation = predicted;
+                *visibility = Visibility::Inherited;
+            }
+            _ => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+fn handle_shooting(
+    time: Res<Time<Virtual>>,
     input: Res<crate::input::PlayerInput>,
     mut weapon_state: ResMut<WeaponState>,
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    weapon_assets: Res<WeaponAssets>,
+    mut pool: ResMut<ProjectilePool>,
     turret_q: Query<&GlobalTransform, With<WeaponTurret>>,
-    vehicle_q: Query<&GlobalTransform, (With<crate::vehicle::PlayerVehicle>, Without<WeaponTurret>)>,
+    mut rng: ResMut<GameRng>,
     target_lock: Res<TargetLock>,
     keyboard: Res<ButtonInput<KeyCode>>,
     dino_q: Query<&GlobalTransform, With<Dinosaur>>,
     weapon_inv: Res<WeaponInventory>,
+    lead: Res<LeadTarget>,
+    mut fired_events: EventWriter<WeaponFiredEvent>,
+    mut accuracy: ResMut<AccuracyStats>,
+    upgrades: Res<crate::shop::WeaponUpgrades>,
 ) {
     let current_time = time.elapsed_secs();
 
@@ -102,7 +337,8 @@ fn handle_shooting(
     }
 
     let current_weapon = weapon_inv.current_weapon;
-    let fire_rate = current_weapon.fire_rate();
+    // Scaled with game speed, so bullet time keeps the real-world fire rate
+    let fire_rate = upgrades.fire_interval(current_weapon) * time.relative_speed();
 
     if current_time - weapon_state.last_shot < fire_rate {
         return;
@@ -114,19 +350,15 @@ fn handle_shooting(
         return;
     };
 
-    let Ok(_vehicle_global) = vehicle_q.get_single() else {
-        return;
-    };
-
     // Get world positions
     let turret_pos = turret_global.translation();
 
     // Determine fire direction
     let fire_direction = if shooting_at_lock {
-        // Shooting at locked target - aim directly at it
+        // Shooting at locked target - aim where it will be when the shot arrives
         if let Some(locked_entity) = target_lock.locked_entity {
             if let Ok(dino_global) = dino_q.get(locked_entity) {
-                let target_pos = dino_global.translation();
+                let target_pos = lead.predicted.unwrap_or(dino_global.translation());
                 (target_pos - turret_pos).normalize()
             } else {
                 *turret_global.forward()
@@ -135,80 +367,94 @@ fn handle_shooting(
             *turret_global.forward()
         }
     } else {
-        // Free aim - use turret's facing direction
+        // Free aim - use turret's full 3D facing direction (yaw and pitch)
         *turret_global.forward()
     };
 
-    let base_damage = current_weapon.damage();
-    let pellet_count = current_weapon.pellet_count();
+    let base_damage = upgrades.damage(current_weapon);
+    let pellet_count = upgrades.pellet_count(current_weapon);
     let spread = current_weapon.spread();
     let bullet_speed = current_weapon.bullet_speed();
     let bullet_radius = current_weapon.bullet_radius();
+    let ammo = weapon_inv.ammo_for(current_weapon);
+
+    fired_events.send(WeaponFiredEvent { weapon: current_weapon });
+    accuracy.shots_fired += pellet_count;
 
     // Spawn bullets
-    for i in 0..pellet_count {
+    for _ in 0..pellet_count {
         let bullet_origin = turret_pos + fire_direction * 1.0;
 
         // Apply spread for shotgun
         let bullet_direction = if spread > 0.0 && pellet_count > 1 {
-            let spread_angle = spread;
-            let horizontal_angle = (i as f32 / pellet_count as f32 - 0.5) * spread_angle;
-            let vertical_angle = (rand::random::<f32>() - 0.5) * spread_angle * 0.5;
-
-            let mut dir = fire_direction;
-            dir = Quat::from_rotation_y(horizontal_angle) * dir;
-            dir = Quat::from_rotation_x(vertical_angle) * dir;
-            dir.normalize()
+            spread_direction(fire_direction, spread, &mut *rng)
         } else {
             fire_direction
         };
 
+        let bullet_transform = Transform::from_translation(bullet_origin)
+            .with_scale(Vec3::splat(bullet_radius));
+
         // Rocket launcher creates rockets instead of bullets
         if current_weapon.explosive() {
-            commands.spawn((
+            pool.spawn_bullet(&mut commands, (
                 Bullet {
                     lifetime: Timer::from_seconds(5.0, TimerMode::Once),
                     damage: base_damage,
                     weapon_type: current_weapon,
+                    penetration: current_weapon.penetration(),
+                    pierced: Vec::new(),
+                    ammo,
                 },
                 Rocket {
                     timer: Timer::from_seconds(current_weapon.rocket_delay(), TimerMode::Once),
                     damage: base_damage,
-                    explosion_radius: current_weapon.explosion_radius(),
+                    explosion_radius: upgrades.explosion_radius(current_weapon),
                 },
                 BulletVelocity {
                     vec: bullet_direction * bullet_speed,
                 },
-                Mesh3d(meshes.add(Sphere { radius: bullet_radius })),
-                MeshMaterial3d(materials.add(Color::srgb(1.0, 0.3, 0.1))),
-                Transform::from_translation(bullet_origin),
+                Mesh3d(weapon_assets.sphere.clone()),
+                MeshMaterial3d(weapon_assets.bullet_material(current_weapon)),
+                bullet_transform,
             ));
         } else {
             // Normal bullets
-            commands.spawn((
+            pool.spawn_bullet(&mut commands, (
                 Bullet {
                     lifetime: Timer::from_seconds(3.0, TimerMode::Once),
                     damage: base_damage,
                     weapon_type: current_weapon,
+                    penetration: current_weapon.penetration(),
+                    pierced: Vec::new(),
+                    ammo,
                 },
                 BulletVelocity {
                     vec: bullet_direction * bullet_speed,
                 },
-                Mesh3d(meshes.add(Sphere { radius: bullet_radius })),
-                MeshMaterial3d(materials.add(if current_weapon == crate::weapon_system::WeaponType::Shotgun {
-                    Color::srgb(0.8, 0.6, 0.3) // Buckshot color
-                } else {
-                    Color::srgb(1.0, 0.8, 0.2) // Machine gun color
-                })),
-                Transform::from_translation(bullet_origin),
+                Mesh3d(weapon_assets.sphere.clone()),
+                MeshMaterial3d(weapon_assets.bullet_material(current_weapon)),
+                bullet_transform,
             ));
         }
     }
 }
 
+/// Random pellet direction within a cone of full angle `spread` around `forward`, spread evenly over its area
+pub fn spread_direction(forward: Vec3, spread: f32, rng: &mut impl Rng) -> Vec3 {
+    let forward = forward.normalize();
+    let (right, up) = forward.any_orthonormal_pair();
+    // sqrt keeps the pattern from bunching up in the middle
+    let offset = spread * 0.5 * rng.gen_range(0.0f32..1.0).sqrt();
+    let around = rng.gen_range(0.0..std::f32::consts::TAU);
+    let sideways = right * around.cos() + up * around.sin();
+    (forward * offset.cos() + sideways * offset.sin()).normalize()
+}
+
 fn update_bullets(
     time: Res<Time>,
     mut commands: Commands,
+    mut pool: ResMut<ProjectilePool>,
     mut bullet_q: Query<(Entity, &mut Bullet, &mut Transform, &BulletVelocity), Without<Rocket>>,
 ) {
     let dt = time.delta_secs();
@@ -217,7 +463,7 @@ fn update_bullets(
         bullet.lifetime.tick(time.delta());
 
         if bullet.lifetime.finished() {
-            commands.entity(entity).despawn_recursive();
+            pool.release_bullet(&mut commands, entity);
             continue;
         }
 
@@ -229,6 +475,7 @@ fn update_bullets(
 fn update_rockets(
     time: Res<Time>,
     mut commands: Commands,
+    mut pool: ResMut<ProjectilePool>,
     mut rocket_q: Query<(Entity, &mut Rocket, &mut Transform, &BulletVelocity)>,
     mut explosion_events: EventWriter<RocketExplosionEvent>,
 ) {
@@ -247,26 +494,35 @@ fn update_rockets(
                 position: transform.translation,
                 damage: rocket.damage,
                 radius: rocket.explosion_radius,
+                environmental: false,
             });
-            commands.entity(entity).despawn_recursive();
+            pool.release_bullet(&mut commands, entity);
         }
     }
 }
 
 fn check_bullet_collisions(
     mut commands: Commands,
-    mut bullet_q: Query<(Entity, &Bullet, &Transform)>,
+    mut bullet_q: Query<(Entity, &mut Bullet, &Transform)>,
     dino_q: Query<(Entity, &GlobalTransform), With<Dinosaur>>,
     hitbox_q: Query<(&HitBox, &GlobalTransform, &Parent)>,
     _parent_q: Query<&Parent>,
     mut hit_events: EventWriter<BulletHitEvent>,
     mut hit_feedback: EventWriter<HitFeedbackEvent>,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    weapon_assets: Res<WeaponAssets>,
+    mut pool: ResMut<ProjectilePool>,
     mut explosion_events: EventReader<RocketExplosionEvent>,
+    mouse_settings: Res<crate::input::MouseSettings>,
+    mut accuracy: ResMut<AccuracyStats>,
+    mut barrel_q: Query<(&GlobalTransform, &mut ExplosiveBarrel)>,
+    mut rng: ResMut<GameRng>,
 ) {
+    let hitbox_scale = mouse_settings.hitbox_scale();
+
     // Handle rocket explosions first
     for event in explosion_events.read() {
+        let mut caught_any = false;
+
         // Find all dinosaurs in explosion radius
         for (dino_entity, dino_global) in dino_q.iter() {
             let dino_pos = dino_global.translation();
@@ -282,22 +538,36 @@ fn check_bullet_collisions(
                     damage,
                     position: event.position,
                     hit_part: BodyPart::Body, // Explosion hits body
+                    // The blast staggers dinos caught in it
+                    status: Some(StatusEffect::Stun { seconds: BLAST_STAGGER_SECONDS * falloff }),
                 });
 
                 // Spawn blood particles
-                spawn_blood_particles(&mut commands, &mut meshes, &mut materials, dino_pos);
+                spawn_blood_particles(&mut commands, &mut pool, &weapon_assets, &mut *rng, dino_pos);
 
                 // Trigger crosshair feedback
                 hit_feedback.send(HitFeedbackEvent);
+                caught_any = true;
+            }
+        }
+
+        if caught_any && !event.environmental {
+            accuracy.shots_hit += 1;
+        }
+
+        // Blasts set off nearby barrels, which chain through their own explosions
+        for (barrel_global, mut barrel) in barrel_q.iter_mut() {
+            if barrel_global.translation().distance(event.position) < event.radius {
+                barrel.ignite();
             }
         }
 
         // Spawn explosion particles
-        spawn_explosion_particles(&mut commands, &mut meshes, &mut materials, event.position);
+        spawn_explosion_particles(&mut commands, &mut pool, &weapon_assets, &mut *rng, event.position);
     }
 
     // Handle bullet collisions
-    for (bullet_entity, bullet, bullet_transform) in bullet_q.iter_mut() {
+    for (bullet_entity, mut bullet, bullet_transform) in bullet_q.iter_mut() {
         // Skip rockets (they're handled by update_rockets)
         if bullet.weapon_type.explosive() {
             continue;
@@ -305,15 +575,29 @@ fn check_bullet_collisions(
 
         let bullet_pos = bullet_transform.translation;
 
+        // Barrels stop the bullet and start their fuse
+        if let Some((_, mut barrel)) = barrel_q
+            .iter_mut()
+            .find(|(barrel_global, _)| barrel_global.translation().distance(bullet_pos) < BARREL_HIT_RADIUS)
+        {
+            barrel.ignite();
+            accuracy.shots_hit += 1;
+            pool.release_bullet(&mut commands, bullet_entity);
+            continue;
+        }
+
         // Check collision with all dinosaurs
         for (dino_entity, dino_global) in dino_q.iter() {
+            if bullet.pierced.contains(&dino_entity) {
+                continue;
+            }
             let dino_pos = dino_global.translation();
 
             // Simple distance check for collision (larger hitbox)
             let distance = (bullet_pos - dino_pos).length();
 
             // Hit detection threshold - generous hitbox
-            if distance < 4.0 {
+            if distance < 4.0 * hitbox_scale {
                 // Find which body part was hit by checking all hitboxes
                 let mut hit_part = BodyPart::Body; // default
                 let mut found_hit = false;
@@ -322,15 +606,16 @@ fn check_bullet_collisions(
                     let hitbox_pos = hitbox_global.translation();
                     let hitbox_distance = (bullet_pos - hitbox_pos).length();
 
-                    if hitbox_distance < 1.5 {
+                    if hitbox_distance < 1.5 * hitbox_scale {
                         hit_part = hit_box.part;
                         found_hit = true;
                         break;
                     }
                 }
 
-                // Calculate damage based on body part
-                let damage = calculate_damage(if found_hit { hit_part } else { BodyPart::Body });
+                // Scale the bullet's damage by body part, weakened by every dino already passed through
+                let damage = bullet.damage * part_multiplier(if found_hit { hit_part } else { BodyPart::Body })
+                    * PENETRATION_FALLOFF.powi(bullet.pierced.len() as i32);
 
                 // Send hit event
                 hit_events.send(BulletHitEvent {
@@ -338,94 +623,101 @@ fn check_bullet_collisions(
                     damage,
                     position: bullet_pos,
                     hit_part: hit_part,
+                    status: bullet.ammo.status_effect(),
                 });
 
                 // Trigger crosshair feedback on hit
                 hit_feedback.send(HitFeedbackEvent);
+                if bullet.pierced.is_empty() {
+                    accuracy.shots_hit += 1;
+                }
 
                 // Spawn blood particles
-                spawn_blood_particles(&mut commands, &mut meshes, &mut materials, bullet_pos);
-
-                // Despawn bullet
-                commands.entity(bullet_entity).despawn_recursive();
+                spawn_blood_particles(&mut commands, &mut pool, &weapon_assets, &mut *rng, bullet_pos);
 
-                // Only one hit per bullet
+                // Carry on through, or return the spent bullet to the pool
+                if bullet.penetration > 0 {
+                    bullet.penetration -= 1;
+                    bullet.pierced.push(dino_entity);
+                    continue;
+                }
+                pool.release_bullet(&mut commands, bullet_entity);
                 break;
             }
         }
     }
 }
 
-fn calculate_damage(part: BodyPart) -> f32 {
+fn part_multiplier(part: BodyPart) -> f32 {
     match part {
-        BodyPart::Head => 50.0,
-        BodyPart::Body => 15.0,
-        BodyPart::Legs => 8.0,
+        BodyPart::Head => 3.0,
+        BodyPart::Body => 1.0,
+        BodyPart::Legs => 0.5,
     }
 }
 
 fn spawn_blood_particles(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    pool: &mut ProjectilePool,
+    weapon_assets: &WeaponAssets,
+    rng: &mut impl Rng,
     position: Vec3,
 ) {
-    let blood_material = materials.add(Color::srgba(0.6, 0.05, 0.05, 0.8));
-
     for _ in 0..12 {
         let offset = Vec3::new(
-            rand::random::<f32>() * 0.8 - 0.4,
-            rand::random::<f32>() * 0.8,
-            rand::random::<f32>() * 0.8 - 0.4,
+            rng.gen_range(-0.4..0.4),
+            rng.gen_range(0.0..0.8),
+            rng.gen_range(-0.4..0.4),
         );
 
         let velocity = Vec3::new(
-            rand::random::<f32>() * 6.0 - 3.0,
-            rand::random::<f32>() * 6.0 + 2.0,
-            rand::random::<f32>() * 6.0 - 3.0,
+            rng.gen_range(-3.0..3.0),
+            rng.gen_range(2.0..8.0),
+            rng.gen_range(-3.0..3.0),
         );
 
-        commands.spawn((
+        pool.spawn_particle(commands, (
             BloodParticle {
                 lifetime: Timer::from_seconds(0.8, TimerMode::Once),
+                size: 0.075,
             },
             BulletVelocity { vec: velocity },
-            Mesh3d(meshes.add(Sphere { radius: 0.15 })),
-            MeshMaterial3d(blood_material.clone()),
-            Transform::from_translation(position + offset).with_scale(Vec3::splat(0.5)),
+            Mesh3d(weapon_assets.sphere.clone()),
+            MeshMaterial3d(weapon_assets.blood_material.clone()),
+            Transform::from_translation(position + offset).with_scale(Vec3::splat(0.075)),
         ));
     }
 }
 
 fn spawn_explosion_particles(
     commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
+    pool: &mut ProjectilePool,
+    weapon_assets: &WeaponAssets,
+    rng: &mut impl Rng,
     position: Vec3,
 ) {
-    let explosion_material = materials.add(Color::srgba(1.0, 0.5, 0.1, 0.9));
-
     for _ in 0..20 {
         let offset = Vec3::new(
-            rand::random::<f32>() * 0.5 - 0.25,
-            rand::random::<f32>() * 0.5,
-            rand::random::<f32>() * 0.5 - 0.25,
+            rng.gen_range(-0.25..0.25),
+            rng.gen_range(0.0..0.5),
+            rng.gen_range(-0.25..0.25),
         );
 
         let velocity = Vec3::new(
-            rand::random::<f32>() * 10.0 - 5.0,
-            rand::random::<f32>() * 8.0 + 3.0,
-            rand::random::<f32>() * 10.0 - 5.0,
+            rng.gen_range(-5.0..5.0),
+            rng.gen_range(3.0..11.0),
+            rng.gen_range(-5.0..5.0),
         );
 
-        commands.spawn((
+        pool.spawn_particle(commands, (
             BloodParticle {
                 lifetime: Timer::from_seconds(0.6, TimerMode::Once),
+                size: 0.24,
             },
             BulletVelocity { vec: velocity },
-            Mesh3d(meshes.add(Sphere { radius: 0.3 })),
-            MeshMaterial3d(explosion_material.clone()),
-            Transform::from_translation(position + offset).with_scale(Vec3::splat(0.8)),
+            Mesh3d(weapon_assets.sphere.clone()),
+            MeshMaterial3d(weapon_assets.explosion_material.clone()),
+            Transform::from_translation(position + offset).with_scale(Vec3::splat(0.24)),
         ));
     }
 }
@@ -433,6 +725,7 @@ fn spawn_explosion_particles(
 fn update_blood_particles(
     time: Res<Time>,
     mut commands: Commands,
+    mut pool: ResMut<ProjectilePool>,
     mut particle_q: Query<(Entity, &mut BloodParticle, &mut Transform, &BulletVelocity)>,
 ) {
     let dt = time.delta_secs();
@@ -441,7 +734,7 @@ fn update_blood_particles(
         particle.lifetime.tick(time.delta());
 
         if particle.lifetime.finished() {
-            commands.entity(entity).despawn_recursive();
+            pool.release_particle(&mut commands, entity);
             continue;
         }
 
@@ -454,6 +747,6 @@ fn update_blood_particles(
         let elapsed = particle.lifetime.elapsed_secs();
         let duration = particle.lifetime.duration().as_secs_f32();
         let scale = 1.0 - (elapsed / duration);
-        transform.scale = Vec3::splat(scale * 0.5);
+        transform.scale = Vec3::splat(scale * particle.size);
     }
 }
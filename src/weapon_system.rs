@@ -1,7 +1,9 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::status_effects::StatusEffect;
 
 /// Different weapon types available in the game
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum WeaponType {
     #[default]
     MachineGun,
@@ -10,6 +12,12 @@ pub enum WeaponType {
 }
 
 impl WeaponType {
+    pub const ALL: [WeaponType; 3] = [
+        WeaponType::MachineGun,
+        WeaponType::Shotgun,
+        WeaponType::RocketLauncher,
+    ];
+
     pub fn name(&self) -> &str {
         match self {
             WeaponType::MachineGun => "Machine Gun",
@@ -42,14 +50,24 @@ impl WeaponType {
         }
     }
 
+    /// Full angle of the pellet cone, in radians
     pub fn spread(&self) -> f32 {
         match self {
             WeaponType::MachineGun => 0.0,
-            WeaponType::Shotgun => 0.15, // Spread angle for shotgun
+            WeaponType::Shotgun => 0.15,
             WeaponType::RocketLauncher => 0.0,
         }
     }
 
+    /// How many dinos a round carries on through after the first it hits
+    pub fn penetration(&self) -> u32 {
+        match self {
+            WeaponType::MachineGun => 0,
+            WeaponType::Shotgun => 1, // Heavy buckshot reaches the dino behind
+            WeaponType::RocketLauncher => 0, // Detonates on its fuse instead
+        }
+    }
+
     pub fn bullet_speed(&self) -> f32 {
         match self {
             WeaponType::MachineGun => 100.0,
@@ -85,58 +103,168 @@ impl WeaponType {
     }
 }
 
-#[derive(Resource, Default)]
+/// Machine gun rounds; every kind but standard is bought in the shop
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AmmoType {
+    #[default]
+    Standard,
+    Incendiary,
+    Cryo,
+}
+
+impl AmmoType {
+    pub const ALL: [AmmoType; 3] = [
+        AmmoType::Standard,
+        AmmoType::Incendiary,
+        AmmoType::Cryo,
+    ];
+
+    pub fn name(&self) -> &str {
+        match self {
+            AmmoType::Standard => "Standard",
+            AmmoType::Incendiary => "Incendiary",
+            AmmoType::Cryo => "Cryo",
+        }
+    }
+
+    /// Effect each hit leaves on the dino
+    pub fn status_effect(&self) -> Option<StatusEffect> {
+        match self {
+            AmmoType::Standard => None,
+            AmmoType::Incendiary => Some(StatusEffect::Burn { dps: 6.0, seconds: 3.0 }),
+            AmmoType::Cryo => Some(StatusEffect::Slow { factor: 0.5, seconds: 2.5 }),
+        }
+    }
+}
+
+#[derive(Resource)]
 pub struct WeaponInventory {
     pub current_weapon: WeaponType,
     pub unlocked_weapons: Vec<WeaponType>,
+    /// Loaded in the machine gun; the other weapons always fire standard rounds
+    pub current_ammo: AmmoType,
+    pub unlocked_ammo: Vec<AmmoType>,
+}
+
+// Default must match new(), an empty unlock list breaks switching
+impl Default for WeaponInventory {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl WeaponInventory {
+    /// Start with only the machine gun; the rest are bought in the shop
     pub fn new() -> Self {
         Self {
             current_weapon: WeaponType::MachineGun,
-            unlocked_weapons: vec![
-                WeaponType::MachineGun,
-                WeaponType::Shotgun,
-                WeaponType::RocketLauncher,
-            ],
+            unlocked_weapons: vec![WeaponType::MachineGun],
+            current_ammo: AmmoType::Standard,
+            unlocked_ammo: vec![AmmoType::Standard],
+        }
+    }
+
+    pub fn is_unlocked(&self, weapon: WeaponType) -> bool {
+        self.unlocked_weapons.contains(&weapon)
+    }
+
+    /// Unlock a weapon, keeping the list in slot order so cycling stays predictable
+    pub fn unlock(&mut self, weapon: WeaponType) {
+        if self.is_unlocked(weapon) {
+            return;
+        }
+
+        self.unlocked_weapons.push(weapon);
+        self.unlocked_weapons.sort_by_key(|w| WeaponType::ALL.iter().position(|a| a == w));
+    }
+
+    /// Take a bought weapon back; the machine gun always stays
+    pub fn lock(&mut self, weapon: WeaponType) {
+        if weapon == WeaponType::MachineGun {
+            return;
+        }
+
+        self.unlocked_weapons.retain(|w| *w != weapon);
+        if self.current_weapon == weapon {
+            self.current_weapon = WeaponType::MachineGun;
         }
     }
 
-    pub fn switch_to(&mut self, weapon: WeaponType) {
-        if self.unlocked_weapons.contains(&weapon) {
+    /// Returns true if the current weapon changed
+    pub fn switch_to(&mut self, weapon: WeaponType) -> bool {
+        if self.is_unlocked(weapon) && self.current_weapon != weapon {
             self.current_weapon = weapon;
+            return true;
         }
+        false
     }
 
     pub fn next_weapon(&mut self) {
-        if self.unlocked_weapons.is_empty() {
+        self.cycle(1);
+    }
+
+    pub fn previous_weapon(&mut self) {
+        self.cycle(-1);
+    }
+
+    fn cycle(&mut self, step: isize) {
+        let count = self.unlocked_weapons.len();
+        if count == 0 {
             return;
         }
 
-        let current_idx = self.unlocked_weapons.iter()
-            .position(|w| *w == self.current_weapon)
-            .unwrap_or(0);
+        // If the current weapon isn't unlocked, fall back to the first one
+        let Some(current_idx) = self.unlocked_weapons.iter().position(|w| *w == self.current_weapon) else {
+            self.current_weapon = self.unlocked_weapons[0];
+            return;
+        };
 
-        let next_idx = (current_idx + 1) % self.unlocked_weapons.len();
+        let next_idx = (current_idx as isize + step).rem_euclid(count as isize) as usize;
         self.current_weapon = self.unlocked_weapons[next_idx];
     }
 
-    pub fn previous_weapon(&mut self) {
-        if self.unlocked_weapons.is_empty() {
+    pub fn is_ammo_unlocked(&self, ammo: AmmoType) -> bool {
+        self.unlocked_ammo.contains(&ammo)
+    }
+
+    /// Unlock an ammo type, keeping the list in cycling order
+    pub fn unlock_ammo(&mut self, ammo: AmmoType) {
+        if self.is_ammo_unlocked(ammo) {
             return;
         }
 
-        let current_idx = self.unlocked_weapons.iter()
-            .position(|w| *w == self.current_weapon)
-            .unwrap_or(0);
+        self.unlocked_ammo.push(ammo);
+        self.unlocked_ammo.sort_by_key(|a| AmmoType::ALL.iter().position(|b| b == a));
+    }
 
-        let prev_idx = if current_idx == 0 {
-            self.unlocked_weapons.len() - 1
-        } else {
-            current_idx - 1
+    /// Take bought ammo back; standard rounds always stay
+    pub fn lock_ammo(&mut self, ammo: AmmoType) {
+        if ammo == AmmoType::Standard {
+            return;
+        }
+
+        self.unlocked_ammo.retain(|a| *a != ammo);
+        if self.current_ammo == ammo {
+            self.current_ammo = AmmoType::Standard;
+        }
+    }
+
+    pub fn next_ammo(&mut self) {
+        let Some(current_idx) = self.unlocked_ammo.iter().position(|a| *a == self.current_ammo) else {
+            self.current_ammo = AmmoType::Standard;
+            return;
         };
-        self.current_weapon = self.unlocked_weapons[prev_idx];
+
+        self.current_ammo = self.unlocked_ammo[(current_idx + 1) % self.unlocked_ammo.len()];
+    }
+
+    /// Ammo a shot from `weapon` carries
+    pub fn ammo_for(&self, weapon: WeaponType) -> AmmoType {
+        if weapon == WeaponType::MachineGun {
+            self.current_ammo
+        } else {
+            AmmoType::Standard
+        }
     }
 
     pub fn get_current_stats(&self) -> WeaponStats {
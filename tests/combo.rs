@@ -0,0 +1,14 @@
+use vibe_dragon_game::combo::ComboSystem;
+
+fn window_after(kills: u32) -> f32 {
+    let mut combo = ComboSystem::new();
+    for _ in 0..kills {
+        combo.add_kill();
+    }
+    combo.combo_timer.duration().as_secs_f32()
+}
+
+#[test]
+fn longer_streaks_get_a_longer_window() {
+    assert!(window_after(15) > window_after(3));
+}
@@ -0,0 +1,365 @@
+use std::time::Duration;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use vibe_dragon_game::combo::ComboSystem;
+use vibe_dragon_game::dino::{BodyPart, DamageReaction, DinoDeath, DinoHealth, Dinosaur};
+use vibe_dragon_game::headless::headless_app;
+use vibe_dragon_game::effects::HitFeedbackEvent;
+use vibe_dragon_game::input::TargetLock;
+use vibe_dragon_game::main_menu::StartButton;
+use vibe_dragon_game::pause::{GameState, RestartGameEvent, RestartProgress};
+use vibe_dragon_game::rng::GameRng;
+use vibe_dragon_game::shop::{VehicleUpgrades, WeaponUpgrades};
+use vibe_dragon_game::ui::{Crosshair, ScoreText};
+use vibe_dragon_game::vehicle::{
+    PlayerVehicle, SelectedVehicle, TargetLockIndicator, VehicleHealth, VehicleType, VehicleVelocity, WeaponTurret,
+    VEHICLE_SPAWN_POSITION,
+};
+use vibe_dragon_game::round_start::RoundStart;
+use vibe_dragon_game::weapon::{AccuracyStats, Bullet, BulletHitEvent};
+use vibe_dragon_game::weapon_system::{AmmoType, WeaponType};
+use vibe_dragon_game::GameScore;
+
+/// Headless app on a fixed seed, past startup: the herd and vehicle are spawned and placed
+fn seeded_app() -> App {
+    let mut app = headless_app();
+    app.insert_resource(GameRng::new(42));
+
+    // Startup spawns the herd; the second frame propagates their transforms
+    app.update();
+    app.update();
+    app
+}
+
+#[test]
+fn bullet_kill_scores() {
+    let mut app = seeded_app();
+
+    let world = app.world_mut();
+    // Weaken the whole herd, so whichever dino the bullet finds dies in one hit
+    for mut health in world.query::<&mut DinoHealth>().iter_mut(world) {
+        health.current = 1.0;
+    }
+    let target = world
+        .query_filtered::<&GlobalTransform, With<Dinosaur>>()
+        .iter(world)
+        .next()
+        .expect("the herd spawns at startup")
+        .translation();
+    let score_before = world.resource::<GameScore>().score;
+
+    world.spawn((
+        Bullet {
+            lifetime: Timer::from_seconds(1.0, TimerMode::Once),
+            damage: 15.0,
+            weapon_type: WeaponType::MachineGun,
+            penetration: 0,
+            pierced: Vec::new(),
+            ammo: AmmoType::Standard,
+        },
+        Transform::from_translation(target),
+    ));
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert!(app.world().resource::<GameScore>().score > score_before);
+}
+
+#[test]
+fn penetrating_bullet_hits_dinos_in_a_line() {
+    let mut app = seeded_app();
+
+    let world = app.world_mut();
+    let mut herd = world.query_filtered::<(Entity, &mut Transform, &mut GlobalTransform), With<Dinosaur>>();
+    let mut dinos = herd.iter_mut(world);
+    let (front, front_transform, mut front_global) = dinos.next().expect("the herd spawns at startup");
+    let (back, mut back_transform, mut back_global) = dinos.next().expect("the herd has more than one dino");
+
+    // Line the second dino up right behind the first, with the bullet between them
+    let line_start = front_transform.translation;
+    let behind = line_start + Vec3::new(0.0, 0.0, -3.0);
+    back_transform.translation = behind;
+    *back_global = GlobalTransform::from(*back_transform);
+    *front_global = GlobalTransform::from(*front_transform);
+
+    let health_of = |world: &mut World, dino: Entity| world.get::<DinoHealth>(dino).unwrap().current;
+    let (front_before, back_before) = (health_of(world, front), health_of(world, back));
+
+    world.spawn((
+        Bullet {
+            lifetime: Timer::from_seconds(1.0, TimerMode::Once),
+            damage: 15.0,
+            weapon_type: WeaponType::Shotgun,
+            penetration: WeaponType::Shotgun.penetration(),
+            pierced: Vec::new(),
+            ammo: AmmoType::Standard,
+        },
+        Transform::from_translation(line_start.lerp(behind, 0.5)),
+    ));
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let world = app.world_mut();
+    assert!(health_of(world, front) < front_before, "the first dino in line wasn't hit");
+    assert!(health_of(world, back) < back_before, "the bullet stopped at the first dino");
+}
+
+/// Health a tough dino loses to one machine-gun bullet fired with the given upgrades
+fn health_lost_to_bullet(upgrades: WeaponUpgrades) -> f32 {
+    let mut app = seeded_app();
+
+    let world = app.world_mut();
+    let (target, mut health, global) = world
+        .query_filtered::<(Entity, &mut DinoHealth, &GlobalTransform), With<Dinosaur>>()
+        .iter_mut(world)
+        .next()
+        .expect("the herd spawns at startup");
+    // Tough enough to survive the hit, so the damage isn't capped by what was left
+    health.current = 1000.0;
+    health.max = 1000.0;
+    let position = global.translation();
+
+    world.spawn((
+        Bullet {
+            lifetime: Timer::from_seconds(1.0, TimerMode::Once),
+            damage: upgrades.damage(WeaponType::MachineGun),
+            weapon_type: WeaponType::MachineGun,
+            penetration: 0,
+            pierced: Vec::new(),
+            ammo: AmmoType::Standard,
+        },
+        Transform::from_translation(position),
+    ));
+    for _ in 0..3 {
+        app.update();
+    }
+
+    1000.0 - app.world().get::<DinoHealth>(target).unwrap().current
+}
+
+#[test]
+fn damage_upgrades_make_bullets_hit_harder() {
+    let base = health_lost_to_bullet(WeaponUpgrades::default());
+    let upgraded = health_lost_to_bullet(WeaponUpgrades { machinegun_damage_level: 3, ..default() });
+
+    assert!(base > 0.0, "the bullet missed");
+    assert!(upgraded > base, "upgraded bullet took {upgraded} HP, level 0 took {base}");
+}
+
+/// Seeded app, and two of its dinos to lock onto
+fn lock_test_app() -> (App, Entity, Entity) {
+    let mut app = seeded_app();
+
+    let world = app.world_mut();
+    let mut herd = world.query_filtered::<Entity, With<Dinosaur>>();
+    let mut dinos = herd.iter(world);
+    let first = dinos.next().expect("the herd spawns at startup");
+    let second = dinos.next().expect("the herd has more than one dino");
+    (app, first, second)
+}
+
+fn lock_onto(app: &mut App, dino: Entity) {
+    let world = app.world_mut();
+    let position = world.get::<GlobalTransform>(dino).unwrap().translation();
+    *world.resource_mut::<TargetLock>() = TargetLock {
+        locked_entity: Some(dino),
+        lock_position: Some(position),
+    };
+
+    // The indicator follows the lock on its own
+    app.update();
+    let world = app.world_mut();
+    let indicators = world.query_filtered::<(), With<TargetLockIndicator>>().iter(world).count();
+    assert_eq!(indicators, 1);
+}
+
+fn assert_lock_cleared(app: &mut App) {
+    let world = app.world_mut();
+    let target_lock = world.resource::<TargetLock>();
+    assert_eq!(target_lock.locked_entity, None);
+    assert_eq!(target_lock.lock_position, None);
+    let indicators = world.query_filtered::<(), With<TargetLockIndicator>>().iter(world).count();
+    assert_eq!(indicators, 0, "the lock indicator outlived its target");
+}
+
+#[test]
+fn target_lock_clears_when_dino_dies() {
+    let (mut app, dino, _) = lock_test_app();
+    lock_onto(&mut app, dino);
+
+    let health = app.world().get::<DinoHealth>(dino).unwrap().current;
+    app.world_mut().send_event(BulletHitEvent {
+        target: dino,
+        damage: health,
+        position: Vec3::ZERO,
+        hit_part: BodyPart::Body,
+        status: None,
+    });
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert_lock_cleared(&mut app);
+}
+
+#[test]
+fn target_lock_clears_when_dino_despawns() {
+    let (mut app, _, dino) = lock_test_app();
+    lock_onto(&mut app, dino);
+
+    app.world_mut().entity_mut(dino).despawn_recursive();
+    app.update();
+
+    assert_lock_cleared(&mut app);
+}
+
+#[test]
+fn restart_resets_the_vehicle_combo_and_herd() {
+    let mut app = seeded_app();
+
+    // Kill one dino and wound another, so both death and hit reactions are in play
+    let world = app.world_mut();
+    let herd: Vec<(Entity, f32)> = world
+        .query_filtered::<(Entity, &DinoHealth), With<Dinosaur>>()
+        .iter(world)
+        .map(|(entity, health)| (entity, health.current))
+        .take(2)
+        .collect();
+    for (index, (dino, health)) in herd.into_iter().enumerate() {
+        world.send_event(BulletHitEvent {
+            target: dino,
+            damage: if index == 0 { health } else { 1.0 },
+            position: Vec3::ZERO,
+            hit_part: BodyPart::Body,
+            status: None,
+        });
+    }
+    app.update();
+
+    let world = app.world_mut();
+    assert!(world.query::<&DinoDeath>().iter(world).count() > 0);
+    assert!(world.query::<&DamageReaction>().iter(world).count() > 0);
+    let (mut transform, mut health) = world
+        .query_filtered::<(&mut Transform, &mut VehicleHealth), With<PlayerVehicle>>()
+        .single_mut(world);
+    transform.translation += Vec3::new(30.0, 0.0, -40.0);
+    health.current = 10.0;
+    let mut combo = world.resource_mut::<ComboSystem>();
+    for _ in 0..4 {
+        combo.add_kill();
+    }
+
+    world.send_event(RestartGameEvent::new(RestartProgress::Continue));
+    app.update();
+    app.update();
+
+    let world = app.world_mut();
+    let (transform, health) = world
+        .query_filtered::<(&Transform, &VehicleHealth), With<PlayerVehicle>>()
+        .single(world);
+    assert_eq!(transform.translation, VEHICLE_SPAWN_POSITION);
+    assert_eq!(health.current, health.max);
+    assert_eq!(world.resource::<ComboSystem>().current_combo, 0);
+    assert_eq!(world.query::<&DinoDeath>().iter(world).count(), 0);
+    assert_eq!(world.query::<&DamageReaction>().iter(world).count(), 0);
+}
+
+/// The player vehicle's chassis, top speed and (current, max) health, after checking the old turret went with the old body
+fn player_chassis(app: &mut App) -> (VehicleType, f32, (f32, f32)) {
+    let world = app.world_mut();
+    let (vehicle, chassis, velocity, health) = world
+        .query_filtered::<(Entity, &VehicleType, &VehicleVelocity, &VehicleHealth), With<PlayerVehicle>>()
+        .single(world);
+    let (chassis, max_speed, health) = (*chassis, velocity.max_speed, (health.current, health.max));
+
+    let turrets: Vec<_> = world.query_filtered::<&Parent, With<WeaponTurret>>().iter(world).collect();
+    assert_eq!(turrets.len(), 1);
+    assert_eq!(turrets[0].get(), vehicle);
+    (chassis, max_speed, health)
+}
+
+#[test]
+fn restart_swaps_in_the_selected_chassis() {
+    let mut app = seeded_app();
+
+    app.world_mut().resource_mut::<SelectedVehicle>().0 = VehicleType::Truck;
+    app.world_mut().send_event(RestartGameEvent::new(RestartProgress::Continue));
+    app.update();
+    app.update();
+
+    let (chassis, max_speed, (current, max)) = player_chassis(&mut app);
+    assert_eq!(chassis, VehicleType::Truck);
+    assert_eq!(max_speed, VehicleType::Truck.velocity().max_speed);
+    assert_eq!(max, VehicleUpgrades::default().max_health(VehicleType::Truck));
+    assert_eq!(current, max);
+
+    // Picking another chassis and pressing Start on the menu swaps it in too
+    app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Paused);
+    app.update();
+    app.world_mut().resource_mut::<SelectedVehicle>().0 = VehicleType::Buggy;
+    let world = app.world_mut();
+    let start = world.query_filtered::<Entity, With<StartButton>>().single(world);
+    world.entity_mut(start).insert(Interaction::Pressed);
+    app.update();
+    app.update();
+
+    assert_eq!(*app.world().resource::<State<GameState>>().get(), GameState::Playing);
+    let (chassis, max_speed, (current, max)) = player_chassis(&mut app);
+    assert_eq!(chassis, VehicleType::Buggy);
+    assert_eq!(max_speed, VehicleType::Buggy.velocity().max_speed);
+    assert_eq!(max, VehicleUpgrades::default().max_health(VehicleType::Buggy));
+    assert_eq!(current, max);
+}
+
+#[test]
+fn firing_reuses_bullet_assets() {
+    let mut app = seeded_app();
+    // One machine gun shot per update
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)));
+    app.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(MouseButton::Left);
+    while app.world().resource::<RoundStart>().is_counting() {
+        app.update();
+    }
+
+    app.update();
+    let meshes_before = app.world().resource::<Assets<Mesh>>().len();
+    let shots_before = app.world().resource::<AccuracyStats>().shots_fired;
+    while app.world().resource::<AccuracyStats>().shots_fired < shots_before + 1000 {
+        app.update();
+    }
+
+    assert_eq!(app.world().resource::<Assets<Mesh>>().len(), meshes_before);
+}
+
+#[test]
+fn hit_stretches_the_vertical_crosshair_vertically() {
+    let mut app = seeded_app();
+    app.world_mut().send_event(HitFeedbackEvent);
+    app.update();
+
+    let world = app.world_mut();
+    let node = world
+        .query::<(&Crosshair, &Node)>()
+        .iter(world)
+        .find(|(line, _)| **line == Crosshair::Vertical)
+        .map(|(_, node)| node.clone())
+        .expect("the HUD spawns a vertical crosshair line");
+    let (Val::Px(width), Val::Px(height)) = (node.width, node.height) else {
+        panic!("crosshair lines are sized in pixels");
+    };
+    assert!(height > 20.0, "the hit didn't bloom the crosshair");
+    assert!(height > width, "vertical line is {width}x{height}");
+}
+
+#[test]
+fn score_text_follows_game_score() {
+    let mut app = seeded_app();
+    app.world_mut().resource_mut::<GameScore>().score = 1234;
+    app.update();
+
+    let world = app.world_mut();
+    let text = world.query_filtered::<&Text, With<ScoreText>>().single(world);
+    assert_eq!(text.0, "Score: 1234");
+}
@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use vibe_dragon_game::rng::GameRng;
+use vibe_dragon_game::weapon::spread_direction;
+use vibe_dragon_game::weapon_system::WeaponType;
+
+#[test]
+fn pellets_fill_the_cone() {
+    let mut rng = GameRng::new(7);
+    let spread = WeaponType::Shotgun.spread();
+    let forward = Vec3::new(0.3, -0.1, -1.0).normalize();
+
+    let pellets: Vec<Vec3> = (0..64).map(|_| spread_direction(forward, spread, &mut rng)).collect();
+
+    for pellet in &pellets {
+        assert!(pellet.angle_between(forward) <= spread * 0.5 + 1e-4, "pellet left the cone: {pellet}");
+    }
+
+    // A fixed horizontal fan would put every pellet at the same elevation
+    let elevations: Vec<f32> = pellets.iter().map(|pellet| pellet.y.asin()).collect();
+    let lowest = elevations.iter().copied().fold(f32::INFINITY, f32::min);
+    let highest = elevations.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    assert!(highest - lowest > spread * 0.5, "pellets stayed on one line: {lowest}..{highest}");
+}
@@ -0,0 +1,22 @@
+use vibe_dragon_game::weapon_system::{WeaponInventory, WeaponType};
+
+#[test]
+fn next_weapon_cycles_through_unlocked_weapons() {
+    // Default is what init_resource registers
+    let mut inventory = WeaponInventory::default();
+    inventory.unlock(WeaponType::RocketLauncher);
+    inventory.unlock(WeaponType::Shotgun);
+
+    let mut order = vec![inventory.current_weapon];
+    for _ in 0..3 {
+        inventory.next_weapon();
+        order.push(inventory.current_weapon);
+    }
+
+    assert_eq!(order, [
+        WeaponType::MachineGun,
+        WeaponType::Shotgun,
+        WeaponType::RocketLauncher,
+        WeaponType::MachineGun,
+    ]);
+}